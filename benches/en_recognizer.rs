@@ -0,0 +1,151 @@
+use chrono::NaiveDate;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use date_time_parser_multi_language::{
+    bench::*, DateFormat, DateParser, EnDateParser, StartDayOfWeek,
+};
+
+/// Short commands, like the ones typed into a todo app.
+const SHORT_COMMANDS: [&str; 10] = [
+    "call mom tomorrow",
+    "pay rent next friday",
+    "dentist on 10.12.2025",
+    "remind me to water the plants in 3 days",
+    "team lunch monday next week",
+    "submit the report in two weeks",
+    "book flights for june 5th",
+    "renew passport in 2 months",
+    "budget review next month",
+    "standup on wednesday",
+];
+
+/// Long texts, where the date is somewhere in the middle or at the end.
+const LONG_EMAILS: [&str; 3] = [
+    "Hi Bob, thanks for the update on the migration. I went through the open tickets and most of \
+     them look fine to me. The only thing I'm worried about is the database schema, because the \
+     old columns are still used by the reporting jobs. Could we go through the remaining changes \
+     together? Monday in two weeks would work for me, otherwise let me know what suits you. Cheers, Alice",
+    "Dear all, as announced in the last all hands, the office will be closed for maintenance. \
+     Please make sure to take your laptops home and to clear your desks, because the cleaning team \
+     needs access to every room. The facility team will send out a separate note with the details \
+     about the parking garage. The maintenance starts on 24.12.2025 and we expect to be back soon.",
+    "Hello team, here is the summary of our retrospective. We agreed to reduce the number of \
+     meetings, to write shorter tickets and to pair more often on the tricky parts of the code base. \
+     Everyone should update their personal goals in the tracker and share them with their lead. \
+     The next retrospective takes place in 3 weeks, please add your topics to the board beforehand.",
+];
+
+/// Texts without any date.
+const NO_DATE: [&str; 5] = [
+    "buy milk",
+    "fix the flaky integration test in the payment service",
+    "Thanks a lot for your help with the presentation, the customer loved it!",
+    "Could you send me the slides and the recording of the workshop?",
+    "We should rethink the caching strategy of the search endpoint before scaling it out.",
+];
+
+/// An expression parser, which returns if it found an expression.
+type Parse = fn(&str) -> bool;
+
+fn search_relative_date_expression(c: &mut Criterion) {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+    let mut group = c.benchmark_group("search_relative_date_expression");
+
+    for (name, corpus) in [
+        ("short commands", &SHORT_COMMANDS[..]),
+        ("long emails", &LONG_EMAILS[..]),
+        ("no date", &NO_DATE[..]),
+    ] {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for text in corpus {
+                    black_box(EnDateParser::search_relative_date_expression(
+                        black_box(text),
+                        &now,
+                        &DateFormat::DayMonthYear,
+                        &StartDayOfWeek::Monday,
+                    ));
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn expressions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("expressions");
+    let corpus: Vec<&str> = SHORT_COMMANDS
+        .iter()
+        .chain(LONG_EMAILS.iter())
+        .chain(NO_DATE.iter())
+        .copied()
+        .collect();
+
+    let parsers: [(&str, Parse); 20] = [
+        ("parse_keywords", |text| parse_keywords(text).is_some()),
+        ("parse_ago", |text| parse_ago(text).is_some()),
+        ("parse_day_in_relative_week", |text| {
+            parse_day_in_relative_week(text).is_some()
+        }),
+        ("parse_day_in_explicit_week", |text| {
+            parse_day_in_explicit_week(text).is_some()
+        }),
+        ("parse_date_in_week", |text| {
+            parse_date_in_week(text, &DateFormat::DayMonthYear).is_some()
+        }),
+        ("parse_day_alone", |text| parse_day_alone(text).is_some()),
+        ("parse_keyword_relative_week", |text| {
+            parse_keyword_relative_week(text, &DateFormat::DayMonthYear).is_some()
+        }),
+        ("parse_in_x_weeks", |text| parse_in_x_weeks(text).is_some()),
+        ("parse_relative_day", |text| {
+            parse_relative_day(text).is_some()
+        }),
+        ("parse_iso_date", |text| parse_iso_date(text).is_some()),
+        ("parse_date_month_year", |text| {
+            parse_date_month_year(text).is_some()
+        }),
+        ("parse_month_date_year", |text| {
+            parse_month_date_year(text).is_some()
+        }),
+        ("parse_year_month_day", |text| {
+            parse_year_month_day(text).is_some()
+        }),
+        ("parse_month_in_relative_year", |text| {
+            parse_month_in_relative_year(text, &DateFormat::DayMonthYear).is_some()
+        }),
+        ("parse_month_date", |text| {
+            parse_month_date(text, &DateFormat::DayMonthYear).is_some()
+        }),
+        ("parse_month_year", |text| {
+            parse_month_year(text, &DateFormat::DayMonthYear).is_some()
+        }),
+        ("parse_day_of_month", |text| {
+            parse_day_of_month(text).is_some()
+        }),
+        ("parse_in_n_months", |text| {
+            parse_in_n_months(text).is_some()
+        }),
+        ("parse_relative_month", |text| {
+            parse_relative_month(text).is_some()
+        }),
+        ("parse_relative_year", |text| {
+            parse_relative_year(text).is_some()
+        }),
+    ];
+
+    for (name, parse) in parsers {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for text in &corpus {
+                    black_box(parse(black_box(text)));
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, search_relative_date_expression, expressions);
+criterion_main!(benches);
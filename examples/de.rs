@@ -0,0 +1,16 @@
+use date_time_parser_multi_language::{DateParser, DeDateParser};
+
+fn main() {
+    let some_input = "Erinnere mich daran, übermorgen die Miete zu zahlen.";
+
+    let now = chrono::Utc::now().naive_local().date();
+
+    let date = DeDateParser::search_relative_date_expression(
+        some_input,
+        &now,
+        &DeDateParser::DATE_FORMAT,
+        &DeDateParser::START_DAY_OF_WEEK,
+    );
+
+    println!("I found the date {:?}", date);
+}
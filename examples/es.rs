@@ -0,0 +1,16 @@
+use date_time_parser_multi_language::{DateParser, EsDateParser};
+
+fn main() {
+    let some_input = "Recuérdame que pague el alquiler pasado mañana.";
+
+    let now = chrono::Utc::now().naive_local().date();
+
+    let date = EsDateParser::search_relative_date_expression(
+        some_input,
+        &now,
+        &EsDateParser::DATE_FORMAT,
+        &EsDateParser::START_DAY_OF_WEEK,
+    );
+
+    println!("I found the date {:?}", date);
+}
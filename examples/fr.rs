@@ -0,0 +1,16 @@
+use date_time_parser_multi_language::{DateParser, FrDateParser};
+
+fn main() {
+    let some_input = "Rappelle-moi de payer le loyer après-demain.";
+
+    let now = chrono::Utc::now().naive_local().date();
+
+    let date = FrDateParser::search_relative_date_expression(
+        some_input,
+        &now,
+        &FrDateParser::DATE_FORMAT,
+        &FrDateParser::START_DAY_OF_WEEK,
+    );
+
+    println!("I found the date {:?}", date);
+}
@@ -0,0 +1,16 @@
+use date_time_parser_multi_language::{DateParser, JaDateParser};
+
+fn main() {
+    let some_input = "明後日に家賃を払う";
+
+    let now = chrono::Utc::now().naive_local().date();
+
+    let date = JaDateParser::search_relative_date_expression(
+        some_input,
+        &now,
+        &JaDateParser::DATE_FORMAT,
+        &JaDateParser::START_DAY_OF_WEEK,
+    );
+
+    println!("I found the date {:?}", date);
+}
@@ -0,0 +1,16 @@
+use date_time_parser_multi_language::{DateParser, RuDateParser};
+
+fn main() {
+    let some_input = "Напомни мне заплатить за квартиру послезавтра.";
+
+    let now = chrono::Utc::now().naive_local().date();
+
+    let date = RuDateParser::search_relative_date_expression(
+        some_input,
+        &now,
+        &RuDateParser::DATE_FORMAT,
+        &RuDateParser::START_DAY_OF_WEEK,
+    );
+
+    println!("I found the date {:?}", date);
+}
@@ -0,0 +1,16 @@
+use date_time_parser_multi_language::{DateParser, ZhDateParser};
+
+fn main() {
+    let some_input = "提醒我后天交房租";
+
+    let now = chrono::Utc::now().naive_local().date();
+
+    let date = ZhDateParser::search_relative_date_expression(
+        some_input,
+        &now,
+        &ZhDateParser::DATE_FORMAT,
+        &ZhDateParser::START_DAY_OF_WEEK,
+    );
+
+    println!("I found the date {:?}", date);
+}
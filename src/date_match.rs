@@ -0,0 +1,69 @@
+use std::ops::Range;
+
+use chrono::NaiveDate;
+
+use crate::{
+    language::shared::{
+        resolve_date_expression, DateExpression, RecognizedDateExpression, ResolveError,
+    },
+    parse_error::ParseError,
+    StartDayOfWeek,
+};
+
+/// A date found in a string together with the part of the string it was found in.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DateMatch {
+    /// The resolved date.
+    pub date: NaiveDate,
+    /// The expression the date was resolved from.
+    pub expression: DateExpression,
+    /// The byte range of the matched text in the input.
+    pub range: Range<usize>,
+    /// The matched text, e.g. `next monday` in `call Bob next monday about taxes`.
+    pub text: String,
+}
+
+impl DateMatch {
+    /// Resolves a recognized expression of `input` relative to `now`.
+    pub(crate) fn resolve(
+        input: &str,
+        recognized: RecognizedDateExpression,
+        now: &NaiveDate,
+        start_day_week: &StartDayOfWeek,
+    ) -> Result<DateMatch, ParseError> {
+        DateMatch::resolve_with(input, recognized, |expression| {
+            resolve_date_expression(expression, now, start_day_week)
+        })
+    }
+
+    /// Resolves a recognized expression of `input` with `resolve`.
+    pub(crate) fn resolve_with(
+        input: &str,
+        recognized: RecognizedDateExpression,
+        resolve: impl FnOnce(DateExpression) -> Result<NaiveDate, ResolveError>,
+    ) -> Result<DateMatch, ParseError> {
+        let date = resolve(recognized.expression.clone()).map_err(|error| {
+            ParseError::from_resolve_error(
+                error,
+                recognized.expression.clone(),
+                recognized.range.clone(),
+            )
+        })?;
+
+        Ok(DateMatch {
+            date,
+            expression: recognized.expression,
+            text: input[recognized.range.clone()].to_string(),
+            range: recognized.range,
+        })
+    }
+}
+
+/// The input without the date phrase, e.g. the title of a task, together with the found date.
+#[derive(Debug, PartialEq, Clone)]
+pub struct StrippedDateExpression {
+    /// The input without the date phrase, its dangling preposition and leading phrases like `remind me to`.
+    pub title: String,
+    /// The found date, if any.
+    pub date_match: Option<DateMatch>,
+}
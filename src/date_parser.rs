@@ -1,12 +1,61 @@
 use chrono::NaiveDate;
 
-use crate::{language::date_format::DateFormat, StartDayOfWeek};
+use crate::{
+    date_match::{DateMatch, StrippedDateExpression},
+    language::date_format::DateFormat,
+    parse_error::ParseError,
+    StartDayOfWeek,
+};
 
 pub trait DateParser {
+    /// Searches the first date expression in `input` and returns it together with the matched text.
+    ///
+    /// Explains why no date was found, e.g. `February has no 30th`.
+    fn try_search_date_expression(
+        input: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_of_week: &StartDayOfWeek,
+    ) -> Result<DateMatch, ParseError>;
+
+    /// Searches the first date expression in `input` and returns it together with the matched text.
+    fn search_date_expression(
+        input: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_of_week: &StartDayOfWeek,
+    ) -> Option<DateMatch> {
+        Self::try_search_date_expression(input, now, date_format, start_of_week).ok()
+    }
+
+    /// Searches all non-overlapping date expressions in `input` and returns them in reading order.
+    ///
+    /// Expressions which can't be resolved into a valid date, like `30.2`, are skipped.
+    fn search_all_date_expressions(
+        input: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_of_week: &StartDayOfWeek,
+    ) -> Vec<DateMatch>;
+
+    /// Searches the first date expression in `input` and removes it from the input.
+    ///
+    /// This turns `Remind me to pay rent next friday` into the title `pay rent` and the date of next friday.
+    fn strip_date_expression(
+        input: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_of_week: &StartDayOfWeek,
+    ) -> StrippedDateExpression;
+
+    /// Searches the first date expression in `input` and returns the resolved date.
     fn search_relative_date_expression(
         input: &str,
         now: &NaiveDate,
         date_format: &DateFormat,
         start_of_week: &StartDayOfWeek,
-    ) -> Option<NaiveDate>;
+    ) -> Option<NaiveDate> {
+        Self::search_date_expression(input, now, date_format, start_of_week)
+            .map(|date_match| date_match.date)
+    }
 }
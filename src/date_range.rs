@@ -0,0 +1,54 @@
+use std::ops::Range;
+
+use chrono::NaiveDate;
+
+use crate::{
+    language::shared::{
+        resolve_date_range_expression, DateRangeExpression, RecognizedDateRangeExpression,
+    },
+    StartDayOfWeek,
+};
+
+/// A range of dates. Both `start` and `end` are inclusive.
+///
+/// `from monday to wednesday` starts on monday and ends on wednesday, it contains three days.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DateRange {
+    /// The first day of the range.
+    pub start: NaiveDate,
+    /// The last day of the range.
+    pub end: NaiveDate,
+}
+
+/// A date range found in a string together with the part of the string it was found in.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DateRangeMatch {
+    /// The resolved date range.
+    pub date_range: DateRange,
+    /// The expression the date range was resolved from.
+    pub expression: DateRangeExpression,
+    /// The byte range of the matched text in the input.
+    pub range: Range<usize>,
+    /// The matched text, e.g. `from monday to wednesday` in `I'm on vacation from monday to wednesday`.
+    pub text: String,
+}
+
+impl DateRangeMatch {
+    /// Resolves a recognized range expression of `input` relative to `now`.
+    pub(crate) fn resolve(
+        input: &str,
+        recognized: RecognizedDateRangeExpression,
+        now: &NaiveDate,
+        start_day_week: &StartDayOfWeek,
+    ) -> Option<DateRangeMatch> {
+        let (start, end) =
+            resolve_date_range_expression(recognized.expression.clone(), now, start_day_week)?;
+
+        Some(DateRangeMatch {
+            date_range: DateRange { start, end },
+            expression: recognized.expression,
+            text: input[recognized.range.clone()].to_string(),
+            range: recognized.range,
+        })
+    }
+}
@@ -0,0 +1,13 @@
+use chrono::NaiveDate;
+
+use crate::{date_range::DateRangeMatch, language::date_format::DateFormat, StartDayOfWeek};
+
+pub trait DateRangeParser {
+    /// Searches the first date range like `from monday to wednesday` or `next week` in `input`.
+    fn search_date_range(
+        input: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_of_week: &StartDayOfWeek,
+    ) -> Option<DateRangeMatch>;
+}
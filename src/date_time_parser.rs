@@ -0,0 +1,13 @@
+use chrono::{NaiveDateTime, NaiveTime};
+
+use crate::{language::date_format::DateFormat, StartDayOfWeek};
+
+pub trait DateTimeParser {
+    fn search_relative_date_time_expression(
+        input: &str,
+        now: &NaiveDateTime,
+        date_format: &DateFormat,
+        start_of_week: &StartDayOfWeek,
+        default_time: &NaiveTime,
+    ) -> Option<NaiveDateTime>;
+}
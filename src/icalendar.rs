@@ -0,0 +1,445 @@
+//! Serializes parsed dates into iCalendar (RFC 5545) text.
+//!
+//! Dates are written as all-day values (`VALUE=DATE`). Date times are written as floating local
+//! times without a time zone, because the parsers don't know the time zone of the input. Pass a
+//! `TZID` to `date_time_to_ical` or set the `tzid` of an `Event` to pin a date time to a time zone
+//! of the calendar.
+
+use chrono::{Datelike, Days, NaiveDate, NaiveDateTime, Weekday};
+
+use crate::{
+    date_range::DateRange,
+    language::shared::{Frequency, Recurrence},
+    StartDayOfWeek,
+};
+
+const DATE_FORMAT: &str = "%Y%m%d";
+const DATE_TIME_FORMAT: &str = "%Y%m%dT%H%M%S";
+
+/// Returns an all-day `DTSTART` line, e.g. `DTSTART;VALUE=DATE:20261205`.
+pub fn date_to_ical(date: &NaiveDate) -> String {
+    format!("DTSTART;VALUE=DATE:{}", date.format(DATE_FORMAT))
+}
+
+/// Parses an all-day `DTSTART` line into a `NaiveDate`.
+pub fn date_from_ical(line: &str) -> Option<NaiveDate> {
+    let (name, params, value) = split_property(line)?;
+
+    if name != "DTSTART" || !params.contains(&"VALUE=DATE") {
+        return None;
+    }
+
+    parse_date(value)
+}
+
+/// Returns a `DTSTART` line with a floating local time, e.g. `DTSTART:20261205T143000`.
+///
+/// With a `tzid`, the time is bound to this time zone, e.g. `DTSTART;TZID=Europe/Berlin:20261205T143000`.
+pub fn date_time_to_ical(date_time: &NaiveDateTime, tzid: Option<&str>) -> String {
+    match tzid {
+        Some(tzid) => format!(
+            "DTSTART;TZID={}:{}",
+            tzid,
+            date_time.format(DATE_TIME_FORMAT)
+        ),
+        None => format!("DTSTART:{}", date_time.format(DATE_TIME_FORMAT)),
+    }
+}
+
+/// Parses a `DTSTART` line with a date time into a `NaiveDateTime` and the `TZID`, if any.
+///
+/// UTC times (ending with `Z`) are returned as UTC date times without a `TZID`.
+pub fn date_time_from_ical(line: &str) -> Option<(NaiveDateTime, Option<String>)> {
+    let (name, params, value) = split_property(line)?;
+
+    if name != "DTSTART" {
+        return None;
+    }
+
+    let tzid = params
+        .iter()
+        .find_map(|param| param.strip_prefix("TZID="))
+        .map(|tzid| tzid.to_string());
+
+    Some((parse_date_time(value)?, tzid))
+}
+
+/// Returns the all-day `DTSTART` and `DTEND` lines of a range.
+///
+/// The `DTEND` of all-day events is exclusive, so it is the day after the end of the range.
+pub fn date_range_to_ical(date_range: &DateRange) -> Option<String> {
+    let end = date_range.end.checked_add_days(Days::new(1))?;
+
+    Some(format!(
+        "{}\r\nDTEND;VALUE=DATE:{}",
+        date_to_ical(&date_range.start),
+        end.format(DATE_FORMAT)
+    ))
+}
+
+/// Parses all-day `DTSTART` and `DTEND` lines into a `DateRange`.
+pub fn date_range_from_ical(text: &str) -> Option<DateRange> {
+    let mut start = None;
+    let mut end = None;
+
+    for line in unfold_lines(text) {
+        let (name, params, value) = split_property(&line)?;
+
+        if !params.contains(&"VALUE=DATE") {
+            continue;
+        }
+
+        match name {
+            "DTSTART" => start = parse_date(value),
+            "DTEND" => end = parse_date(value)?.checked_sub_days(Days::new(1)),
+            _ => {}
+        }
+    }
+
+    let (start, end) = (start?, end?);
+
+    if end.lt(&start) {
+        return None;
+    }
+
+    Some(DateRange { start, end })
+}
+
+/// Returns the `RRULE` line of a recurrence, e.g. `RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO;WKST=MO`.
+///
+/// The start of the week decides, which weeks of a weekly recurrence with an interval are used.
+pub fn recurrence_to_ical(recurrence: &Recurrence, start_of_week: &StartDayOfWeek) -> String {
+    let frequency = match recurrence.frequency {
+        Frequency::Daily => "DAILY",
+        Frequency::Weekly => "WEEKLY",
+        Frequency::Monthly => "MONTHLY",
+        Frequency::Yearly => "YEARLY",
+    };
+
+    let mut rule = format!("RRULE:FREQ={}", frequency);
+
+    if recurrence.interval != 1 {
+        rule.push_str(&format!(";INTERVAL={}", recurrence.interval));
+    }
+
+    if !recurrence.weekdays.is_empty() {
+        let weekdays: Vec<&str> = recurrence.weekdays.iter().map(weekday_to_ical).collect();
+        rule.push_str(&format!(";BYDAY={}", weekdays.join(",")));
+    }
+
+    if let Some(month_day) = recurrence.month_day {
+        rule.push_str(&format!(";BYMONTHDAY={}", month_day));
+    }
+
+    if recurrence.frequency == Frequency::Weekly {
+        let start_of_week = match start_of_week {
+            StartDayOfWeek::Sunday => Weekday::Sun,
+            StartDayOfWeek::Monday => Weekday::Mon,
+        };
+        rule.push_str(&format!(";WKST={}", weekday_to_ical(&start_of_week)));
+    }
+
+    rule
+}
+
+/// Parses an `RRULE` line into a `Recurrence` and the start of the week.
+///
+/// Returns `None` for rules with parts, which can't be expressed by a `Recurrence`, like `COUNT`.
+pub fn recurrence_from_ical(line: &str) -> Option<(Recurrence, StartDayOfWeek)> {
+    let (name, _params, value) = split_property(line)?;
+
+    if name != "RRULE" {
+        return None;
+    }
+
+    let mut frequency = None;
+    let mut recurrence = Recurrence {
+        frequency: Frequency::Daily,
+        interval: 1,
+        weekdays: vec![],
+        month_day: None,
+    };
+    let mut start_of_week = StartDayOfWeek::Monday;
+
+    for part in value.split(';') {
+        let (key, value) = part.split_once('=')?;
+
+        match key {
+            "FREQ" => {
+                frequency = Some(match value {
+                    "DAILY" => Frequency::Daily,
+                    "WEEKLY" => Frequency::Weekly,
+                    "MONTHLY" => Frequency::Monthly,
+                    "YEARLY" => Frequency::Yearly,
+                    _ => return None,
+                })
+            }
+            "INTERVAL" => recurrence.interval = value.parse().ok().filter(|i| *i > 0)?,
+            "BYDAY" => {
+                recurrence.weekdays = value
+                    .split(',')
+                    .map(weekday_from_ical)
+                    .collect::<Option<Vec<Weekday>>>()?
+            }
+            "BYMONTHDAY" => {
+                recurrence.month_day = Some(value.parse().ok().filter(|d| (1..=31).contains(d))?)
+            }
+            "WKST" => {
+                start_of_week = match weekday_from_ical(value)? {
+                    Weekday::Sun => StartDayOfWeek::Sunday,
+                    Weekday::Mon => StartDayOfWeek::Monday,
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    recurrence.frequency = frequency?;
+
+    Some((recurrence, start_of_week))
+}
+
+/// The date or time of an `Event`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum EventTime {
+    /// An all-day event.
+    Date(NaiveDate),
+    /// An event at a local time, which is floating unless the `Event` has a `tzid`.
+    DateTime(NaiveDateTime),
+    /// An all-day event over several days.
+    DateRange(DateRange),
+}
+
+/// A minimal calendar event, which can be written as a `VEVENT` block.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Event {
+    /// The unique id of the event.
+    pub uid: String,
+    /// The UTC time the event was created at, written as `DTSTAMP`.
+    pub timestamp: NaiveDateTime,
+    /// The title of the event.
+    pub summary: String,
+    pub time: EventTime,
+    /// The time zone of a timed event, written as `TZID` parameter, e.g. `Europe/Berlin`.
+    ///
+    /// All-day events ignore it.
+    pub tzid: Option<String>,
+    pub recurrence: Option<Recurrence>,
+    /// The start of the week of a weekly recurrence.
+    pub start_of_week: StartDayOfWeek,
+}
+
+impl Event {
+    /// Returns the event as a `VEVENT` block with CRLF line endings and folded lines.
+    pub fn to_ical(&self) -> Option<String> {
+        let time = match &self.time {
+            EventTime::Date(date) => date_to_ical(date),
+            EventTime::DateTime(date_time) => date_time_to_ical(date_time, self.tzid.as_deref()),
+            EventTime::DateRange(date_range) => date_range_to_ical(date_range)?,
+        };
+
+        let mut lines = vec![
+            String::from("BEGIN:VEVENT"),
+            format!("UID:{}", escape_text(&self.uid)),
+            format!("DTSTAMP:{}Z", self.timestamp.format(DATE_TIME_FORMAT)),
+            time,
+        ];
+
+        if let Some(recurrence) = &self.recurrence {
+            lines.push(recurrence_to_ical(recurrence, &self.start_of_week));
+        }
+
+        lines.push(format!("SUMMARY:{}", escape_text(&self.summary)));
+        lines.push(String::from("END:VEVENT"));
+
+        let folded: Vec<String> = lines
+            .iter()
+            .flat_map(|line| line.split("\r\n"))
+            .map(fold_line)
+            .collect();
+
+        Some(folded.join("\r\n") + "\r\n")
+    }
+
+    /// Parses the first `VEVENT` block of `text`.
+    ///
+    /// The `DTEND` of a timed event is ignored, because an `EventTime::DateTime` has no end.
+    pub fn from_ical(text: &str) -> Option<Event> {
+        let lines = unfold_lines(text);
+
+        let begin = lines.iter().position(|line| line == "BEGIN:VEVENT")?;
+        let end = begin
+            + lines[begin..]
+                .iter()
+                .position(|line| line == "END:VEVENT")?;
+        let lines = &lines[begin + 1..end];
+
+        let mut uid = None;
+        let mut timestamp = None;
+        let mut summary = None;
+        let mut start = None;
+        let mut has_end = false;
+        let mut recurrence = None;
+
+        for line in lines {
+            let (name, _params, value) = split_property(line)?;
+
+            match name {
+                "UID" => uid = Some(unescape_text(value)),
+                "DTSTAMP" => timestamp = parse_date_time(value.strip_suffix('Z')?),
+                "SUMMARY" => summary = Some(unescape_text(value)),
+                "DTSTART" => start = Some(line.as_str()),
+                "DTEND" => has_end = true,
+                "RRULE" => recurrence = Some(recurrence_from_ical(line)?),
+                _ => {}
+            }
+        }
+
+        let start = start?;
+        let (time, tzid) = if let Some(date) = date_from_ical(start) {
+            match has_end {
+                true => (
+                    EventTime::DateRange(date_range_from_ical(&lines.join("\r\n"))?),
+                    None,
+                ),
+                false => (EventTime::Date(date), None),
+            }
+        } else {
+            let (date_time, tzid) = date_time_from_ical(start)?;
+            (EventTime::DateTime(date_time), tzid)
+        };
+
+        let (recurrence, start_of_week) = match recurrence {
+            Some((recurrence, start_of_week)) => (Some(recurrence), start_of_week),
+            None => (None, StartDayOfWeek::Monday),
+        };
+
+        Some(Event {
+            uid: uid?,
+            timestamp: timestamp?,
+            summary: summary.unwrap_or_default(),
+            time,
+            tzid,
+            recurrence,
+            start_of_week,
+        })
+    }
+}
+
+fn weekday_to_ical(weekday: &Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn weekday_from_ical(input: &str) -> Option<Weekday> {
+    match input {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    let date = NaiveDate::parse_from_str(value, DATE_FORMAT).ok()?;
+
+    // chrono accepts dates without leading zeros, iCalendar doesn't
+    if value.len() == 8 && date.year() >= 0 {
+        Some(date)
+    } else {
+        None
+    }
+}
+
+fn parse_date_time(value: &str) -> Option<NaiveDateTime> {
+    let value = value.strip_suffix('Z').unwrap_or(value);
+
+    if value.len() != 15 {
+        return None;
+    }
+
+    NaiveDateTime::parse_from_str(value, DATE_TIME_FORMAT).ok()
+}
+
+/// Splits a content line into its name, its parameters and its value.
+fn split_property(line: &str) -> Option<(&str, Vec<&str>, &str)> {
+    let (name_and_params, value) = line.split_once(':')?;
+    let mut parts = name_and_params.split(';');
+    let name = parts.next()?;
+
+    Some((name, parts.collect(), value))
+}
+
+/// Folds lines longer than 75 octets, see RFC 5545 section 3.1.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut line_length = 0;
+
+    for c in line.chars() {
+        if line_length + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            line_length = 1;
+        }
+
+        folded.push(c);
+        line_length += c.len_utf8();
+    }
+
+    folded
+}
+
+/// Splits `text` into lines and joins folded lines.
+fn unfold_lines(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for line in text.split('\n') {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ if line.is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    lines
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn unescape_text(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') | Some('N') => unescaped.push('\n'),
+            Some(escaped) => unescaped.push(escaped),
+            None => {}
+        }
+    }
+
+    unescaped
+}
@@ -2,9 +2,10 @@
 pub enum DateFormat {
     DayMonthYear,
     MonthDayYear,
+    YearMonthDay,
 }
 
-/// Supported values are `dayMonthYear` and `monthDayYear`.
+/// Supported values are `dayMonthYear`, `monthDayYear` and `yearMonthDay`.
 ///
 /// ```
 /// use date_time_parser_multi_language::DateFormat;
@@ -12,6 +13,7 @@ pub enum DateFormat {
 /// fn main() {
 ///     assert_eq!(DateFormat::from("dayMonthYear"), DateFormat::DayMonthYear);
 ///     assert_eq!(DateFormat::from("monthDayYear"), DateFormat::MonthDayYear);
+///     assert_eq!(DateFormat::from("yearMonthDay"), DateFormat::YearMonthDay);
 /// }
 /// ```
 impl From<&str> for DateFormat {
@@ -24,6 +26,10 @@ impl From<&str> for DateFormat {
             return DateFormat::MonthDayYear;
         }
 
+        if input == "yearMonthDay" {
+            return DateFormat::YearMonthDay;
+        }
+
         DateFormat::MonthDayYear
     }
 }
@@ -41,4 +47,9 @@ mod from_str_works_for_date_format_when {
     fn month_day_year_is_parsed_correctly() {
         assert_eq!(DateFormat::from("monthDayYear"), DateFormat::MonthDayYear)
     }
+
+    #[test]
+    fn year_month_day_is_parsed_correctly() {
+        assert_eq!(DateFormat::from("yearMonthDay"), DateFormat::YearMonthDay)
+    }
 }
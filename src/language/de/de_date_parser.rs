@@ -0,0 +1,368 @@
+use std::ops::Range;
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::{
+    date_match::{DateMatch, StrippedDateExpression},
+    date_parser::DateParser,
+    language::{
+        shared::{recognize_all_date_expressions, Month, RecognizedDateExpression},
+        shared_date_parser::{
+            parse_date_month_year, parse_iso_date, parse_month_date_year, parse_year_month_day,
+        },
+        shared_title::strip_date_phrase,
+    },
+    parse_error::ParseError,
+    DateFormat, StartDayOfWeek,
+};
+
+use super::expressions::{
+    parse_date_in_week::parse_date_in_week, parse_date_in_x_weeks::parse_in_x_weeks,
+    parse_day_alone::parse_day_alone, parse_day_in_explicit_weeks::parse_day_in_explicit_week,
+    parse_day_in_relative_week::parse_day_in_relative_week, parse_in_n_months::parse_in_n_months,
+    parse_keywords::parse_keywords, parse_month_date::parse_month_date,
+    parse_relative_date::parse_relative_day,
+    parse_relative_keyword_week::parse_keyword_relative_week,
+    parse_relative_month::parse_relative_month,
+};
+
+/// The german weekdays for regular expressions.
+pub const WEEKDAYS: &str = "montag|dienstag|mittwoch|donnerstag|freitag|samstag|sonnabend|sonntag";
+
+/// The inflected forms of next, this and last for regular expressions, e.g. `nächsten` or `kommende`.
+pub const RELATIVE_WORDS: &str =
+    r"(?:übernächste|nächste|kommende|diese|letzte|vorige|vergangene)[nrsm]?";
+
+/// The german number words up to a hundred for regular expressions, including the forms of `ein`.
+pub const NUMBER_WORDS: &str = "(?:(?:ein|zwei|drei|vier|fünf|sechs|sieben|acht|neun)und)?(?:zwanzig|dreißig|dreissig|vierzig|fünfzig|sechzig|siebzig|achtzig|neunzig)|dreizehn|vierzehn|fünfzehn|sechzehn|siebzehn|achtzehn|neunzehn|einhundert|hundert|einem|einen|einer|eine|ein|zwei|zwo|drei|vier|fünf|sechs|sieben|acht|neun|zehn|elf|zwölf";
+
+/// Parses a german weekday like `Montag` or `Sonnabend`.
+pub fn parse_weekday_german(text: &str) -> Option<Weekday> {
+    match text.to_lowercase().as_ref() {
+        "montag" => Some(Weekday::Mon),
+        "dienstag" => Some(Weekday::Tue),
+        "mittwoch" => Some(Weekday::Wed),
+        "donnerstag" => Some(Weekday::Thu),
+        "freitag" => Some(Weekday::Fri),
+        "samstag" | "sonnabend" => Some(Weekday::Sat),
+        "sonntag" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Parses a german month name or its abbreviation like `Dezember` or `Dez` into a `Month`.
+pub fn parse_month_of_year_german(text: &str) -> Option<Month> {
+    match text.to_lowercase().trim_end_matches('.') {
+        "januar" | "jänner" | "jan" => Some(Month::January),
+        "februar" | "feb" => Some(Month::February),
+        "märz" | "mär" | "mrz" => Some(Month::March),
+        "april" | "apr" => Some(Month::April),
+        "mai" => Some(Month::May),
+        "juni" | "jun" => Some(Month::June),
+        "juli" | "jul" => Some(Month::July),
+        "august" | "aug" => Some(Month::August),
+        "september" | "sept" | "sep" => Some(Month::September),
+        "oktober" | "okt" => Some(Month::October),
+        "november" | "nov" => Some(Month::November),
+        "dezember" | "dez" => Some(Month::December),
+        _ => None,
+    }
+}
+
+/// Returns the relative week or month of an inflected form of next, this or last.
+///
+/// e.g. `nächsten` => 1, `übernächste` => 2, `diesen` => 0, `letzte` => -1
+pub fn relative_word_to_num(text: &str) -> Option<i32> {
+    let text = text.to_lowercase();
+    let stem = text.trim_end_matches(['n', 'r', 's', 'm']);
+
+    match stem {
+        "übernächste" => Some(2),
+        "nächste" | "kommende" => Some(1),
+        "diese" => Some(0),
+        "letzte" | "vorige" | "vergangene" => Some(-1),
+        _ => None,
+    }
+}
+
+/// Parses a german number word up to a hundred like `drei`, `zwölf` or `einundzwanzig`.
+pub fn string_to_num_german(input: &str) -> Option<i32> {
+    let input = input.to_lowercase();
+
+    let num = match input.as_ref() {
+        "ein" | "eine" | "einen" | "einem" | "einer" => 1,
+        "zwei" | "zwo" => 2,
+        "drei" => 3,
+        "vier" => 4,
+        "fünf" => 5,
+        "sechs" => 6,
+        "sieben" => 7,
+        "acht" => 8,
+        "neun" => 9,
+        "zehn" => 10,
+        "elf" => 11,
+        "zwölf" => 12,
+        "dreizehn" => 13,
+        "vierzehn" => 14,
+        "fünfzehn" => 15,
+        "sechzehn" => 16,
+        "siebzehn" => 17,
+        "achtzehn" => 18,
+        "neunzehn" => 19,
+        "zwanzig" => 20,
+        "dreißig" | "dreissig" => 30,
+        "vierzig" => 40,
+        "fünfzig" => 50,
+        "sechzig" => 60,
+        "siebzig" => 70,
+        "achtzig" => 80,
+        "neunzig" => 90,
+        "hundert" | "einhundert" => 100,
+        _ => {
+            // einundzwanzig, fünfundvierzig
+            let (units, tens) = input.split_once("und")?;
+            let units = string_to_num_german(units).filter(|units| *units < 10)?;
+            let tens = string_to_num_german(tens)
+                .filter(|tens| (20..100).contains(tens) && tens % 10 == 0)?;
+
+            tens + units
+        }
+    };
+
+    Some(num)
+}
+
+/// Prepositions which are removed together with a date phrase, like `am` in `Miete zahlen am Freitag`.
+const PREPOSITIONS: [&str; 8] = ["am", "an", "bis", "zum", "ab", "für", "um", "vor"];
+
+/// Phrases at the beginning of an input which are not part of a title.
+const LEADING_PHRASES: [&str; 5] = [
+    "erinnere mich daran,",
+    "erinnere mich daran",
+    "erinnere mich an",
+    "erinnere mich",
+    "vergiss nicht",
+];
+
+pub struct DeDateParser {}
+
+impl DeDateParser {
+    /// Dates are written day first in german, e.g. 5.12.2026.
+    pub const DATE_FORMAT: DateFormat = DateFormat::DayMonthYear;
+
+    /// Weeks start on monday in german speaking countries.
+    pub const START_DAY_OF_WEEK: StartDayOfWeek = StartDayOfWeek::Monday;
+}
+
+/// Recognizes the first german date expression in `input`.
+///
+/// The parsers are tried from the most specific to the least specific one.
+pub fn recognize_date_expression(
+    input: &str,
+    date_format: &DateFormat,
+) -> Option<RecognizedDateExpression> {
+    // heute, morgen, übermorgen, gestern, vorgestern
+    if let Some(date) = parse_keywords(input) {
+        return Some(date);
+    }
+
+    // Montag nächste Woche, nächste Woche am Montag
+    if let Some(date) = parse_day_in_relative_week(input) {
+        return Some(date);
+    }
+
+    // Montag in zwei Wochen
+    if let Some(date) = parse_day_in_explicit_week(input) {
+        return Some(date);
+    }
+
+    // nächsten Montag, kommenden Freitag, letzten Dienstag
+    if let Some(date) = parse_date_in_week(input) {
+        return Some(date);
+    }
+
+    // a single day of the week
+    if let Some(date) = parse_day_alone(input) {
+        return Some(date);
+    }
+
+    // nächste Woche, diese Woche, letzte Woche
+    if let Some(date) = parse_keyword_relative_week(input) {
+        return Some(date);
+    }
+
+    // in drei Wochen, in 3 Wochen
+    if let Some(date) = parse_in_x_weeks(input) {
+        return Some(date);
+    }
+
+    // in drei Tagen, in 3 Tagen
+    if let Some(date) = parse_relative_day(input) {
+        return Some(date);
+    }
+
+    // ISO dates like 2026-12-05, 2026-W49-3 or 2026-339 regardless of the date format
+    if let Some(date) = parse_iso_date(input) {
+        return Some(date);
+    }
+
+    // full dates in DD.MM.YYYY, MM.DD.YYYY or YYYY.MM.DD formats
+    match date_format {
+        DateFormat::DayMonthYear => {
+            if let Some(date) = parse_date_month_year(input) {
+                return Some(date);
+            }
+        }
+        DateFormat::MonthDayYear => {
+            if let Some(date) = parse_month_date_year(input) {
+                return Some(date);
+            }
+        }
+        DateFormat::YearMonthDay => {
+            if let Some(date) = parse_year_month_day(input) {
+                return Some(date);
+            }
+        }
+    }
+
+    // 5. Dezember, 5. Dez. 2026
+    if let Some(date) = parse_month_date(input) {
+        return Some(date);
+    }
+
+    // in zwei Monaten
+    if let Some(date) = parse_in_n_months(input) {
+        return Some(date);
+    }
+
+    // nächsten Monat, diesen Monat, letzten Monat
+    if let Some(date) = parse_relative_month(input) {
+        return Some(date);
+    }
+
+    None
+}
+
+/// Removes the date phrase in `range` from `text` together with the words around it which belong to the date.
+pub fn title_without_date_phrase(text: &str, range: Option<Range<usize>>) -> String {
+    match range {
+        Some(range) => strip_date_phrase(text, range, &PREPOSITIONS, &LEADING_PHRASES),
+        None => strip_date_phrase(text, text.len()..text.len(), &[], &LEADING_PHRASES),
+    }
+}
+
+/// The DeDateParser can be used to parse german date information out of strings.
+///
+/// ```
+/// use date_time_parser_multi_language::{DateParser, DeDateParser};
+/// use chrono::NaiveDate;
+//
+/// fn main() {
+///     let some_input = "Erinnere mich am 5. Dezember an den Zahnarzt.";
+///
+///     let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+///
+///     let date_match = DeDateParser::search_date_expression(some_input, &now, &DeDateParser::DATE_FORMAT, &DeDateParser::START_DAY_OF_WEEK).unwrap();
+///
+///     assert_eq!(date_match.date, NaiveDate::from_ymd_opt(2026, 12, 5).unwrap());
+///     assert_eq!(date_match.text, "5. Dezember");
+/// }
+/// ```
+impl DateParser for DeDateParser {
+    fn try_search_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Result<DateMatch, ParseError> {
+        let recognized =
+            recognize_date_expression(text, date_format).ok_or(ParseError::NoExpressionFound)?;
+
+        DateMatch::resolve(text, recognized, now, start_day_week)
+    }
+
+    fn search_all_date_expressions(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Vec<DateMatch> {
+        recognize_all_date_expressions(text, date_format, recognize_date_expression)
+            .into_iter()
+            .filter_map(|recognized| DateMatch::resolve(text, recognized, now, start_day_week).ok())
+            .collect()
+    }
+
+    fn strip_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> StrippedDateExpression {
+        let date_match = Self::search_date_expression(text, now, date_format, start_day_week);
+        let title = title_without_date_phrase(
+            text,
+            date_match
+                .as_ref()
+                .map(|date_match| date_match.range.clone()),
+        );
+
+        StrippedDateExpression { title, date_match }
+    }
+}
+
+#[cfg(test)]
+mod de_date_parser_works_when {
+    use chrono::Weekday;
+
+    use super::{
+        parse_month_of_year_german, parse_weekday_german, relative_word_to_num,
+        string_to_num_german,
+    };
+    use crate::language::shared::Month;
+
+    #[test]
+    fn weekdays_are_parsed() {
+        assert_eq!(parse_weekday_german("Montag"), Some(Weekday::Mon));
+        assert_eq!(parse_weekday_german("sonnabend"), Some(Weekday::Sat));
+        assert_eq!(parse_weekday_german("Monday"), None);
+    }
+
+    #[test]
+    fn months_are_parsed() {
+        assert_eq!(
+            parse_month_of_year_german("Dezember"),
+            Some(Month::December)
+        );
+        assert_eq!(parse_month_of_year_german("Dez."), Some(Month::December));
+        assert_eq!(parse_month_of_year_german("März"), Some(Month::March));
+        assert_eq!(parse_month_of_year_german("Jänner"), Some(Month::January));
+        assert_eq!(parse_month_of_year_german("May"), None);
+    }
+
+    #[test]
+    fn inflected_relative_words_are_parsed() {
+        assert_eq!(relative_word_to_num("nächste"), Some(1));
+        assert_eq!(relative_word_to_num("Nächsten"), Some(1));
+        assert_eq!(relative_word_to_num("kommenden"), Some(1));
+        assert_eq!(relative_word_to_num("übernächsten"), Some(2));
+        assert_eq!(relative_word_to_num("diesen"), Some(0));
+        assert_eq!(relative_word_to_num("letztes"), Some(-1));
+        assert_eq!(relative_word_to_num("vergangenen"), Some(-1));
+        assert_eq!(relative_word_to_num("morgen"), None);
+    }
+
+    #[test]
+    fn number_words_are_parsed() {
+        assert_eq!(string_to_num_german("einer"), Some(1));
+        assert_eq!(string_to_num_german("Drei"), Some(3));
+        assert_eq!(string_to_num_german("fünf"), Some(5));
+        assert_eq!(string_to_num_german("zwölf"), Some(12));
+        assert_eq!(string_to_num_german("dreizehn"), Some(13));
+        assert_eq!(string_to_num_german("Einundzwanzig"), Some(21));
+        assert_eq!(string_to_num_german("siebenundneunzig"), Some(97));
+        assert_eq!(string_to_num_german("hundert"), Some(100));
+        assert_eq!(string_to_num_german("zwölfundzwanzig"), None);
+        assert_eq!(string_to_num_german("einundzehn"), None);
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    de::de_date_parser::{parse_weekday_german, relative_word_to_num, RELATIVE_WORDS, WEEKDAYS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DATE_IN_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?P<prep>{})\s(?P<day>{})\b",
+        RELATIVE_WORDS, WEEKDAYS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_date_in_week(text: &str) -> Option<RecognizedDateExpression> {
+    // nächsten Montag, kommenden Freitag, diesen Samstag, letzten Dienstag
+
+    let caps = DATE_IN_WEEK.captures(text)?;
+
+    let relative_week = relative_word_to_num(&caps["prep"])?;
+    let day = parse_weekday_german(&caps["day"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(relative_week, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_date_in_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_date_in_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn day_in_n_weeks() {
+        assert_day_in_n_weeks("nächsten Montag", Weekday::Mon, 1);
+        assert_day_in_n_weeks("Nächsten Dienstag", Weekday::Tue, 1);
+        assert_day_in_n_weeks("nächster Mittwoch", Weekday::Wed, 1);
+        assert_day_in_n_weeks("am kommenden Freitag", Weekday::Fri, 1);
+        assert_day_in_n_weeks("übernächsten Sonntag", Weekday::Sun, 2);
+        assert_day_in_n_weeks("diesen Samstag", Weekday::Sat, 0);
+        assert_day_in_n_weeks("letzten Donnerstag", Weekday::Thu, -1);
+        assert_day_in_n_weeks("vergangenen Montag", Weekday::Mon, -1);
+    }
+
+    #[test]
+    fn range_includes_the_relative_word() {
+        let text = "Zahnarzt am kommenden Freitag";
+        let recognized = parse_date_in_week(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "kommenden Freitag");
+    }
+
+    fn assert_day_in_n_weeks(text: &str, day: Weekday, relative_week: i32) {
+        assert_eq!(
+            parse_date_in_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(relative_week, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
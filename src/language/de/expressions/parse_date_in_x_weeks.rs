@@ -0,0 +1,62 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    de::de_date_parser::{string_to_num_german, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static IN_X_WEEKS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\bin\s(?P<num>\d{{1,3}}|{})\s(?:wochen|woche)\b",
+        NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::InXWeeks(i32)`
+pub fn parse_in_x_weeks(text: &str) -> Option<RecognizedDateExpression> {
+    // in drei Wochen, in 3 Wochen, in einer Woche
+
+    let caps = IN_X_WEEKS.captures(text)?;
+
+    let num = match caps["num"].parse::<i32>() {
+        Ok(num) => num,
+        Err(_) => string_to_num_german(&caps["num"])?,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXWeeks(num),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_in_x_weeks_works_when {
+    use super::parse_in_x_weeks;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn weeks_are_parsed() {
+        assert_in_x_weeks("in 3 Wochen", 3);
+        assert_in_x_weeks("in drei Wochen", 3);
+        assert_in_x_weeks("in einer Woche", 1);
+        assert_in_x_weeks("in zwo Wochen", 2);
+    }
+
+    #[test]
+    fn no_weeks_are_found() {
+        assert_eq!(parse_in_x_weeks("in Wochen"), None);
+        assert_eq!(parse_in_x_weeks("in 3 Wochenenden"), None);
+    }
+
+    fn assert_in_x_weeks(text: &str, weeks: i32) {
+        assert_eq!(
+            parse_in_x_weeks(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXWeeks(weeks)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    de::de_date_parser::{parse_weekday_german, WEEKDAYS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DAY_ALONE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?i)\b(?P<day>{})\b", WEEKDAYS)).unwrap());
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(0, Weekday)`
+pub fn parse_day_alone(text: &str) -> Option<RecognizedDateExpression> {
+    // Samstag
+
+    let caps = DAY_ALONE.captures(text)?;
+    let day = parse_weekday_german(&caps["day"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(0, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_alone_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_alone;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn weekdays_are_parsed() {
+        assert_day("am Montag", Weekday::Mon);
+        assert_day("Dienstag", Weekday::Tue);
+        assert_day("mittwoch", Weekday::Wed);
+        assert_day("Donnerstag", Weekday::Thu);
+        assert_day("Freitag", Weekday::Fri);
+        assert_day("Samstag", Weekday::Sat);
+        assert_day("Sonnabend", Weekday::Sat);
+        assert_day("Sonntag", Weekday::Sun);
+    }
+
+    #[test]
+    fn no_weekday_is_found() {
+        assert_eq!(parse_day_alone("freitags joggen"), None);
+        assert_eq!(parse_day_alone("Friday"), None);
+    }
+
+    fn assert_day(text: &str, day: Weekday) {
+        assert_eq!(
+            parse_day_alone(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(0, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
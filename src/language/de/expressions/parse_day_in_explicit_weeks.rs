@@ -0,0 +1,74 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    de::de_date_parser::{parse_weekday_german, string_to_num_german, NUMBER_WORDS, WEEKDAYS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DAY_IN_EXPLICIT_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?P<day>{weekdays})\s(?:in\s)?(?P<num>\d{{1,3}}|{numbers})\s(?:wochen|woche)|in\s(?P<num2>\d{{1,3}}|{numbers})\s(?:wochen|woche)\s(?:am\s)?(?P<day2>{weekdays}))\b",
+        weekdays = WEEKDAYS,
+        numbers = NUMBER_WORDS
+    )).unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_day_in_explicit_week(text: &str) -> Option<RecognizedDateExpression> {
+    // Montag in zwei Wochen, in 2 Wochen am Montag
+
+    let caps = DAY_IN_EXPLICIT_WEEK.captures(text)?;
+
+    let (num_match, day_match) = match caps.name("num") {
+        Some(num_match) => (num_match, caps.name("day")?),
+        None => (caps.name("num2")?, caps.name("day2")?),
+    };
+
+    let in_weeks = match num_match.as_str().parse::<i32>() {
+        Ok(num) => num,
+        Err(_) => string_to_num_german(num_match.as_str())?,
+    };
+    let day = parse_weekday_german(day_match.as_str())?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(in_weeks, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_in_explicit_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_in_explicit_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn day_before_the_weeks_is_parsed() {
+        assert_day_in_weeks("Montag in zwei Wochen", Weekday::Mon, 2);
+        assert_day_in_weeks("Freitag in 3 Wochen", Weekday::Fri, 3);
+        assert_day_in_weeks("Dienstag in einer Woche", Weekday::Tue, 1);
+    }
+
+    #[test]
+    fn day_after_the_weeks_is_parsed() {
+        assert_day_in_weeks("in zwei Wochen am Montag", Weekday::Mon, 2);
+        assert_day_in_weeks("in 4 Wochen Sonntag", Weekday::Sun, 4);
+    }
+
+    #[test]
+    fn weeks_without_day_are_ignored() {
+        assert_eq!(parse_day_in_explicit_week("in zwei Wochen"), None);
+    }
+
+    fn assert_day_in_weeks(text: &str, day: Weekday, weeks: i32) {
+        assert_eq!(
+            parse_day_in_explicit_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(weeks, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
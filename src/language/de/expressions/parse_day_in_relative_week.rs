@@ -0,0 +1,75 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    de::de_date_parser::{parse_weekday_german, relative_word_to_num, RELATIVE_WORDS, WEEKDAYS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DAY_IN_RELATIVE_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?P<day>{weekdays})\s(?:(?:in\s)?der\s)?(?P<prep>{relative})\swoche|(?P<prep2>{relative})\swoche\s(?:am\s)?(?P<day2>{weekdays}))\b",
+        weekdays = WEEKDAYS,
+        relative = RELATIVE_WORDS
+    )).unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_day_in_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    // Montag nächste Woche, Montag der nächsten Woche, nächste Woche am Montag
+
+    let caps = DAY_IN_RELATIVE_WEEK.captures(text)?;
+
+    let (prep_match, day_match) = match caps.name("prep") {
+        Some(prep_match) => (prep_match, caps.name("day")?),
+        None => (caps.name("prep2")?, caps.name("day2")?),
+    };
+
+    let relative_week = relative_word_to_num(prep_match.as_str())?;
+    let day = parse_weekday_german(day_match.as_str())?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(relative_week, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_in_relative_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_in_relative_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn day_before_the_week_is_parsed() {
+        assert_day_in_week("Montag nächste Woche", Weekday::Mon, 1);
+        assert_day_in_week("Dienstag der nächsten Woche", Weekday::Tue, 1);
+        assert_day_in_week("Freitag in der übernächsten Woche", Weekday::Fri, 2);
+        assert_day_in_week("Mittwoch letzte Woche", Weekday::Wed, -1);
+        assert_day_in_week("Sonntag diese Woche", Weekday::Sun, 0);
+    }
+
+    #[test]
+    fn day_after_the_week_is_parsed() {
+        assert_day_in_week("nächste Woche Montag", Weekday::Mon, 1);
+        assert_day_in_week("nächste Woche am Donnerstag", Weekday::Thu, 1);
+        assert_day_in_week("kommende Woche Samstag", Weekday::Sat, 1);
+        assert_day_in_week("letzte Woche am Freitag", Weekday::Fri, -1);
+    }
+
+    #[test]
+    fn week_without_day_is_ignored() {
+        assert_eq!(parse_day_in_relative_week("nächste Woche"), None);
+    }
+
+    fn assert_day_in_week(text: &str, day: Weekday, relative_week: i32) {
+        assert_eq!(
+            parse_day_in_relative_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(relative_week, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    de::de_date_parser::{string_to_num_german, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static IN_N_MONTHS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\bin\s(?P<num>\d{{1,3}}|{})\s(?:monaten|monate|monat)\b",
+        NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+pub fn parse_in_n_months(text: &str) -> Option<RecognizedDateExpression> {
+    // in zwei Monaten, in 2 Monaten, in einem Monat
+
+    let caps = IN_N_MONTHS.captures(text)?;
+
+    let num = match caps["num"].parse::<i32>() {
+        Ok(num) => num,
+        Err(_) => string_to_num_german(&caps["num"])?,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXMonths(num),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_in_n_months_works_when {
+    use super::parse_in_n_months;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn months_are_parsed() {
+        assert_in_n_months("in 2 Monaten", 2);
+        assert_in_n_months("in zwei Monaten", 2);
+        assert_in_n_months("in einem Monat", 1);
+    }
+
+    #[test]
+    fn no_months_are_found() {
+        assert_eq!(parse_in_n_months("in Monaten"), None);
+    }
+
+    fn assert_in_n_months(text: &str, months: i32) {
+        assert_eq!(
+            parse_in_n_months(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXMonths(months)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
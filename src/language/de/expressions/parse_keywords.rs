@@ -0,0 +1,93 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::shared::{DateExpression, RecognizedDateExpression};
+
+static KEYWORDS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:(?P<morning>(?:guten|am|den|der|jeden|diesen|einen|vom)\smorgen)|(?P<this_morning>heute\smorgen)|(?P<key>heute|übermorgen|morgen|vorgestern|gestern))\b").unwrap()
+});
+
+/// Parses common keywords into an `Option` containing a `DateExpr::InNDays(i32)`.
+///
+/// `morgen` is tomorrow, unless an article or a greeting like `am` or `guten` makes it the morning.
+/// `heute Morgen` is this morning and therefore today.
+pub fn parse_keywords(text: &str) -> Option<RecognizedDateExpression> {
+    // heute, morgen, übermorgen, gestern, vorgestern, heute Morgen
+
+    KEYWORDS.captures_iter(text).find_map(|caps| {
+        let n = if caps.name("this_morning").is_some() {
+            0
+        } else {
+            match caps.name("key")?.as_str().to_lowercase().as_ref() {
+                "heute" => 0,
+                "morgen" => 1,
+                "übermorgen" => 2,
+                "gestern" => -1,
+                "vorgestern" => -2,
+                _ => return None,
+            }
+        };
+
+        Some(RecognizedDateExpression::from_captures(
+            DateExpression::InXDays(n),
+            &caps,
+        ))
+    })
+}
+
+#[cfg(test)]
+mod parse_keywords_works_when {
+    use crate::language::{
+        de::expressions::parse_keywords::parse_keywords, shared::DateExpression,
+    };
+
+    #[test]
+    fn day_keywords() {
+        assert_in_n_days("heute", 0);
+        assert_in_n_days("Morgen", 1);
+        assert_in_n_days("übermorgen", 2);
+        assert_in_n_days("Übermorgen", 2);
+        assert_in_n_days("gestern", -1);
+        assert_in_n_days("vorgestern", -2);
+    }
+
+    #[test]
+    fn keywords_inside_words_are_ignored() {
+        assert_eq!(parse_keywords("morgens joggen"), None);
+        assert_eq!(parse_keywords("heutezutage"), None);
+    }
+
+    #[test]
+    fn morning_is_no_keyword() {
+        assert_eq!(parse_keywords("Guten Morgen"), None);
+        assert_eq!(parse_keywords("Joggen am Morgen"), None);
+        assert_eq!(parse_keywords("jeden Morgen Yoga"), None);
+        assert_eq!(parse_keywords("den Morgen genießen"), None);
+        assert_in_n_days("heute Morgen", 0);
+        assert_in_n_days("Joggen am Morgen und morgen Bob anrufen", 1);
+    }
+
+    #[test]
+    fn range_of_keyword_is_returned() {
+        let recognized = parse_keywords("Bob übermorgen anrufen").unwrap();
+
+        assert_eq!(recognized.range, 4..15);
+    }
+
+    #[test]
+    fn range_of_this_morning_is_returned() {
+        let recognized = parse_keywords("heute Morgen joggen").unwrap();
+
+        assert_eq!(recognized.range, 0..12);
+    }
+
+    fn assert_in_n_days(text: &str, n: i32) {
+        assert_eq!(
+            parse_keywords(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(n)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
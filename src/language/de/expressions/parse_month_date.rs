@@ -0,0 +1,84 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    de::de_date_parser::parse_month_of_year_german,
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static MONTH_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?P<date>0?[1-9]|[12]\d|3[01])\.?\s*(?P<month>(?:januar|jänner|februar|märz|april|mai|juni|juli|august|september|oktober|november|dezember)\b|(?:jan|feb|mär|mrz|apr|jun|jul|aug|sept|sep|okt|nov|dez)\b\.?)(?:\s(?P<year>\d{4})\b)?").unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::DayInMonth(Month, u32)` or a
+/// `DateExpr::DayInMonthInYear(Month, u32, i32)`.
+pub fn parse_month_date(text: &str) -> Option<RecognizedDateExpression> {
+    // 5. Dezember, 5 Dez., 5. Dezember 2026
+
+    let caps = MONTH_DATE.captures(text)?;
+
+    let date: u32 = caps["date"].parse().unwrap();
+    let month = parse_month_of_year_german(&caps["month"])?;
+
+    let expression = match caps.name("year") {
+        Some(year) => DateExpression::DayInMonthInYear(month, date, year.as_str().parse().unwrap()),
+        None => DateExpression::DayInMonth(month, date),
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_month_date_german_works_when {
+    use super::parse_month_date;
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn day_and_month_are_parsed() {
+        assert_month_date(
+            "am 5. Dezember",
+            DateExpression::DayInMonth(Month::December, 5),
+        );
+        assert_month_date("5 Dezember", DateExpression::DayInMonth(Month::December, 5));
+        assert_month_date("24. Dez.", DateExpression::DayInMonth(Month::December, 24));
+        assert_month_date("1. März", DateExpression::DayInMonth(Month::March, 1));
+        assert_month_date("1. Mai", DateExpression::DayInMonth(Month::May, 1));
+        assert_month_date("3. Jänner", DateExpression::DayInMonth(Month::January, 3));
+    }
+
+    #[test]
+    fn day_month_and_year_are_parsed() {
+        assert_month_date(
+            "am 5. Dezember 2026",
+            DateExpression::DayInMonthInYear(Month::December, 5, 2026),
+        );
+        assert_month_date(
+            "5. Okt. 2027",
+            DateExpression::DayInMonthInYear(Month::October, 5, 2027),
+        );
+    }
+
+    #[test]
+    fn range_ends_after_the_month() {
+        let text = "Party am 5. Dezember.";
+        let recognized = parse_month_date(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "5. Dezember");
+    }
+
+    #[test]
+    fn no_date_is_found() {
+        assert_eq!(parse_month_date("5 Maibäume"), None);
+        assert_eq!(parse_month_date("Dezember"), None);
+    }
+
+    fn assert_month_date(text: &str, expected: DateExpression) {
+        assert_eq!(
+            parse_month_date(text).map(|recognized| recognized.expression),
+            Some(expected),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,65 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    de::de_date_parser::{string_to_num_german, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static RELATIVE_DAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\bin\s(?P<num>\d{{1,3}}|{})\s(?:tagen|tage|tag)\b",
+        NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNDays(i32)`
+pub fn parse_relative_day(text: &str) -> Option<RecognizedDateExpression> {
+    // in drei Tagen, in 3 Tagen, in einem Tag
+
+    let caps = RELATIVE_DAY.captures(text)?;
+
+    let num = match caps["num"].parse::<i32>() {
+        Ok(num) => num,
+        Err(_) => string_to_num_german(&caps["num"])?,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXDays(num),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_relative_day_works_when {
+    use super::parse_relative_day;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn days_are_parsed() {
+        assert_in_x_days("in 3 Tagen", 3);
+        assert_in_x_days("in drei Tagen", 3);
+        assert_in_x_days("in einem Tag", 1);
+        assert_in_x_days("in zwölf Tagen", 12);
+        assert_in_x_days("in fünfzehn Tagen", 15);
+        assert_in_x_days("in einundzwanzig Tagen", 21);
+        assert_in_x_days("In Fünf Tagen", 5);
+    }
+
+    #[test]
+    fn no_days_are_found() {
+        assert_eq!(parse_relative_day("in Tagen"), None);
+        assert_eq!(parse_relative_day("in 3 Tageszeiten"), None);
+    }
+
+    fn assert_in_x_days(text: &str, days: i32) {
+        assert_eq!(
+            parse_relative_day(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(days)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
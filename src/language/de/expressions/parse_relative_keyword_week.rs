@@ -0,0 +1,55 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    de::de_date_parser::{relative_word_to_num, RELATIVE_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static KEYWORD_RELATIVE_WEEK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?i)\b(?P<prep>{})\swoche\b", RELATIVE_WORDS)).unwrap());
+
+/// Parses a `str` into an `Option` containing a `DateExpression::InXWeeks(i32)`
+pub fn parse_keyword_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    // nächste Woche, diese Woche, letzte Woche
+
+    let caps = KEYWORD_RELATIVE_WEEK.captures(text)?;
+    let relative_week = relative_word_to_num(&caps["prep"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXWeeks(relative_week),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_keyword_relative_week_works_when {
+    use super::parse_keyword_relative_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn relative_weeks_are_parsed() {
+        assert_in_x_weeks("nächste Woche", 1);
+        assert_in_x_weeks("in der nächsten Woche", 1);
+        assert_in_x_weeks("kommende Woche", 1);
+        assert_in_x_weeks("übernächste Woche", 2);
+        assert_in_x_weeks("diese Woche", 0);
+        assert_in_x_weeks("letzte Woche", -1);
+        assert_in_x_weeks("vorige Woche", -1);
+    }
+
+    #[test]
+    fn no_week_is_found() {
+        assert_eq!(parse_keyword_relative_week("nächstes Wochenende"), None);
+    }
+
+    fn assert_in_x_weeks(text: &str, weeks: i32) {
+        assert_eq!(
+            parse_keyword_relative_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXWeeks(weeks)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,52 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    de::de_date_parser::{relative_word_to_num, RELATIVE_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static RELATIVE_MONTH: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?i)\b(?P<prep>{})\smonat\b", RELATIVE_WORDS)).unwrap());
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+pub fn parse_relative_month(text: &str) -> Option<RecognizedDateExpression> {
+    // nächsten Monat, diesen Monat, letzten Monat
+
+    let caps = RELATIVE_MONTH.captures(text)?;
+    let relative_month = relative_word_to_num(&caps["prep"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXMonths(relative_month),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_relative_month_works_when {
+    use super::parse_relative_month;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn relative_months_are_parsed() {
+        assert_in_x_months("nächsten Monat", 1);
+        assert_in_x_months("kommender Monat", 1);
+        assert_in_x_months("diesen Monat", 0);
+        assert_in_x_months("letzten Monat", -1);
+    }
+
+    #[test]
+    fn no_month_is_found() {
+        assert_eq!(parse_relative_month("nächsten Montag"), None);
+    }
+
+    fn assert_in_x_months(text: &str, months: i32) {
+        assert_eq!(
+            parse_relative_month(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXMonths(months)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
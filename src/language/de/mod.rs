@@ -0,0 +1,2 @@
+pub mod de_date_parser;
+mod expressions;
@@ -1,43 +1,68 @@
-use chrono::{Datelike, Days, Duration, Months, NaiveDate, Weekday};
-use log::debug;
-use regex::Regex;
+use std::{ops::Range, sync::LazyLock};
+
+use chrono::NaiveDate;
+use regex::{Regex, RegexSet};
 
 use crate::{
+    date_match::{DateMatch, StrippedDateExpression},
     date_parser::DateParser,
+    date_range::DateRangeMatch,
+    date_range_parser::DateRangeParser,
     language::{
-        shared::{DateExpression, Month},
-        shared_date_parser::{parse_date_month_year, parse_month_date_year},
+        shared::{
+            recognize_all_date_expressions, Month, RecognizeDateExpression,
+            RecognizedDateExpression,
+        },
+        shared_date_parser::{
+            parse_date_month_year, parse_iso_date, parse_month_date_year, parse_year_month_day,
+            DATE_MONTH_YEAR_PATTERN, ISO_DATE_PATTERN, MONTH_DATE_YEAR_PATTERN,
+            YEAR_MONTH_DAY_PATTERN,
+        },
+        shared_title::strip_date_phrase,
     },
+    parse_error::ParseError,
     recognizable::Recognizable,
+    recurrence_parser::{RecurrenceMatch, RecurrenceParser},
     DateFormat, StartDayOfWeek,
 };
 
 use super::expressions::{
-    parse_date_in_week::parse_date_in_week, parse_date_in_x_weeks::parse_in_x_weeks,
-    parse_day_alone::parse_day_alone, parse_day_in_explicit_weeks::parse_day_in_explicit_week,
-    parse_day_in_relative_week::parse_day_in_relative_week, parse_in_n_months::parse_in_n_months,
-    parse_keywords::parse_keywords, parse_month_date::parse_month_date,
-    parse_relative_date::parse_relative_day,
-    parse_relative_keywork_week::parse_keyword_relative_week,
-    parse_relative_month::parse_relative_month,
+    parse_ago::{parse_ago, AGO_PATTERN},
+    parse_date_in_week::{parse_date_in_week, DATE_IN_WEEK_PATTERN},
+    parse_date_in_x_weeks::{parse_in_x_weeks, IN_X_WEEKS_PATTERN},
+    parse_date_range::parse_date_range,
+    parse_day_alone::{parse_day_alone, DAY_ALONE_PATTERN},
+    parse_day_in_explicit_weeks::{parse_day_in_explicit_week, DAY_IN_EXPLICIT_WEEK_PATTERN},
+    parse_day_in_relative_week::{parse_day_in_relative_week, DAY_IN_RELATIVE_WEEK_PATTERN},
+    parse_day_of_month::{parse_day_of_month, DAY_OF_MONTH_PATTERN},
+    parse_in_n_months::{parse_in_n_months, IN_N_MONTHS_PATTERN},
+    parse_keywords::{parse_keywords, KEYWORDS_PATTERN},
+    parse_month_date::{parse_month_date, MONTH_DATE_PATTERN},
+    parse_month_year::{parse_month_year, MONTH_YEAR_PATTERN},
+    parse_recurrence::parse_recurrence,
+    parse_relative_date::{parse_relative_day, RELATIVE_DAY_PATTERN},
+    parse_relative_keywork_week::{parse_keyword_relative_week, KEYWORD_RELATIVE_WEEK_PATTERN},
+    parse_relative_month::{parse_relative_month, RELATIVE_MONTH_PATTERN},
+    parse_relative_year::{
+        parse_month_in_relative_year, parse_relative_year, MONTH_IN_RELATIVE_YEAR_PATTERN,
+        RELATIVE_YEAR_PATTERN,
+    },
 };
 
+static MONTH_OF_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?P<month>jan|january|feb|mar|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)(r?uary|ch|il|e|y|ust|tember|ober|ember|\b)").unwrap()
+});
+
 /// Parsing a str into a `MonthOfYear` uses english abbreviations and full names.
 impl Recognizable for Month {
     fn recognize(text: &str, _date_format: &DateFormat) -> Option<Month> {
         parse_month_of_year_english(text)
     }
-
-    fn describe() -> &'static str {
-        "month of year"
-    }
 }
 
 /// Parses a `str` into an `Option` containing a `MonthOfYear`.
 fn parse_month_of_year_english(text: &str) -> Option<Month> {
-    let re = Regex::new(r"(?i)(?P<month>jan|january|feb|mar|mar|apr|may|jun|jul|aug|sep|oct|nov|dec)(r?uary|ch|il|e|y|ust|tember|ober|ember|\b)").unwrap();
-
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = MONTH_OF_YEAR.captures(text) {
         if let Some(month_match) = caps.name("month") {
             match month_match.as_str().to_lowercase().as_ref() {
                 "jan" => return Some(Month::January),
@@ -59,8 +84,68 @@ fn parse_month_of_year_english(text: &str) -> Option<Month> {
     None
 }
 
+/// The english number words for regular expressions, like `three`, `twenty-one`, `a hundred and five`,
+/// `a couple of`, `a few`, `a dozen` or `a`.
+///
+/// This is a macro, so the patterns of the cascade can stay `&str` constants built with `concat!`.
+macro_rules! number_words {
+    () => {
+        r"(?:(?:an?|one|two|three|four|five|six|seven|eight|nine)\shundred(?:\s(?:and\s)?(?:(?:twenty|thirty|forty|fifty|sixty|seventy|eighty|ninety)(?:[-\s](?:one|two|three|four|five|six|seven|eight|nine))?|ten|eleven|twelve|thirteen|fourteen|fifteen|sixteen|seventeen|eighteen|nineteen|one|two|three|four|five|six|seven|eight|nine))?|(?:an?|one|two|three|four|five|six|seven|eight|nine|ten)\sdozen|(?:twenty|thirty|forty|fifty|sixty|seventy|eighty|ninety)(?:[-\s](?:one|two|three|four|five|six|seven|eight|nine))?|ten|eleven|twelve|thirteen|fourteen|fifteen|sixteen|seventeen|eighteen|nineteen|one|two|three|four|five|six|seven|eight|nine|(?:an?\s)?couple(?:\sof)?|(?:an?\s)?few|an?)"
+    };
+}
+
+pub(crate) use number_words;
+
+/// Parses a number in digits or english words like `15`, `fifteen`, `twenty-one`, `a hundred`,
+/// `a couple of`, `a few` or `two dozen`.
 pub fn string_to_num_english(input: &str) -> Option<i32> {
-    let num = match input {
+    let halves = string_to_halves_english(input)?;
+
+    (halves % 2 == 0).then_some(halves / 2)
+}
+
+/// Parses a number like `string_to_num_english`, which may contain a half, into the number of halves.
+///
+/// e.g. `half a` => 1, `two and a half` => 5, `three` => 6
+pub fn string_to_halves_english(input: &str) -> Option<i32> {
+    let input = input.to_lowercase();
+    let words: Vec<&str> = input
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|word| !word.is_empty() && *word != "and" && *word != "of")
+        .collect();
+
+    match words.as_slice() {
+        ["half", "a" | "an"] | ["a", "half"] => Some(1),
+        [number @ .., "a", "half"] => whole_number_english(number)?.checked_mul(2)?.checked_add(1),
+        number => whole_number_english(number)?.checked_mul(2),
+    }
+}
+
+/// Sums up the words of a whole number, e.g. `["a", "hundred", "twenty", "one"]` => 121.
+fn whole_number_english(words: &[&str]) -> Option<i32> {
+    if words.is_empty() {
+        return None;
+    }
+
+    words.iter().try_fold(0i32, |num, word| {
+        let num = match *word {
+            "a" | "an" => num.max(1),
+            "couple" => 2,
+            "few" => 3,
+            "dozen" => num.max(1).checked_mul(12)?,
+            "hundred" => num.max(1).checked_mul(100)?,
+            word => num.checked_add(match word.parse::<i32>() {
+                Ok(digits) => digits,
+                Err(_) => single_number_word_english(word)?,
+            })?,
+        };
+
+        Some(num)
+    })
+}
+
+fn single_number_word_english(word: &str) -> Option<i32> {
+    let num = match word {
         "one" => 1,
         "two" => 2,
         "three" => 3,
@@ -73,13 +158,90 @@ pub fn string_to_num_english(input: &str) -> Option<i32> {
         "ten" => 10,
         "eleven" => 11,
         "twelve" => 12,
+        "thirteen" => 13,
+        "fourteen" => 14,
+        "fifteen" => 15,
+        "sixteen" => 16,
+        "seventeen" => 17,
+        "eighteen" => 18,
+        "nineteen" => 19,
+        "twenty" => 20,
+        "thirty" => 30,
+        "forty" => 40,
+        "fifty" => 50,
+        "sixty" => 60,
+        "seventy" => 70,
+        "eighty" => 80,
+        "ninety" => 90,
         _ => return None, // Return None if it doesn't match
     };
 
-    return Some(num);
+    Some(num)
+}
+
+/// The spelled-out ordinals from `first` to `thirty-first` for regular expressions.
+///
+/// This is a macro, so the patterns of the cascade can stay `&str` constants built with `concat!`.
+macro_rules! ordinal_words {
+    () => {
+        r"(?:twenty[-\s]?(?:first|second|third|fourth|fifth|sixth|seventh|eighth|ninth)|thirty[-\s]?first|first|second|third|fourth|fifth|sixth|seventh|eighth|ninth|tenth|eleventh|twelfth|thirteenth|fourteenth|fifteenth|sixteenth|seventeenth|eighteenth|nineteenth|twentieth|thirtieth)"
+    };
+}
+
+pub(crate) use ordinal_words;
+
+/// Parses a spelled-out ordinal like `first` or `twenty-first` into its number.
+pub fn ordinal_to_num_english(input: &str) -> Option<u32> {
+    let input = input.to_lowercase();
+    let (tens, unit) = if let Some(unit) = input.strip_prefix("twenty") {
+        (20, unit.trim_start_matches(['-', ' ']))
+    } else if let Some(unit) = input.strip_prefix("thirty") {
+        (30, unit.trim_start_matches(['-', ' ']))
+    } else {
+        (0, input.as_str())
+    };
+
+    let num = match (tens, unit) {
+        (_, "first") => 1,
+        (_, "second") => 2,
+        (_, "third") => 3,
+        (_, "fourth") => 4,
+        (_, "fifth") => 5,
+        (_, "sixth") => 6,
+        (_, "seventh") => 7,
+        (_, "eighth") => 8,
+        (_, "ninth") => 9,
+        (0, "tenth") => 10,
+        (0, "eleventh") => 11,
+        (0, "twelfth") => 12,
+        (0, "thirteenth") => 13,
+        (0, "fourteenth") => 14,
+        (0, "fifteenth") => 15,
+        (0, "sixteenth") => 16,
+        (0, "seventeenth") => 17,
+        (0, "eighteenth") => 18,
+        (0, "nineteenth") => 19,
+        (0, "twentieth") => 20,
+        (0, "thirtieth") => 30,
+        _ => return None,
+    };
+
+    Some(tens + num).filter(|day| *day <= 31)
 }
 
-pub fn weekday_to_english(input: &Weekday) -> &str {
+/// Parses a day of a month like `5`, `22nd` or `twenty-second`.
+pub fn day_of_month_english(input: &str) -> Option<u32> {
+    if input.starts_with(|c: char| c.is_ascii_digit()) {
+        input.trim_end_matches(char::is_alphabetic).parse().ok()
+    } else {
+        ordinal_to_num_english(input)
+    }
+}
+
+#[cfg(test)]
+pub fn weekday_to_english(input: &chrono::Weekday) -> &str {
+    use chrono::Weekday;
+
     match input {
         Weekday::Mon => "monday",
         Weekday::Tue => "tuesday",
@@ -91,82 +253,181 @@ pub fn weekday_to_english(input: &Weekday) -> &str {
     }
 }
 
-pub struct EnDateParser {}
-
-impl Recognizable for DateExpression {
-    fn recognize(input: &str, date_format: &DateFormat) -> Option<Self> {
-        // things like today, tomorrow, yesterday
-        if let Some(date) = parse_keywords(input) {
-            return Some(date);
-        }
-
-        if let Some(date) = parse_day_in_relative_week(input) {
-            return Some(date);
-        }
-
-        if let Some(date) = parse_day_in_explicit_week(input) {
-            return Some(date);
-        }
-
-        // parses next monday or last tuesday
-        if let Some(date) = parse_date_in_week(input, date_format) {
-            return Some(date);
-        }
-
-        // parse a single day of the week
-        if let Some(date) = parse_day_alone(input) {
-            return Some(date);
-        }
+/// Prepositions which are removed together with a date phrase, like `on` in `pay rent on friday`.
+const PREPOSITIONS: [&str; 8] = ["on", "by", "in", "at", "until", "till", "before", "due"];
 
-        // next week, last week, this week
-        if let Some(date) = parse_keyword_relative_week(input, date_format) {
-            return Some(date);
-        }
+/// Phrases at the beginning of an input which are not part of a title.
+const LEADING_PHRASES: [&str; 6] = [
+    "remind me to",
+    "remind me that",
+    "remind me",
+    "don't forget to",
+    "do not forget to",
+    "remember to",
+];
 
-        // in x weeks like in three weeks or in 3 weeks
-        if let Some(date) = parse_in_x_weeks(input) {
-            return Some(date);
-        }
+pub struct EnDateParser {}
 
-        // things like in three days or in four days
-        if let Some(date) = parse_relative_day(input) {
-            return Some(date);
-        }
+impl EnDateParser {
+    /// Dates are written month first in american english, e.g. 12.5.2026.
+    pub const DATE_FORMAT: DateFormat = DateFormat::MonthDayYear;
 
-        // parses date full dates in DD.MM.YYYY or MM.DD.YYYY formats
-        match date_format {
-            DateFormat::DayMonthYear => {
-                if let Some(date) = parse_date_month_year(input) {
-                    return Some(date);
-                }
-            }
-            DateFormat::MonthDayYear => {
-                if let Some(date) = parse_month_date_year(input) {
-                    return Some(date);
-                }
-            }
-        }
+    /// Weeks start on sunday in american english.
+    pub const START_DAY_OF_WEEK: StartDayOfWeek = StartDayOfWeek::Sunday;
+}
 
-        // parses 12th of january or 5th of may
-        if let Some(date) = parse_month_date(input, date_format) {
-            return Some(date);
-        }
+/// The english date expression parsers together with their patterns.
+///
+/// The parsers are ordered from the most specific to the least specific one. Only the english recognizer
+/// is prefiltered like this, the other languages still try their parsers one after another.
+const PARSERS: [(&str, RecognizeDateExpression); 20] = [
+    // things like today, tomorrow, yesterday
+    (KEYWORDS_PATTERN, |input, _| parse_keywords(input)),
+    // 3 days ago, two weeks ago, a month ago
+    (AGO_PATTERN, |input, _| parse_ago(input)),
+    // monday next week, next week on monday
+    (DAY_IN_RELATIVE_WEEK_PATTERN, |input, _| {
+        parse_day_in_relative_week(input)
+    }),
+    // monday in 2 weeks, in two weeks on monday
+    (DAY_IN_EXPLICIT_WEEK_PATTERN, |input, _| {
+        parse_day_in_explicit_week(input)
+    }),
+    // parses next monday or last tuesday
+    (DATE_IN_WEEK_PATTERN, parse_date_in_week),
+    // parse a single day of the week
+    (DAY_ALONE_PATTERN, |input, _| parse_day_alone(input)),
+    // next week, last week, this week
+    (KEYWORD_RELATIVE_WEEK_PATTERN, parse_keyword_relative_week),
+    // in x weeks like in three weeks or in 3 weeks
+    (IN_X_WEEKS_PATTERN, |input, _| parse_in_x_weeks(input)),
+    // things like in three days or in four days
+    (RELATIVE_DAY_PATTERN, |input, _| parse_relative_day(input)),
+    // ISO dates like 2026-12-05, 2026-W49-3 or 2026-339 regardless of the date format
+    (ISO_DATE_PATTERN, |input, _| parse_iso_date(input)),
+    // parses date full dates in DD.MM.YYYY, MM.DD.YYYY or YYYY.MM.DD formats
+    (
+        DATE_MONTH_YEAR_PATTERN,
+        |input, date_format| match date_format {
+            DateFormat::DayMonthYear => parse_date_month_year(input),
+            _ => None,
+        },
+    ),
+    (
+        MONTH_DATE_YEAR_PATTERN,
+        |input, date_format| match date_format {
+            DateFormat::MonthDayYear => parse_month_date_year(input),
+            _ => None,
+        },
+    ),
+    (
+        YEAR_MONTH_DAY_PATTERN,
+        |input, date_format| match date_format {
+            DateFormat::YearMonthDay => parse_year_month_day(input),
+            _ => None,
+        },
+    ),
+    // in december next year, june 5th next year
+    (MONTH_IN_RELATIVE_YEAR_PATTERN, parse_month_in_relative_year),
+    // parses 12th of january or 5th of may
+    (MONTH_DATE_PATTERN, parse_month_date),
+    // june 2027
+    (MONTH_YEAR_PATTERN, parse_month_year),
+    // the 22nd, on the 5th
+    (DAY_OF_MONTH_PATTERN, |input, _| parse_day_of_month(input)),
+    // parses in n months
+    (IN_N_MONTHS_PATTERN, |input, _| parse_in_n_months(input)),
+    // parse next month, last month or this month
+    (RELATIVE_MONTH_PATTERN, |input, _| {
+        parse_relative_month(input)
+    }),
+    // parse next year, last year, this time next year or in 2 years
+    (RELATIVE_YEAR_PATTERN, |input, _| parse_relative_year(input)),
+];
+
+/// Finds the parsers whose pattern matches an input, so only these have to run.
+static PREFILTER: LazyLock<RegexSet> =
+    LazyLock::new(|| RegexSet::new(PARSERS.iter().map(|(pattern, _)| pattern)).unwrap());
+
+/// Recognizes the first english date expression in `input`.
+///
+/// The parsers are tried from the most specific to the least specific one.
+pub fn recognize_date_expression(
+    input: &str,
+    date_format: &DateFormat,
+) -> Option<RecognizedDateExpression> {
+    let candidates = PREFILTER.matches(input);
+
+    candidates
+        .iter()
+        .find_map(|index| PARSERS[index].1(input, date_format))
+}
 
-        // parses in n months
-        if let Some(date) = parse_in_n_months(input) {
-            return Some(date);
-        }
+/// Date ranges like `from monday to wednesday`, `3.-7.12.2026`, `until friday` or `next week` are
+/// recognized by the EnDateParser too.
+///
+/// ```
+/// use date_time_parser_multi_language::{DateFormat, DateRange, DateRangeParser, EnDateParser, StartDayOfWeek};
+/// use chrono::NaiveDate;
+//
+/// fn main() {
+///     let some_input = "I'm on vacation from june 3rd to june 7th.";
+///
+///     let now = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+///
+///     let range = EnDateParser::search_date_range(some_input, &now, &DateFormat::DayMonthYear, &StartDayOfWeek::Monday).unwrap();
+///
+///     assert_eq!(range.date_range, DateRange {
+///         start: NaiveDate::from_ymd_opt(2025, 6, 3).unwrap(),
+///         end: NaiveDate::from_ymd_opt(2025, 6, 7).unwrap(),
+///     });
+/// }
+/// ```
+impl DateRangeParser for EnDateParser {
+    fn search_date_range(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Option<DateRangeMatch> {
+        let recognized = parse_date_range(text, date_format)?;
 
-        // parse next month, last month or this month
-        if let Some(date) = parse_relative_month(input) {
-            return Some(date);
-        }
+        DateRangeMatch::resolve(text, recognized, now, start_day_week)
+    }
+}
 
-        None
+/// Recurring dates like `every monday`, `every other week`, `each 1st of the month` or `daily`
+/// are recognized by the EnDateParser too.
+///
+/// ```
+/// use date_time_parser_multi_language::{EnDateParser, RecurrenceParser, StartDayOfWeek};
+/// use chrono::NaiveDate;
+//
+/// fn main() {
+///     let some_input = "Water the plants every other week on monday.";
+///
+///     let recurrence = EnDateParser::search_recurrence(some_input).unwrap().recurrence;
+///
+///     let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+///     let dates: Vec<NaiveDate> = recurrence.occurrences(&now, &StartDayOfWeek::Sunday).take(2).collect();
+///
+///     assert_eq!(dates, vec![
+///         NaiveDate::from_ymd_opt(2024, 12, 2).unwrap(),
+///         NaiveDate::from_ymd_opt(2024, 12, 16).unwrap(),
+///     ]);
+/// }
+/// ```
+impl RecurrenceParser for EnDateParser {
+    fn search_recurrence(text: &str) -> Option<RecurrenceMatch> {
+        parse_recurrence(text)
     }
+}
 
-    fn describe() -> &'static str {
-        ""
+/// Removes the date phrase in `range` from `text` together with the words around it which belong to the date.
+pub fn title_without_date_phrase(text: &str, range: Option<Range<usize>>) -> String {
+    match range {
+        Some(range) => strip_date_phrase(text, range, &PREPOSITIONS, &LEADING_PHRASES),
+        None => strip_date_phrase(text, text.len()..text.len(), &[], &LEADING_PHRASES),
     }
 }
 
@@ -184,98 +445,184 @@ impl Recognizable for DateExpression {
 ///     let date = EnDateParser::search_relative_date_expression(some_input, &now, &DateFormat::DayMonthYear, &StartDayOfWeek::Monday);
 ///
 ///     println!("I found the date {:?}", date);
+///
+///     let date_match = EnDateParser::search_date_expression(some_input, &now, &DateFormat::DayMonthYear, &StartDayOfWeek::Monday).unwrap();
+///
+///     assert_eq!(date_match.text, "tomorrow");
+///     assert_eq!(date_match.range, 40..48);
 /// }
 /// ```
 impl DateParser for EnDateParser {
-    fn search_relative_date_expression(
+    fn try_search_date_expression(
         text: &str,
         now: &NaiveDate,
         date_format: &DateFormat,
         start_day_week: &StartDayOfWeek,
-    ) -> Option<NaiveDate> {
-        if let Some(date_expr) = DateExpression::recognize(text, date_format) {
-            match date_expr {
-                DateExpression::InXDays(days) => {
-                    debug!("InXDays; days: {}", days);
-
-                    return Some(now.checked_add_days(Days::new(days as u64)).unwrap());
-                }
-
-                DateExpression::DayInMonth(month, day) => {
-                    debug!("DayInMonth; Month: {:?}; Day: {}", month, day);
-
-                    let new_date = NaiveDate::from_ymd_opt(now.year(), month as u32, day);
-
-                    match new_date {
-                        Some(date) => {
-                            if date.lt(&now) {
-                                return date.checked_add_months(Months::new(12));
-                            } else {
-                                return Some(date);
-                            }
-                        }
-                        _ => {
-                            return None;
-                        }
-                    }
-                }
-
-                DateExpression::DayInMonthInYear(month, day, year) => {
-                    debug!(
-                        "DayInMonthInYear; Month: {:?}; Day: {}; Year: {}",
-                        month, day, year
-                    );
-                    return NaiveDate::from_ymd_opt(year, month as u32, day);
-                }
-
-                DateExpression::InXWeeks(weeks) => {
-                    debug!("InXWeeks; Weeks {}", weeks);
-
-                    let mut difference = 7 * (weeks as i32);
-
-                    difference -= match start_day_week {
-                        StartDayOfWeek::Sunday => now.weekday().num_days_from_sunday() as i32,
-                        StartDayOfWeek::Monday => now.weekday().num_days_from_monday() as i32,
-                    };
-
-                    let dur = Duration::days(difference as i64);
-                    return Some(now.checked_add_signed(dur).unwrap());
-                }
-
-                DateExpression::DayInXWeeks(weeks, weekday) => {
-                    debug!("DayInXWeeks; Weeks: {}; Weekday: {:?}", weeks, weekday);
-
-                    let mut difference = 7 * (weeks as i32);
-
-                    difference += match start_day_week {
-                        StartDayOfWeek::Sunday => {
-                            (weekday.num_days_from_sunday() as i32)
-                                - (now.weekday().num_days_from_sunday() as i32)
-                        }
-                        StartDayOfWeek::Monday => {
-                            (weekday.num_days_from_monday() as i32)
-                                - (now.weekday().num_days_from_monday() as i32)
-                        }
-                    };
-
-                    let dur = Duration::days(difference as i64);
-                    return Some(now.checked_add_signed(dur).unwrap());
-                }
-
-                DateExpression::InXMonths(months) => {
-                    debug!("InXMonths; Months {} ", months);
-
-                    let now_month = now.month();
-                    let to_month = (now_month as i32) + months;
-                    return NaiveDate::from_ymd_opt(now.year(), to_month as u32, now.day());
-                }
-
-                DateExpression::InMonthInYear(month, year) => {
-                    debug!("DayInXWeeks; Months: {:?}; Year: {} ", month, year);
-                    return NaiveDate::from_ymd_opt(year, month as u32, 1);
-                }
-            }
-        }
-        None
+    ) -> Result<DateMatch, ParseError> {
+        let recognized =
+            recognize_date_expression(text, date_format).ok_or(ParseError::NoExpressionFound)?;
+
+        DateMatch::resolve(text, recognized, now, start_day_week)
+    }
+
+    fn search_all_date_expressions(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Vec<DateMatch> {
+        recognize_all_date_expressions(text, date_format, recognize_date_expression)
+            .into_iter()
+            .filter_map(|recognized| DateMatch::resolve(text, recognized, now, start_day_week).ok())
+            .collect()
+    }
+
+    fn strip_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> StrippedDateExpression {
+        let date_match = Self::search_date_expression(text, now, date_format, start_day_week);
+        let title = title_without_date_phrase(
+            text,
+            date_match
+                .as_ref()
+                .map(|date_match| date_match.range.clone()),
+        );
+
+        StrippedDateExpression { title, date_match }
+    }
+}
+
+#[cfg(test)]
+mod en_date_parser_works_when {
+    use super::{
+        day_of_month_english, ordinal_to_num_english, string_to_halves_english,
+        string_to_num_english,
+    };
+
+    #[test]
+    fn number_words_are_parsed() {
+        assert_eq!(string_to_num_english("three"), Some(3));
+        assert_eq!(string_to_num_english("15"), Some(15));
+        assert_eq!(string_to_num_english("Fifteen"), Some(15));
+        assert_eq!(string_to_num_english("twenty-one"), Some(21));
+        assert_eq!(string_to_num_english("ninety nine"), Some(99));
+        assert_eq!(string_to_num_english("a"), Some(1));
+        assert_eq!(string_to_num_english("an"), Some(1));
+        assert_eq!(string_to_num_english("a hundred"), Some(100));
+        assert_eq!(string_to_num_english("two hundred and five"), Some(205));
+        assert_eq!(string_to_num_english("one hundred twenty-one"), Some(121));
+        assert_eq!(string_to_num_english("a couple of"), Some(2));
+        assert_eq!(string_to_num_english("couple"), Some(2));
+        assert_eq!(string_to_num_english("a few"), Some(3));
+        assert_eq!(string_to_num_english("a dozen"), Some(12));
+        assert_eq!(string_to_num_english("two dozen"), Some(24));
+    }
+
+    #[test]
+    fn halves_are_parsed() {
+        assert_eq!(string_to_halves_english("half a"), Some(1));
+        assert_eq!(string_to_halves_english("a half"), Some(1));
+        assert_eq!(string_to_halves_english("two and a half"), Some(5));
+        assert_eq!(string_to_halves_english("2 and a half"), Some(5));
+        assert_eq!(string_to_halves_english("three"), Some(6));
+        assert_eq!(string_to_num_english("half a"), None);
+    }
+
+    #[test]
+    fn other_words_are_no_numbers() {
+        assert_eq!(string_to_num_english(""), None);
+        assert_eq!(string_to_num_english("some"), None);
+        assert_eq!(string_to_num_english("twenty-first"), None);
+        assert_eq!(string_to_halves_english("half"), None);
+    }
+
+    #[test]
+    fn spelled_out_ordinals_are_parsed() {
+        assert_eq!(ordinal_to_num_english("first"), Some(1));
+        assert_eq!(ordinal_to_num_english("Twelfth"), Some(12));
+        assert_eq!(ordinal_to_num_english("twentieth"), Some(20));
+        assert_eq!(ordinal_to_num_english("twenty-first"), Some(21));
+        assert_eq!(ordinal_to_num_english("twenty second"), Some(22));
+        assert_eq!(ordinal_to_num_english("twentyninth"), Some(29));
+        assert_eq!(ordinal_to_num_english("thirtieth"), Some(30));
+        assert_eq!(ordinal_to_num_english("thirty-first"), Some(31));
+    }
+
+    #[test]
+    fn invalid_ordinals_are_not_parsed() {
+        assert_eq!(ordinal_to_num_english("thirty-second"), None);
+        assert_eq!(ordinal_to_num_english("twenty-tenth"), None);
+        assert_eq!(ordinal_to_num_english("forty-first"), None);
+        assert_eq!(ordinal_to_num_english("one"), None);
+    }
+
+    #[test]
+    fn days_of_month_are_parsed() {
+        assert_eq!(day_of_month_english("5"), Some(5));
+        assert_eq!(day_of_month_english("22nd"), Some(22));
+        assert_eq!(day_of_month_english("3rd"), Some(3));
+        assert_eq!(day_of_month_english("twenty-first"), Some(21));
+        assert_eq!(day_of_month_english("nd"), None);
+    }
+}
+
+#[cfg(test)]
+mod recognize_date_expression_works_when {
+    use chrono::Weekday;
+
+    use super::recognize_date_expression;
+    use crate::{
+        language::shared::{DateExpression, Month},
+        DateFormat,
+    };
+
+    #[test]
+    fn the_most_specific_parser_wins() {
+        assert_recognized("next monday", DateExpression::DayInXWeeks(1, Weekday::Mon));
+        assert_recognized(
+            "monday next week",
+            DateExpression::DayInXWeeks(1, Weekday::Mon),
+        );
+        assert_recognized("monday", DateExpression::DayInXWeeks(0, Weekday::Mon));
+        assert_recognized("call Bob tomorrow on monday", DateExpression::InXDays(1));
+    }
+
+    #[test]
+    fn the_date_format_selects_the_numeric_parser() {
+        assert_eq!(
+            recognize_date_expression("5.12", &DateFormat::DayMonthYear)
+                .map(|recognized| recognized.expression),
+            Some(DateExpression::DayInMonth(Month::December, 5))
+        );
+        assert_eq!(
+            recognize_date_expression("5.12", &DateFormat::MonthDayYear)
+                .map(|recognized| recognized.expression),
+            Some(DateExpression::DayInMonth(Month::May, 12))
+        );
+    }
+
+    #[test]
+    fn nothing_is_recognized_without_a_date() {
+        assert_eq!(
+            recognize_date_expression("nothing to see here", &DateFormat::DayMonthYear),
+            None
+        );
+        assert_eq!(
+            recognize_date_expression("", &DateFormat::DayMonthYear),
+            None
+        );
+    }
+
+    fn assert_recognized(text: &str, expected: DateExpression) {
+        assert_eq!(
+            recognize_date_expression(text, &DateFormat::DayMonthYear)
+                .map(|recognized| recognized.expression),
+            Some(expected),
+            "Failed for input: {}",
+            text
+        )
     }
 }
@@ -0,0 +1,64 @@
+use chrono::{Days, NaiveDateTime, NaiveTime};
+
+use crate::{
+    date_parser::DateParser, date_time_parser::DateTimeParser, time_parser::TimeParser, DateFormat,
+    StartDayOfWeek,
+};
+
+use super::{en_date_parser::EnDateParser, en_time_parser::EnTimeParser};
+
+pub struct EnDateTimeParser {}
+
+/// The EnDateTimeParser can be used to parse english date and time information out of strings.
+///
+/// The date is resolved by the `EnDateParser` and the time by the `EnTimeParser`.
+/// When the input only contains a date, the `default_time` is used.
+/// When the input only contains a time, the next occurrence of that time after `now` is returned.
+///
+/// ```
+/// use date_time_parser_multi_language::{DateFormat, DateTimeParser, EnDateTimeParser, StartDayOfWeek};
+/// use chrono::{NaiveDate, NaiveTime};
+//
+/// fn main() {
+///     let some_input = "Remind me tomorrow at 3pm to call Bob.";
+///
+///     let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap().and_hms_opt(10, 0, 0).unwrap();
+///     let default_time = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+///
+///     let date_time = EnDateTimeParser::search_relative_date_time_expression(some_input, &now, &DateFormat::DayMonthYear, &StartDayOfWeek::Monday, &default_time);
+///
+///     assert_eq!(date_time, NaiveDate::from_ymd_opt(2024, 12, 2).unwrap().and_hms_opt(15, 0, 0));
+/// }
+/// ```
+impl DateTimeParser for EnDateTimeParser {
+    fn search_relative_date_time_expression(
+        input: &str,
+        now: &NaiveDateTime,
+        date_format: &DateFormat,
+        start_of_week: &StartDayOfWeek,
+        default_time: &NaiveTime,
+    ) -> Option<NaiveDateTime> {
+        let date = EnDateParser::search_relative_date_expression(
+            input,
+            &now.date(),
+            date_format,
+            start_of_week,
+        );
+        let time = EnTimeParser::search_time(input);
+
+        match (date, time) {
+            (Some(date), Some(time)) => Some(date.and_time(time)),
+            (Some(date), None) => Some(date.and_time(*default_time)),
+            (None, Some(time)) => {
+                let date_time = now.date().and_time(time);
+
+                if date_time.lt(now) {
+                    date_time.checked_add_days(Days::new(1))
+                } else {
+                    Some(date_time)
+                }
+            }
+            (None, None) => None,
+        }
+    }
+}
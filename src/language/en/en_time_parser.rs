@@ -1 +1,86 @@
+use chrono::NaiveTime;
 
+use crate::time_parser::TimeParser;
+
+use super::expressions::{
+    parse_time_keywords::parse_time_keywords, parse_time_of_day::parse_time_of_day,
+    parse_time_relative_to_hour::parse_time_relative_to_hour,
+};
+
+/// Converts an hour into the 24 hour clock.
+///
+/// Without a meridiem (`a` or `p`), the hour is expected to be on the 24 hour clock already.
+pub fn hour_to_24_hour_clock(hour: u32, meridiem: Option<&str>) -> Option<u32> {
+    let Some(meridiem) = meridiem else {
+        return if hour < 24 { Some(hour) } else { None };
+    };
+
+    if !(1..=12).contains(&hour) {
+        return None;
+    }
+
+    match meridiem.to_lowercase().as_ref() {
+        "a" => Some(hour % 12),
+        "p" => Some(hour % 12 + 12),
+        _ => None,
+    }
+}
+
+pub struct EnTimeParser {}
+
+/// The EnTimeParser can be used to parse english time information out of strings.
+///
+/// ```
+/// use date_time_parser_multi_language::{EnTimeParser, TimeParser};
+/// use chrono::NaiveTime;
+//
+/// fn main() {
+///     let some_input = "Remind me to call Bob at 5pm.";
+///
+///     let time = EnTimeParser::search_time(some_input);
+///
+///     assert_eq!(time, NaiveTime::from_hms_opt(17, 0, 0));
+/// }
+/// ```
+impl TimeParser for EnTimeParser {
+    fn search_time(input: &str) -> Option<NaiveTime> {
+        // noon, midnight
+        if let Some(time) = parse_time_keywords(input) {
+            return Some(time);
+        }
+
+        // half past nine, quarter to 8
+        if let Some(time) = parse_time_relative_to_hour(input) {
+            return Some(time);
+        }
+
+        // 17:30, 5pm, at 17
+        if let Some(time) = parse_time_of_day(input) {
+            return Some(time);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod hour_to_24_hour_clock_works_when {
+    use super::hour_to_24_hour_clock;
+
+    #[test]
+    fn hours_without_meridiem_are_kept() {
+        assert_eq!(hour_to_24_hour_clock(0, None), Some(0));
+        assert_eq!(hour_to_24_hour_clock(17, None), Some(17));
+        assert_eq!(hour_to_24_hour_clock(24, None), None);
+    }
+
+    #[test]
+    fn hours_with_meridiem_are_converted() {
+        assert_eq!(hour_to_24_hour_clock(12, Some("a")), Some(0));
+        assert_eq!(hour_to_24_hour_clock(1, Some("A")), Some(1));
+        assert_eq!(hour_to_24_hour_clock(12, Some("p")), Some(12));
+        assert_eq!(hour_to_24_hour_clock(5, Some("P")), Some(17));
+        assert_eq!(hour_to_24_hour_clock(13, Some("p")), None);
+        assert_eq!(hour_to_24_hour_clock(0, Some("a")), None);
+    }
+}
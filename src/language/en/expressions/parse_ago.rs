@@ -0,0 +1,83 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    en::en_date_parser::{number_words, string_to_num_english},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+pub const AGO_PATTERN: &str = concat!(
+    r"(?i)\b(?P<num>\d{1,3}|",
+    number_words!(),
+    r")\s(?P<unit>day|week|month|year)s?\sago\b"
+);
+
+static AGO: LazyLock<Regex> = LazyLock::new(|| Regex::new(AGO_PATTERN).unwrap());
+
+/// Parses past expressions like `3 days ago` or `a month ago` into negative offsets.
+pub fn parse_ago(text: &str) -> Option<RecognizedDateExpression> {
+    // 3 days ago, two weeks ago, a month ago, a year ago
+
+    let caps = AGO.captures(text)?;
+
+    let num = string_to_num_english(&caps["num"])?;
+
+    let expression = match caps["unit"].to_lowercase().as_ref() {
+        "day" => DateExpression::InXDays(-num),
+        "week" => DateExpression::InXDays(-7 * num),
+        "month" => DateExpression::InXMonths(-num),
+        "year" => DateExpression::InXYears(-num),
+        _ => return None,
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_ago_works_when {
+    use super::parse_ago;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn days_ago_are_parsed() {
+        assert_ago("I called Bob 3 days ago", DateExpression::InXDays(-3));
+        assert_ago("one day ago", DateExpression::InXDays(-1));
+        assert_ago("a day ago", DateExpression::InXDays(-1));
+        assert_ago("a few days ago", DateExpression::InXDays(-3));
+        assert_ago("twenty-one days ago", DateExpression::InXDays(-21));
+    }
+
+    #[test]
+    fn weeks_months_and_years_ago_are_parsed() {
+        assert_ago("two weeks ago", DateExpression::InXDays(-14));
+        assert_ago("a month ago", DateExpression::InXMonths(-1));
+        assert_ago("5 Months ago", DateExpression::InXMonths(-5));
+        assert_ago("a year ago", DateExpression::InXYears(-1));
+        assert_ago("2 years ago", DateExpression::InXYears(-2));
+    }
+
+    #[test]
+    fn the_range_covers_the_expression() {
+        assert_eq!(
+            parse_ago("I called Bob 3 days ago.").map(|recognized| recognized.range),
+            Some(13..23)
+        );
+    }
+
+    #[test]
+    fn no_past_expression_is_found() {
+        assert_eq!(parse_ago("in 3 days"), None);
+        assert_eq!(parse_ago("3 days"), None);
+        assert_eq!(parse_ago("agony"), None);
+    }
+
+    fn assert_ago(text: &str, expected: DateExpression) {
+        assert_eq!(
+            parse_ago(text).map(|recognized| recognized.expression),
+            Some(expected),
+            "failed to parse {}",
+            text
+        );
+    }
+}
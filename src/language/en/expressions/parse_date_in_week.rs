@@ -34,18 +34,29 @@ Modifications by Outlawraspberry UG (haftungsbeschränkt)
 
 */
 
+use std::sync::LazyLock;
+
 use chrono::Weekday;
 use regex::Regex;
 
-use crate::{language::shared::DateExpression, recognizable::Recognizable, DateFormat};
+use crate::{
+    language::shared::{DateExpression, RecognizedDateExpression},
+    recognizable::Recognizable,
+    DateFormat,
+};
+
+pub const DATE_IN_WEEK_PATTERN: &str = r"(?i)(?P<prep>next|last|this)\s(?P<day>\w+)";
+
+static DATE_IN_WEEK: LazyLock<Regex> = LazyLock::new(|| Regex::new(DATE_IN_WEEK_PATTERN).unwrap());
 
 /// Parses a `str` into an `Option` containing a `DateExpression::InWeek(i8, Weekday)`
-pub fn parse_date_in_week(text: &str, date_format: &DateFormat) -> Option<DateExpression> {
+pub fn parse_date_in_week(
+    text: &str,
+    date_format: &DateFormat,
+) -> Option<RecognizedDateExpression> {
     // sat, this saturday, next saturday, last saturday, this sat,
 
-    let re = Regex::new(r"(?i)(?P<prep>next|last|this)\s(?P<day>\w+)").unwrap();
-
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = DATE_IN_WEEK.captures(text) {
         if let Some(prep_match) = caps.name("prep") {
             let relative_week = match prep_match.as_str().to_lowercase().as_ref() {
                 "next" => 1,
@@ -58,7 +69,10 @@ pub fn parse_date_in_week(text: &str, date_format: &DateFormat) -> Option<DateEx
                 let day_str = day_match.as_str();
 
                 if let Some(day) = Weekday::recognize(day_str, date_format) {
-                    return Some(DateExpression::DayInXWeeks(relative_week, day));
+                    return Some(RecognizedDateExpression::from_captures(
+                        DateExpression::DayInXWeeks(relative_week, day),
+                        &caps,
+                    ));
                 }
             }
         }
@@ -82,7 +96,8 @@ mod parse_date_in_week_works_when {
 
     fn assert_day_in_n_weeks(text: &str, day: Weekday, relative_week: i32) {
         assert_eq!(
-            parse_date_in_week(text, &DateFormat::DayMonthYear),
+            parse_date_in_week(text, &DateFormat::DayMonthYear)
+                .map(|recognized| recognized.expression),
             Some(DateExpression::DayInXWeeks(relative_week, day))
         )
     }
@@ -1,22 +1,37 @@
+use std::sync::LazyLock;
+
 use regex::Regex;
 
-use crate::language::{en::en_date_parser::string_to_num_english, shared::DateExpression};
+use crate::language::{
+    en::en_date_parser::{number_words, string_to_num_english},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+pub const IN_X_WEEKS_PATTERN: &str = concat!(
+    r"(?i)(in\s(?P<num>([\d]{1,3}|",
+    number_words!(),
+    r"))\s(weeks?))"
+);
+
+static IN_X_WEEKS: LazyLock<Regex> = LazyLock::new(|| Regex::new(IN_X_WEEKS_PATTERN).unwrap());
 
 /// Parses a `str` into an `Option` containing a `DateExpression::InWeek(i8, Weekday)`
-pub fn parse_in_x_weeks(text: &str) -> Option<DateExpression> {
+pub fn parse_in_x_weeks(text: &str) -> Option<RecognizedDateExpression> {
     // sat, this saturday, next saturday, last saturday, this sat,
 
-    let re = Regex::new(r"(in\s(?P<num>([\d]{1,3}|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve))\s(weeks?))").unwrap();
-
-    if let Some(caps) = re.captures(&String::from(text).trim().to_lowercase()) {
+    if let Some(caps) = IN_X_WEEKS.captures(text) {
         if let Some(num_match) = caps.name("num") {
-            if let Ok(num) = num_match.as_str().parse::<i32>() {
-                return Some(DateExpression::InXWeeks(num));
+            let num = if let Ok(num) = num_match.as_str().parse::<i32>() {
+                num
             } else {
-                let num_str = num_match.as_str();
-                let num = string_to_num_english(num_str).unwrap();
-                return Some(DateExpression::InXWeeks(num));
-            }
+                let num_str = num_match.as_str().to_lowercase();
+                string_to_num_english(&num_str)?
+            };
+
+            return Some(RecognizedDateExpression::from_captures(
+                DateExpression::InXWeeks(num),
+                &caps,
+            ));
         }
     }
 
@@ -56,10 +71,16 @@ mod parse_relative_date_works_when {
             ("in ten weeks", Some(DateExpression::InXWeeks(10))),
             ("in eleven weeks", Some(DateExpression::InXWeeks(11))),
             ("in twelve weeks", Some(DateExpression::InXWeeks(12))),
+            ("in thirteen weeks", Some(DateExpression::InXWeeks(13))),
+            ("in fifteen weeks", Some(DateExpression::InXWeeks(15))),
+            ("in twenty-one weeks", Some(DateExpression::InXWeeks(21))),
+            ("in a hundred weeks", Some(DateExpression::InXWeeks(100))),
+            ("in a couple of weeks", Some(DateExpression::InXWeeks(2))),
+            ("in a few weeks", Some(DateExpression::InXWeeks(3))),
         ];
 
         for (input, expected) in cases {
-            let result = parse_in_x_weeks(input);
+            let result = parse_in_x_weeks(input).map(|recognized| recognized.expression);
             assert_eq!(result, expected, "Failed for input: {}", input);
         }
     }
@@ -69,7 +90,7 @@ mod parse_relative_date_works_when {
         // Test cases for invalid inputs
         let invalid_cases = vec![
             "in zero weeks",
-            "in thirteen weeks",
+            "in some weeks",
             "in weeks",
             "in one",
             "in twelve",
@@ -77,12 +98,15 @@ mod parse_relative_date_works_when {
         ];
 
         for input in invalid_cases {
-            let result = parse_in_x_weeks(input);
+            let result = parse_in_x_weeks(input).map(|recognized| recognized.expression);
             assert_eq!(result, None, "Expected None for input: {}", input);
         }
     }
 
     fn assert_in_n_days(text: &str, n: i32) {
-        assert_eq!(parse_in_x_weeks(text), Some(DateExpression::InXWeeks(n)))
+        assert_eq!(
+            parse_in_x_weeks(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXWeeks(n))
+        )
     }
 }
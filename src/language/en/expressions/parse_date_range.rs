@@ -0,0 +1,270 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::{
+    language::{
+        en::en_date_parser::recognize_date_expression,
+        shared::{
+            num_to_month, recognize_all_date_expressions, DateExpression, DateRangeExpression,
+            RecognizedDateExpression, RecognizedDateRangeExpression,
+        },
+    },
+    DateFormat,
+};
+
+static DAY_RANGE_IN_MONTH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[^\d./])(?P<range>(?P<from>0?[1-9]|[12][0-9]|3[01])\.?\s?[-–]\s?(?P<to>0?[1-9]|[12][0-9]|3[01])[./](?P<month>0?[1-9]|1[0-2])(?:[./](?P<year>\d{4}))?)\b").unwrap()
+});
+
+static CONNECTOR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^\s*(?:-|–|to|till|until|through|thru)\s*$").unwrap());
+
+static AND: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)^\s*and\s*$").unwrap());
+
+static FROM: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\bfrom\s*$").unwrap());
+
+static BETWEEN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)\bbetween\s*$").unwrap());
+
+static UNTIL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(?:until|till|through|thru)\s*$").unwrap());
+
+/// Parses a `str` into an `Option` containing a `DateRangeExpression`.
+pub fn parse_date_range(
+    text: &str,
+    date_format: &DateFormat,
+) -> Option<RecognizedDateRangeExpression> {
+    // 3.-7.12.2026
+    if let Some(range) = parse_day_range_in_month(text, date_format) {
+        return Some(range);
+    }
+
+    let recognized = recognize_all_date_expressions(text, date_format, recognize_date_expression);
+
+    // from monday to wednesday, 5.12 - 7.12, between monday and wednesday
+    if let Some(range) = parse_from_to(text, &recognized) {
+        return Some(range);
+    }
+
+    // until friday
+    if let Some(range) = parse_until(text, &recognized) {
+        return Some(range);
+    }
+
+    // next week, in 2 weeks
+    recognized
+        .into_iter()
+        .find_map(|recognized| match recognized.expression {
+            DateExpression::InXWeeks(weeks) => Some(RecognizedDateRangeExpression {
+                expression: DateRangeExpression::WholeWeek(weeks),
+                range: recognized.range,
+            }),
+            _ => None,
+        })
+}
+
+/// Parses day ranges within one month like 3.-7.12.2026 or 3-7.12.
+fn parse_day_range_in_month(
+    text: &str,
+    date_format: &DateFormat,
+) -> Option<RecognizedDateRangeExpression> {
+    if *date_format != DateFormat::DayMonthYear {
+        return None;
+    }
+
+    let caps = DAY_RANGE_IN_MONTH.captures(text)?;
+
+    let from: u32 = caps["from"].parse().unwrap();
+    let to: u32 = caps["to"].parse().unwrap();
+    let month = num_to_month(caps["month"].parse().unwrap())?;
+
+    let expression = match caps.name("year") {
+        Some(year) => {
+            let year: i32 = year.as_str().parse().unwrap();
+
+            DateRangeExpression::FromTo(
+                DateExpression::DayInMonthInYear(month.clone(), from, year),
+                DateExpression::DayInMonthInYear(month, to, year),
+            )
+        }
+        None => DateRangeExpression::FromTo(
+            DateExpression::DayInMonth(month.clone(), from),
+            DateExpression::DayInMonth(month, to),
+        ),
+    };
+
+    Some(RecognizedDateRangeExpression {
+        expression,
+        range: caps.name("range").unwrap().range(),
+    })
+}
+
+/// Parses two date expressions joined by a connector like `to`, `through` or `-`.
+fn parse_from_to(
+    text: &str,
+    recognized: &[RecognizedDateExpression],
+) -> Option<RecognizedDateRangeExpression> {
+    for pair in recognized.windows(2) {
+        let (start, end) = (&pair[0], &pair[1]);
+
+        let between_text = &text[start.range.end..end.range.start];
+        let before_text = &text[..start.range.start];
+
+        let range_start = if CONNECTOR.is_match(between_text) {
+            FROM.find(before_text)
+                .map(|found| found.start())
+                .unwrap_or(start.range.start)
+        } else if AND.is_match(between_text) {
+            match BETWEEN.find(before_text) {
+                Some(found) => found.start(),
+                None => continue,
+            }
+        } else {
+            continue;
+        };
+
+        return Some(RecognizedDateRangeExpression {
+            expression: DateRangeExpression::FromTo(
+                start.expression.clone(),
+                end.expression.clone(),
+            ),
+            range: range_start..end.range.end,
+        });
+    }
+
+    None
+}
+
+/// Parses a date expression preceded by `until`, `till` or `through`.
+fn parse_until(
+    text: &str,
+    recognized: &[RecognizedDateExpression],
+) -> Option<RecognizedDateRangeExpression> {
+    recognized.iter().find_map(|end| {
+        let found = UNTIL.find(&text[..end.range.start])?;
+
+        Some(RecognizedDateRangeExpression {
+            expression: DateRangeExpression::Until(end.expression.clone()),
+            range: found.start()..end.range.end,
+        })
+    })
+}
+
+#[cfg(test)]
+mod parse_date_range_works_when {
+    use chrono::Weekday;
+
+    use super::parse_date_range;
+    use crate::{
+        language::shared::{DateExpression, DateRangeExpression, Month},
+        DateFormat,
+    };
+
+    #[test]
+    fn from_to_ranges_are_parsed() {
+        assert_range(
+            "vacation from june 3rd to june 7th",
+            DateRangeExpression::FromTo(
+                DateExpression::DayInMonth(Month::June, 3),
+                DateExpression::DayInMonth(Month::June, 7),
+            ),
+            "from june 3rd to june 7th",
+        );
+        assert_range(
+            "vacation from monday through wednesday",
+            DateRangeExpression::FromTo(
+                DateExpression::DayInXWeeks(0, Weekday::Mon),
+                DateExpression::DayInXWeeks(0, Weekday::Wed),
+            ),
+            "from monday through wednesday",
+        );
+        assert_range(
+            "vacation 5.12 - 7.12",
+            DateRangeExpression::FromTo(
+                DateExpression::DayInMonth(Month::December, 5),
+                DateExpression::DayInMonth(Month::December, 7),
+            ),
+            "5.12 - 7.12",
+        );
+    }
+
+    #[test]
+    fn between_ranges_are_parsed() {
+        assert_range(
+            "I'm away between monday and wednesday",
+            DateRangeExpression::FromTo(
+                DateExpression::DayInXWeeks(0, Weekday::Mon),
+                DateExpression::DayInXWeeks(0, Weekday::Wed),
+            ),
+            "between monday and wednesday",
+        );
+    }
+
+    #[test]
+    fn day_ranges_in_a_month_are_parsed() {
+        assert_range(
+            "vacation 3.-7.12.2026",
+            DateRangeExpression::FromTo(
+                DateExpression::DayInMonthInYear(Month::December, 3, 2026),
+                DateExpression::DayInMonthInYear(Month::December, 7, 2026),
+            ),
+            "3.-7.12.2026",
+        );
+        assert_range(
+            "vacation 3-7.12",
+            DateRangeExpression::FromTo(
+                DateExpression::DayInMonth(Month::December, 3),
+                DateExpression::DayInMonth(Month::December, 7),
+            ),
+            "3-7.12",
+        );
+    }
+
+    #[test]
+    fn until_ranges_are_parsed() {
+        assert_range(
+            "I'm away until friday",
+            DateRangeExpression::Until(DateExpression::DayInXWeeks(0, Weekday::Fri)),
+            "until friday",
+        );
+    }
+
+    #[test]
+    fn whole_weeks_are_parsed() {
+        assert_range(
+            "I'm away next week",
+            DateRangeExpression::WholeWeek(1),
+            "next week",
+        );
+    }
+
+    #[test]
+    fn no_range_is_found() {
+        let invalid_cases = vec![
+            "I'm free monday and wednesday",
+            "call me tomorrow",
+            "nothing to see",
+        ];
+
+        for input in invalid_cases {
+            assert_eq!(
+                parse_date_range(input, &DateFormat::DayMonthYear),
+                None,
+                "Expected None for input: {}",
+                input
+            );
+        }
+    }
+
+    fn assert_range(text: &str, expected: DateRangeExpression, expected_text: &str) {
+        let recognized = parse_date_range(text, &DateFormat::DayMonthYear)
+            .unwrap_or_else(|| panic!("Failed to parse a range from {}", text));
+
+        assert_eq!(
+            recognized.expression, expected,
+            "Failed for input: {}",
+            text
+        );
+        assert_eq!(&text[recognized.range], expected_text);
+    }
+}
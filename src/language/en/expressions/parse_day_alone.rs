@@ -35,26 +35,33 @@ Modifications by Outlawraspberry UG (haftungsbeschränkt)
 
 */
 
+use std::sync::LazyLock;
+
 use chrono::Weekday;
 use regex::Regex;
 
-use crate::language::shared::DateExpression;
+use crate::language::shared::{DateExpression, RecognizedDateExpression};
+
+pub const DAY_ALONE_PATTERN: &str =
+    r"(?i)(?P<day>mon|tue|wed|thu|fri|sat|sun)(r?day|r?sday|nesday|urday)?\b";
+
+static DAY_ALONE: LazyLock<Regex> = LazyLock::new(|| Regex::new(DAY_ALONE_PATTERN).unwrap());
 
 /// Parses a `str` into an `Option` containing a `DateExpression::InWeek(i8, Weekday)`
-pub fn parse_day_alone(text: &str) -> Option<DateExpression> {
+pub fn parse_day_alone(text: &str) -> Option<RecognizedDateExpression> {
     // saturday
 
-    let re = Regex::new(r"(?i)(?P<day>mon|tue|wed|thu|fri|sat|sun)(r?day|r?sday|nesday|urday)?\b")
-        .unwrap();
-
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = DAY_ALONE.captures(text) {
         if let Some(day_match) = caps.name("day") {
             let d = day_match
                 .as_str()
                 .to_lowercase()
                 .parse::<Weekday>()
                 .unwrap();
-            return Some(DateExpression::DayInXWeeks(0, d));
+            return Some(RecognizedDateExpression::from_captures(
+                DateExpression::DayInXWeeks(0, d),
+                &caps,
+            ));
         }
     }
 
@@ -85,7 +92,7 @@ mod parse_day_alone_works_when {
 
     fn assert_day(input: &str, expected_day: Weekday) {
         assert_eq!(
-            parse_day_alone(input),
+            parse_day_alone(input).map(|recognized| recognized.expression),
             Some(DateExpression::DayInXWeeks(0, expected_day)),
             "expected {} to be {:?}",
             input,
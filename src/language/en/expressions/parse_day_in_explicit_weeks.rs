@@ -1,56 +1,49 @@
+use std::sync::LazyLock;
+
 use chrono::Weekday;
 use regex::Regex;
 
-use crate::language::{en::en_date_parser::string_to_num_english, shared::DateExpression};
-
-pub fn parse_day_in_explicit_week(text: &str) -> Option<DateExpression> {
-    let re = Regex::new(r"(?i)((?P<day>mon|tue|wed|thu|fri|sat|sun)(r?day|r?sday|nesday|urday)?\s(in\s)?((?P<num>([\d]{1,3}|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve))\s(weeks?))\b|(in\s(?P<num2>([\d]{1,3}|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve))\s(weeks?))\s(on\s)?(?P<day2>mon|tue|wed|thu|fri|sat|sun)(r?day|r?sday|nesday|urday)?\b)")
+use crate::language::{
+    en::en_date_parser::{number_words, string_to_num_english},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+pub const DAY_IN_EXPLICIT_WEEK_PATTERN: &str = concat!(
+    r"(?i)((?P<day>mon|tue|wed|thu|fri|sat|sun)(r?day|r?sday|nesday|urday)?\s(in\s)?((?P<num>([\d]{1,3}|",
+    number_words!(),
+    r"))\s(weeks?))\b|(in\s(?P<num2>([\d]{1,3}|",
+    number_words!(),
+    r"))\s(weeks?))\s(on\s)?(?P<day2>mon|tue|wed|thu|fri|sat|sun)(r?day|r?sday|nesday|urday)?\b)"
+);
+
+static DAY_IN_EXPLICIT_WEEK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(DAY_IN_EXPLICIT_WEEK_PATTERN).unwrap());
+
+pub fn parse_day_in_explicit_week(text: &str) -> Option<RecognizedDateExpression> {
+    let caps = DAY_IN_EXPLICIT_WEEK.captures(text)?;
+
+    let (num_match, day_match) = match caps.name("num") {
+        Some(num_match) => (num_match, caps.name("day")?),
+        None => (caps.name("num2")?, caps.name("day2")?),
+    };
+
+    let in_weeks = if let Ok(num) = num_match.as_str().parse::<i32>() {
+        num
+    } else {
+        let num_str = num_match.as_str().to_lowercase();
+        string_to_num_english(&num_str)?
+    };
+
+    let d = day_match
+        .as_str()
+        .to_lowercase()
+        .parse::<Weekday>()
         .unwrap();
 
-    if let Some(caps) = re.captures(&String::from(text).trim().to_lowercase()) {
-        if let Some(num_match) = caps.name("num") {
-            let in_weeks = if let Ok(num) = num_match.as_str().parse::<i32>() {
-                num
-            } else {
-                let num_str = num_match.as_str();
-                string_to_num_english(num_str).unwrap()
-            };
-
-            if let Some(caps) = re.captures(text) {
-                if let Some(day_match) = caps.name("day") {
-                    let d = day_match
-                        .as_str()
-                        .to_lowercase()
-                        .parse::<Weekday>()
-                        .unwrap();
-                    return Some(DateExpression::DayInXWeeks(in_weeks, d));
-                }
-            }
-        }
-    }
-    if let Some(caps) = re.captures(&String::from(text).trim().to_lowercase()) {
-        if let Some(num_match) = caps.name("num2") {
-            let in_weeks = if let Ok(num) = num_match.as_str().parse::<i32>() {
-                num
-            } else {
-                let num_str = num_match.as_str();
-                string_to_num_english(num_str).unwrap()
-            };
-
-            if let Some(caps) = re.captures(text) {
-                if let Some(day_match) = caps.name("day2") {
-                    let d = day_match
-                        .as_str()
-                        .to_lowercase()
-                        .parse::<Weekday>()
-                        .unwrap();
-                    return Some(DateExpression::DayInXWeeks(in_weeks, d));
-                }
-            }
-        }
-    }
-
-    None
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(in_weeks, d),
+        &caps,
+    ))
 }
 
 #[cfg(test)]
@@ -108,9 +101,16 @@ mod parse_day_in_relative_week_works_when {
         }
     }
 
+    #[test]
+    fn number_words_are_parsed() {
+        assert_in_n_days("monday in a couple of weeks", 2, Weekday::Mon);
+        assert_in_n_days("in fifteen weeks on friday", 15, Weekday::Fri);
+        assert_in_n_days("tuesday in twenty-one weeks", 21, Weekday::Tue);
+    }
+
     fn assert_in_n_days(text: &str, in_weeks: i32, weekday: Weekday) {
         assert_eq!(
-            parse_day_in_explicit_week(text),
+            parse_day_in_explicit_week(text).map(|recognized| recognized.expression),
             Some(DateExpression::DayInXWeeks(in_weeks, weekday)),
             "Failed to parse {:?} from {}",
             Some(DateExpression::DayInXWeeks(in_weeks, weekday)),
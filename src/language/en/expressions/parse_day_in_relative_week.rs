@@ -1,57 +1,40 @@
+use std::sync::LazyLock;
+
 use chrono::Weekday;
 use regex::Regex;
 
-use crate::language::shared::DateExpression;
+use crate::language::shared::{DateExpression, RecognizedDateExpression};
 
-pub fn parse_day_in_relative_week(text: &str) -> Option<DateExpression> {
-    let re = Regex::new(r"(?i)((?P<day>mon|tue|wed|thu|fri|sat|sun)(r?day|r?sday|nesday|urday)?\s(?P<prep>last|this|next)\sweek\b|(?P<prep2>last|this|next)\sweek\s(on\s)?(?P<day2>mon|tue|wed|thu|fri|sat|sun)(r?day|r?sday|nesday|urday)?\b)")
-        .unwrap();
+pub const DAY_IN_RELATIVE_WEEK_PATTERN: &str = r"(?i)((?P<day>mon|tue|wed|thu|fri|sat|sun)(r?day|r?sday|nesday|urday)?\s(?P<prep>last|this|next)\sweek\b|(?P<prep2>last|this|next)\sweek\s(on\s)?(?P<day2>mon|tue|wed|thu|fri|sat|sun)(r?day|r?sday|nesday|urday)?\b)";
 
-    if let Some(caps) = re.captures(text) {
-        if let Some(prep_match) = caps.name("prep") {
-            let relative_week = match prep_match.as_str().to_lowercase().as_ref() {
-                "next" => 1,
-                "last" => -1,
-                "this" => 0,
-                _ => 0,
-            };
-
-            if let Some(caps) = re.captures(text) {
-                if let Some(day_match) = caps.name("day") {
-                    let d = day_match
-                        .as_str()
-                        .to_lowercase()
-                        .parse::<Weekday>()
-                        .unwrap();
-                    return Some(DateExpression::DayInXWeeks(relative_week, d));
-                }
-            }
-        }
-    }
+static DAY_IN_RELATIVE_WEEK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(DAY_IN_RELATIVE_WEEK_PATTERN).unwrap());
 
-    if let Some(caps) = re.captures(text) {
-        if let Some(prep_match) = caps.name("prep2") {
-            let relative_week = match prep_match.as_str().to_lowercase().as_ref() {
-                "next" => 1,
-                "last" => -1,
-                "this" => 0,
-                _ => 0,
-            };
-
-            if let Some(caps) = re.captures(text) {
-                if let Some(day_match) = caps.name("day2") {
-                    let d = day_match
-                        .as_str()
-                        .to_lowercase()
-                        .parse::<Weekday>()
-                        .unwrap();
-                    return Some(DateExpression::DayInXWeeks(relative_week, d));
-                }
-            }
-        }
-    }
+pub fn parse_day_in_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    let caps = DAY_IN_RELATIVE_WEEK.captures(text)?;
+
+    let (prep_match, day_match) = match caps.name("prep") {
+        Some(prep_match) => (prep_match, caps.name("day")?),
+        None => (caps.name("prep2")?, caps.name("day2")?),
+    };
+
+    let relative_week = match prep_match.as_str().to_lowercase().as_ref() {
+        "next" => 1,
+        "last" => -1,
+        "this" => 0,
+        _ => 0,
+    };
+
+    let d = day_match
+        .as_str()
+        .to_lowercase()
+        .parse::<Weekday>()
+        .unwrap();
 
-    None
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(relative_week, d),
+        &caps,
+    ))
 }
 
 #[cfg(test)]
@@ -141,7 +124,7 @@ mod parse_day_in_relative_week_works_when {
 
     fn assert_in_n_days(text: &str, in_weeks: i32, weekday: Weekday) {
         assert_eq!(
-            parse_day_in_relative_week(text),
+            parse_day_in_relative_week(text).map(|recognized| recognized.expression),
             Some(DateExpression::DayInXWeeks(in_weeks, weekday)),
             "Failed to parse {:?} from {}",
             Some(DateExpression::DayInXWeeks(in_weeks, weekday)),
@@ -0,0 +1,103 @@
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+
+use crate::language::shared::{DateExpression, RecognizedDateExpression};
+
+pub const DAY_OF_MONTH_PATTERN: &str = concat!(
+    r"(?i)\b(?:(?P<on>on\s)(?:the\s)?|the\s)(?P<ordinal>(?P<day>[12][0-9]|3[01]|0?[1-9])(?:st|nd|rd|th))\b",
+    r"(?:\s(?P<next>[a-z]+)\b)?"
+);
+
+/// The words which may follow a day of the month. Other words, like `floor` in `on the 5th floor`,
+/// are counted by the ordinal instead.
+const FOLLOWING_WORDS: [&str; 23] = [
+    "day", "at", "and", "or", "but", "to", "for", "with", "from", "until", "till", "by", "in",
+    "on", "before", "after", "then", "please", "i", "we", "you", "they", "is",
+];
+
+static DAY_OF_MONTH: LazyLock<Regex> = LazyLock::new(|| Regex::new(DAY_OF_MONTH_PATTERN).unwrap());
+
+/// Parses a day without a month like `the 22nd` into an `Option` containing a `DateExpr::NextDayOfMonth(u32)`.
+///
+/// Spelled-out ordinals like `the first` are left out, because they are rarely meant as a date.
+pub fn parse_day_of_month(text: &str) -> Option<RecognizedDateExpression> {
+    // the 22nd, on the 5th, on 10th, but not on the 5th floor
+
+    DAY_OF_MONTH
+        .captures_iter(text)
+        .find_map(|caps| day_of_month(&caps))
+}
+
+fn day_of_month(caps: &Captures) -> Option<RecognizedDateExpression> {
+    if let Some(next) = caps.name("next") {
+        let next = next.as_str().to_lowercase();
+
+        if !FOLLOWING_WORDS.contains(&next.as_str()) {
+            return None;
+        }
+    }
+
+    let day = caps["day"].parse().ok()?;
+    // the preposition isn't part of the expression
+    let start = caps.name("on").map_or(caps.get(0)?.start(), |on| on.end());
+
+    Some(RecognizedDateExpression {
+        expression: DateExpression::NextDayOfMonth(day),
+        range: start..caps.name("ordinal")?.end(),
+    })
+}
+
+#[cfg(test)]
+mod parse_day_of_month_works_when {
+    use super::parse_day_of_month;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn days_without_a_month_are_parsed() {
+        assert_day_of_month("pay rent on the 1st", 1);
+        assert_day_of_month("the 22nd", 22);
+        assert_day_of_month("Do something on 10th", 10);
+        assert_day_of_month("The 31st", 31);
+    }
+
+    #[test]
+    fn the_range_excludes_the_preposition() {
+        assert_eq!(
+            parse_day_of_month("pay rent on the 1st").map(|recognized| recognized.range),
+            Some(12..19)
+        );
+        assert_eq!(
+            parse_day_of_month("pay rent on 1st").map(|recognized| recognized.range),
+            Some(12..15)
+        );
+    }
+
+    #[test]
+    fn no_day_of_month_is_found() {
+        assert_eq!(parse_day_of_month("my 5th attempt"), None);
+        assert_eq!(parse_day_of_month("the 32nd"), None);
+        assert_eq!(parse_day_of_month("the first step"), None);
+        assert_eq!(parse_day_of_month("the 5"), None);
+        assert_eq!(parse_day_of_month("meet me at 10 on the 5th floor"), None);
+        assert_eq!(parse_day_of_month("on the 3rd try"), None);
+    }
+
+    #[test]
+    fn words_after_the_day_are_allowed() {
+        assert_day_of_month("on the 5th at 10", 5);
+        assert_day_of_month("on the 5th, on the 6th floor", 5);
+        assert_day_of_month("on the 6th floor or on the 5th", 5);
+        assert_day_of_month("pay rent on the 1st please", 1);
+        assert_day_of_month("The 22nd Day", 22);
+    }
+
+    fn assert_day_of_month(text: &str, expected_day: u32) {
+        assert_eq!(
+            parse_day_of_month(text).map(|recognized| recognized.expression),
+            Some(DateExpression::NextDayOfMonth(expected_day)),
+            "failed to parse {}",
+            text
+        )
+    }
+}
@@ -36,19 +36,34 @@ Modifications by Outlawraspberry UG (haftungsbeschränkt)
 
 */
 
+use std::sync::LazyLock;
+
 use regex::Regex;
 
-use crate::language::shared::DateExpression;
+use crate::language::{
+    en::en_date_parser::{number_words, string_to_num_english},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+pub const IN_N_MONTHS_PATTERN: &str = concat!(
+    r"(?i)(in\s(?P<num>\d{1,3}|",
+    number_words!(),
+    r")\s(months?))"
+);
+
+static IN_N_MONTHS: LazyLock<Regex> = LazyLock::new(|| Regex::new(IN_N_MONTHS_PATTERN).unwrap());
 
 /// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
-pub fn parse_in_n_months(text: &str) -> Option<DateExpression> {
-    // in 2 months
+pub fn parse_in_n_months(text: &str) -> Option<RecognizedDateExpression> {
+    // in 2 months, in six months, in a couple of months
 
-    let re = Regex::new(r"(in\s(?P<num>\d{1,3})\s(months?))").unwrap();
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = IN_N_MONTHS.captures(text) {
         if let Some(num_match) = caps.name("num") {
-            let num: i32 = num_match.as_str().parse().unwrap();
-            return Some(DateExpression::InXMonths(num));
+            let num = string_to_num_english(num_match.as_str())?;
+            return Some(RecognizedDateExpression::from_captures(
+                DateExpression::InXMonths(num),
+                &caps,
+            ));
         }
     }
 
@@ -63,11 +78,15 @@ mod parse_in_n_months_works_when {
     fn relative_month_tests() {
         assert_relative_month("in 4 months", 4);
         assert_relative_month("in 1 month", 1);
+        assert_relative_month("in six months", 6);
+        assert_relative_month("In a month", 1);
+        assert_relative_month("in a couple of months", 2);
+        assert_relative_month("in eighteen months", 18);
     }
 
     fn assert_relative_month(text: &str, expected_n: i32) {
         assert_eq!(
-            parse_in_n_months(text),
+            parse_in_n_months(text).map(|recognized| recognized.expression),
             Some(DateExpression::InXMonths(expected_n))
         )
     }
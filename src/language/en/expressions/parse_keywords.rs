@@ -34,25 +34,35 @@ Modifications by Outlawraspberry UG (haftungsbeschränkt)
 
 */
 
+use std::sync::LazyLock;
+
 use regex::Regex;
 
-use crate::language::shared::DateExpression;
+use crate::language::shared::{DateExpression, RecognizedDateExpression};
 
-/// Parses common keywords into an `Option` containing a `DateExpr::InNDays(i32)`.
-pub fn parse_keywords(text: &str) -> Option<DateExpression> {
-    // today, tomorrow, yesterday
+pub const KEYWORDS_PATTERN: &str = r"(?i)\b(?P<key>(?:the\s)?day\s(?:before\syesterday|after\stomorrow)|today|tomorrow|yesterday)\b";
 
-    let re = Regex::new(r"(?i)\b(?P<key>today|tomorrow|yesterday)\b").unwrap();
+static KEYWORDS: LazyLock<Regex> = LazyLock::new(|| Regex::new(KEYWORDS_PATTERN).unwrap());
 
-    if let Some(caps) = re.captures(text) {
+/// Parses common keywords into an `Option` containing a `DateExpr::InNDays(i32)`.
+pub fn parse_keywords(text: &str) -> Option<RecognizedDateExpression> {
+    // today, tomorrow, yesterday, the day before yesterday, the day after tomorrow
+
+    if let Some(caps) = KEYWORDS.captures(text) {
         if let Some(key_match) = caps.name("key") {
-            let n = match key_match.as_str().to_lowercase().as_ref() {
-                "today" => 0,
-                "tomorrow" => 1,
-                "yesterday" => -1,
+            let key = key_match.as_str().to_lowercase();
+            let n = match key.split_whitespace().collect::<Vec<_>>().as_slice() {
+                [.., "day", "before", "yesterday"] => -2,
+                [.., "day", "after", "tomorrow"] => 2,
+                ["today"] => 0,
+                ["tomorrow"] => 1,
+                ["yesterday"] => -1,
                 _ => 0,
             };
-            return Some(DateExpression::InXDays(n));
+            return Some(RecognizedDateExpression::from_captures(
+                DateExpression::InXDays(n),
+                &caps,
+            ));
         }
     }
 
@@ -70,9 +80,25 @@ mod parse_keywords_works_when {
         assert_in_n_days("tomorrow", 1);
         assert_in_n_days("yesterday", -1);
         assert_in_n_days("today", 0);
+        assert_in_n_days("the day before yesterday", -2);
+        assert_in_n_days("day after tomorrow", 2);
     }
 
     fn assert_in_n_days(text: &str, n: i32) {
-        assert_eq!(parse_keywords(text), Some(DateExpression::InXDays(n)))
+        assert_eq!(
+            parse_keywords(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(n))
+        )
+    }
+
+    #[test]
+    fn range_of_keyword_is_returned() {
+        let recognized = parse_keywords("call Bob tomorrow about taxes").unwrap();
+
+        assert_eq!(recognized.range, 9..17);
+
+        let recognized = parse_keywords("I met Bob the day before yesterday").unwrap();
+
+        assert_eq!(recognized.range, 10..34);
     }
 }
@@ -34,25 +34,45 @@ Modifications by Outlawraspberry UG (haftungsbeschränkt)
 
 */
 
+use std::sync::LazyLock;
+
 use regex::Regex;
 
-use crate::language::shared::{DateExpression, Month};
+use crate::language::en::en_date_parser::{day_of_month_english, ordinal_words};
+use crate::language::shared::{
+    date_in_written_year, DateExpression, Month, RecognizedDateExpression,
+};
 use crate::recognizable::Recognizable;
 use crate::DateFormat;
 
-/// Parses a `str` into an `Option` containing a `DateExpr::InMonth(MonthOfYear, u32)`.
-pub fn parse_month_date(text: &str, date_format: &DateFormat) -> Option<DateExpression> {
-    //june 1, june 1st
+pub const MONTH_DATE_PATTERN: &str = concat!(
+    r"(?i)(?:\bthe\s)?\b(?P<date>\d{1,2}(?:st|nd|rd|th)?|",
+    ordinal_words!(),
+    r")\s*(of\s*)?(?P<month>jan|january|feb|mar|april|may|jun|jul|aug|sep|oct|nov|dec)(r?uary|ch|il|e|y|ust|tember|ober|ember|\b)(?:,?\s(?P<year>\d{4}|['’]\d{2})\b)?|(?P<month2>jan|january|feb|mar|april|may|jun|jul|aug|sep|oct|nov|dec)(r?uary|ch|il|e|y|ust|tember|ober|ember|\b)\s*(?:the\s)?(?P<date2>\d{1,2}(?:st|nd|rd|th)?|",
+    ordinal_words!(),
+    r")\b(?:,?\s(?P<year2>\d{4}|['’]\d{2})\b)?"
+);
+
+static MONTH_DATE: LazyLock<Regex> = LazyLock::new(|| Regex::new(MONTH_DATE_PATTERN).unwrap());
 
-    let re = Regex::new(r"(?i)(?P<date>\d{1,2})(th)?\s*(of\s*)?(?P<month>jan|january|feb|mar|april|may|jun|jul|aug|sep|oct|nov|dec)(r?uary|ch|il|e|y|ust|tember|ober|ember|\b)|(?P<month2>jan|january|feb|mar|april|may|jun|jul|aug|sep|oct|nov|dec)(r?uary|ch|il|e|y|ust|tember|ober|ember|\b)\s*(?P<date2>\d{1,2})(th)?").unwrap();
+/// Parses a `str` into an `Option` containing a `DateExpr::InMonth(MonthOfYear, u32)`
+/// or a `DateExpr::DayInMonthInYear(MonthOfYear, u32, i32)`.
+/// Years written like `'26` give a `DateExpr::DayInMonthInTwoDigitYear(MonthOfYear, u32, u32)`.
+pub fn parse_month_date(text: &str, date_format: &DateFormat) -> Option<RecognizedDateExpression> {
+    //june 1, june 1st, june 1st 2027, 1st of june, 2027, the 22nd of may, the first of april
 
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = MONTH_DATE.captures(text) {
         if let Some(month_match) = caps.name("month").or(caps.name("month2")) {
             if let Some(date_match) = caps.name("date").or(caps.name("date2")) {
-                let date: u32 = date_match.as_str().parse().unwrap();
+                let date = day_of_month_english(date_match.as_str())?;
                 let month = month_match.as_str();
                 if let Some(m) = Month::recognize(month, date_format) {
-                    return Some(DateExpression::DayInMonth(m, date));
+                    let expression = match caps.name("year").or(caps.name("year2")) {
+                        Some(year) => date_in_written_year(m, Some(date), year.as_str())?,
+                        None => DateExpression::DayInMonth(m, date),
+                    };
+
+                    return Some(RecognizedDateExpression::from_captures(expression, &caps));
                 }
             }
         }
@@ -90,11 +110,72 @@ mod parse_month_date_english_works_when {
         assert_recognize_in_month("25 May", Month::May, 25);
     }
 
+    #[test]
+    fn ordinals_are_parsed() {
+        assert_recognize_in_month("june 1st", Month::June, 1);
+        assert_recognize_in_month("the 22nd of may", Month::May, 22);
+        assert_recognize_in_month("march 3rd", Month::March, 3);
+        assert_recognize_in_month("the first of april", Month::April, 1);
+        assert_recognize_in_month("twenty-first of june", Month::June, 21);
+        assert_recognize_in_month("Thirty First of December", Month::December, 31);
+        assert_recognize_in_month("june the twentieth", Month::June, 20);
+        assert_recognize_in_month("august second", Month::August, 2);
+    }
+
+    #[test]
+    fn the_range_includes_the_article() {
+        assert_eq!(
+            parse_month_date("pay rent on the 22nd of may", &DateFormat::DayMonthYear)
+                .map(|recognized| recognized.range),
+            Some(12..27)
+        );
+    }
+
+    #[test]
+    fn dates_with_a_year_are_parsed() {
+        for text in [
+            "June 5th 2027",
+            "June 5, 2027",
+            "5th of June 2027",
+            "5 June, 2027",
+        ] {
+            assert_eq!(
+                parse_month_date(text, &DateFormat::DayMonthYear)
+                    .map(|recognized| recognized.expression),
+                Some(DateExpression::DayInMonthInYear(Month::June, 5, 2027)),
+                "Failed to parse {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn dates_with_an_apostrophe_year_are_parsed() {
+        for text in ["June 5th '27", "5 June ’27", "the 5th of June '27"] {
+            assert_eq!(
+                parse_month_date(text, &DateFormat::DayMonthYear)
+                    .map(|recognized| recognized.expression),
+                Some(DateExpression::DayInMonthInTwoDigitYear(Month::June, 5, 27)),
+                "Failed to parse {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn a_year_is_no_day() {
+        assert_eq!(
+            parse_month_date("June 2027", &DateFormat::DayMonthYear),
+            None
+        );
+    }
+
     fn assert_recognize_in_month(text: &str, expected_m: Month, expected_d: u32) {
         let date_expression = DateExpression::DayInMonth(expected_m, expected_d);
 
         assert_eq!(
-            parse_month_date(text, &DateFormat::DayMonthYear),
+            parse_month_date(text, &DateFormat::DayMonthYear)
+                .map(|recognized| recognized.expression),
             Some(date_expression.clone()),
             "Failed to parse {} to {:?}",
             text,
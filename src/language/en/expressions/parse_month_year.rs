@@ -0,0 +1,83 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::{
+    language::shared::{date_in_written_year, Month, RecognizedDateExpression},
+    recognizable::Recognizable,
+    DateFormat,
+};
+
+pub const MONTH_YEAR_PATTERN: &str = r"(?i)\b(?P<month>jan(?:uary)?|feb(?:ruary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?|sep(?:t|tember)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)\.?,?\s(?P<year>\d{4}|['’]\d{2})\b";
+
+static MONTH_YEAR: LazyLock<Regex> = LazyLock::new(|| Regex::new(MONTH_YEAR_PATTERN).unwrap());
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InMonthInYear(Month, i32)`
+/// or, for years like `'26`, a `DateExpr::InMonthInTwoDigitYear(Month, u32)`.
+pub fn parse_month_year(text: &str, date_format: &DateFormat) -> Option<RecognizedDateExpression> {
+    // june 2027, dec 2026, dec '26
+
+    let caps = MONTH_YEAR.captures(text)?;
+
+    let month = Month::recognize(&caps["month"], date_format)?;
+    let expression = date_in_written_year(month, None, &caps["year"])?;
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_month_year_works_when {
+    use super::parse_month_year;
+    use crate::{
+        language::shared::{DateExpression, Month},
+        DateFormat,
+    };
+
+    #[test]
+    fn months_with_a_year_are_parsed() {
+        assert_month_year("june 2027", Month::June, 2027);
+        assert_month_year("Renew the contract in Dec. 2026", Month::December, 2026);
+        assert_month_year("September, 2030", Month::September, 2030);
+    }
+
+    #[test]
+    fn months_with_an_apostrophe_year_are_parsed() {
+        for text in ["Dec '26", "Invoice from Dec. ’26"] {
+            assert_eq!(
+                parse_month_year(text, &DateFormat::DayMonthYear)
+                    .map(|recognized| recognized.expression),
+                Some(DateExpression::InMonthInTwoDigitYear(Month::December, 26)),
+                "failed to parse {}",
+                text
+            );
+        }
+
+        assert_eq!(parse_month_year("Dec 26", &DateFormat::DayMonthYear), None);
+    }
+
+    #[test]
+    fn no_month_with_a_year_is_found() {
+        assert_eq!(
+            parse_month_year("june 5th", &DateFormat::DayMonthYear),
+            None
+        );
+        assert_eq!(
+            parse_month_year("june 20271", &DateFormat::DayMonthYear),
+            None
+        );
+        assert_eq!(
+            parse_month_year("maybe 2027", &DateFormat::DayMonthYear),
+            None
+        );
+    }
+
+    fn assert_month_year(text: &str, expected_month: Month, expected_year: i32) {
+        assert_eq!(
+            parse_month_year(text, &DateFormat::DayMonthYear)
+                .map(|recognized| recognized.expression),
+            Some(DateExpression::InMonthInYear(expected_month, expected_year)),
+            "failed to parse {}",
+            text
+        )
+    }
+}
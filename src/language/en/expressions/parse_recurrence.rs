@@ -0,0 +1,318 @@
+use std::sync::LazyLock;
+
+use chrono::Weekday;
+use regex::{Captures, Regex};
+
+use crate::{
+    language::{
+        en::en_date_parser::{number_words, string_to_num_english},
+        shared::{Frequency, Recurrence},
+    },
+    recurrence_parser::RecurrenceMatch,
+};
+
+static EVERY_DAY_OF_MONTH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:(?:every|each)\s(?P<day>[12][0-9]|3[01]|0?[1-9])(?:st|nd|rd|th)?\s(?:day\s)?of\s(?:the|every|each)\smonth|on\sthe\s(?P<day2>[12][0-9]|3[01]|0?[1-9])(?:st|nd|rd|th)?\s(?:day\s)?of\s(?:every|each)\smonth)\b").unwrap()
+});
+
+static EVERY_INTERVAL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(?i)\b(?:every|each)\s(?:(?P<other>other|second)\s|(?P<num>\d{{1,3}}|{NUMBER_WORDS})\s)?(?P<unit>day|week|month|year)s?\b(?:\son\s(?P<days>{WEEKDAY}(?:(?:\s?,\s?|\sand\s|\s?,\sand\s){WEEKDAY})*)\b)?", NUMBER_WORDS = number_words!())).unwrap()
+});
+
+static EVERY_WEEKDAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(?i)\b(?:(?:every|each)\s(?P<other>other\s)?|weekly\s(?:on\s)?|on\s)(?P<days>{WEEKDAY}(?:(?:\s?,\s?|\sand\s|\s?,\sand\s){WEEKDAY})*)\b")).unwrap()
+});
+
+static RECURRENCE_KEYWORDS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(?P<key>daily|weekly|monthly|yearly|annually)\b").unwrap());
+
+static WEEKDAYS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(?P<day>mon|tue|wed|thu|fri|sat|sun)").unwrap());
+
+const WEEKDAY: &str = r"(?:mon|tue|wed|thu|fri|sat|sun)(?:r?day|r?sday|nesday|urday)?s?";
+
+/// Parses a `str` into an `Option` containing a `Recurrence`.
+pub fn parse_recurrence(text: &str) -> Option<RecurrenceMatch> {
+    // every 1st of the month, each 15th of the month
+    if let Some(recurrence) = parse_every_day_of_month(text) {
+        return Some(recurrence);
+    }
+
+    // every 3 days, every other week on monday
+    if let Some(recurrence) = parse_every_interval(text) {
+        return Some(recurrence);
+    }
+
+    // every monday, weekly on tuesdays and thursdays
+    if let Some(recurrence) = parse_every_weekday(text) {
+        return Some(recurrence);
+    }
+
+    // daily, weekly, monthly, yearly
+    parse_recurrence_keywords(text)
+}
+
+fn parse_every_day_of_month(text: &str) -> Option<RecurrenceMatch> {
+    let caps = EVERY_DAY_OF_MONTH.captures(text)?;
+    let day: u32 = caps
+        .name("day")
+        .or(caps.name("day2"))?
+        .as_str()
+        .parse()
+        .unwrap();
+
+    Some(to_match(
+        text,
+        &caps,
+        Recurrence {
+            frequency: Frequency::Monthly,
+            interval: 1,
+            weekdays: vec![],
+            month_day: Some(day),
+        },
+    ))
+}
+
+fn parse_every_interval(text: &str) -> Option<RecurrenceMatch> {
+    let caps = EVERY_INTERVAL.captures(text)?;
+
+    let interval = if caps.name("other").is_some() {
+        2
+    } else if let Some(num_match) = caps.name("num") {
+        if let Ok(num) = num_match.as_str().parse::<u32>() {
+            num
+        } else {
+            string_to_num_english(&num_match.as_str().to_lowercase())? as u32
+        }
+    } else {
+        1
+    };
+
+    if interval == 0 {
+        return None;
+    }
+
+    let frequency = match caps["unit"].to_lowercase().as_ref() {
+        "day" => Frequency::Daily,
+        "week" => Frequency::Weekly,
+        "month" => Frequency::Monthly,
+        _ => Frequency::Yearly,
+    };
+
+    let weekdays = match (&frequency, caps.name("days")) {
+        (Frequency::Weekly, Some(days)) => parse_weekdays(days.as_str()),
+        _ => vec![],
+    };
+
+    Some(to_match(
+        text,
+        &caps,
+        Recurrence {
+            frequency,
+            interval,
+            weekdays,
+            month_day: None,
+        },
+    ))
+}
+
+fn parse_every_weekday(text: &str) -> Option<RecurrenceMatch> {
+    for caps in EVERY_WEEKDAY.captures_iter(text) {
+        let days = &caps["days"];
+
+        // on monday is a single date, only on mondays is recurring
+        if caps[0].to_lowercase().starts_with("on ") && !days.to_lowercase().ends_with('s') {
+            continue;
+        }
+
+        let interval = if caps.name("other").is_some() { 2 } else { 1 };
+
+        return Some(to_match(
+            text,
+            &caps,
+            Recurrence {
+                frequency: Frequency::Weekly,
+                interval,
+                weekdays: parse_weekdays(days),
+                month_day: None,
+            },
+        ));
+    }
+
+    None
+}
+
+fn parse_recurrence_keywords(text: &str) -> Option<RecurrenceMatch> {
+    let caps = RECURRENCE_KEYWORDS.captures(text)?;
+
+    let frequency = match caps["key"].to_lowercase().as_ref() {
+        "daily" => Frequency::Daily,
+        "weekly" => Frequency::Weekly,
+        "monthly" => Frequency::Monthly,
+        _ => Frequency::Yearly,
+    };
+
+    Some(to_match(
+        text,
+        &caps,
+        Recurrence {
+            frequency,
+            interval: 1,
+            weekdays: vec![],
+            month_day: None,
+        },
+    ))
+}
+
+/// Parses all weekdays of a list like `tuesdays and thursdays`.
+fn parse_weekdays(text: &str) -> Vec<Weekday> {
+    WEEKDAYS
+        .captures_iter(text)
+        .map(|caps| caps["day"].to_lowercase().parse::<Weekday>().unwrap())
+        .collect()
+}
+
+fn to_match(text: &str, caps: &Captures, recurrence: Recurrence) -> RecurrenceMatch {
+    let range = caps.get(0).unwrap().range();
+
+    RecurrenceMatch {
+        recurrence,
+        text: text[range.clone()].to_string(),
+        range,
+    }
+}
+
+#[cfg(test)]
+mod parse_recurrence_works_when {
+    use chrono::Weekday;
+
+    use super::parse_recurrence;
+    use crate::language::shared::{Frequency, Recurrence};
+
+    #[test]
+    fn keywords_are_parsed() {
+        assert_recurrence("water the plants daily", Frequency::Daily, 1, vec![], None);
+        assert_recurrence("weekly review", Frequency::Weekly, 1, vec![], None);
+        assert_recurrence("pay rent Monthly", Frequency::Monthly, 1, vec![], None);
+        assert_recurrence("check the car annually", Frequency::Yearly, 1, vec![], None);
+    }
+
+    #[test]
+    fn intervals_are_parsed() {
+        assert_recurrence("every day", Frequency::Daily, 1, vec![], None);
+        assert_recurrence("every 3 days", Frequency::Daily, 3, vec![], None);
+        assert_recurrence("every three days", Frequency::Daily, 3, vec![], None);
+        assert_recurrence("every fifteen days", Frequency::Daily, 15, vec![], None);
+        assert_recurrence("every twenty-one days", Frequency::Daily, 21, vec![], None);
+        assert_recurrence("every couple of weeks", Frequency::Weekly, 2, vec![], None);
+        assert_recurrence("every other week", Frequency::Weekly, 2, vec![], None);
+        assert_recurrence("every 2 months", Frequency::Monthly, 2, vec![], None);
+        assert_recurrence("each year", Frequency::Yearly, 1, vec![], None);
+        assert_recurrence(
+            "every other week on monday",
+            Frequency::Weekly,
+            2,
+            vec![Weekday::Mon],
+            None,
+        );
+    }
+
+    #[test]
+    fn weekdays_are_parsed() {
+        assert_recurrence(
+            "every monday",
+            Frequency::Weekly,
+            1,
+            vec![Weekday::Mon],
+            None,
+        );
+        assert_recurrence(
+            "every other fri",
+            Frequency::Weekly,
+            2,
+            vec![Weekday::Fri],
+            None,
+        );
+        assert_recurrence(
+            "weekly on tuesdays and thursdays",
+            Frequency::Weekly,
+            1,
+            vec![Weekday::Tue, Weekday::Thu],
+            None,
+        );
+        assert_recurrence(
+            "gym on mondays, wednesdays and fridays",
+            Frequency::Weekly,
+            1,
+            vec![Weekday::Mon, Weekday::Wed, Weekday::Fri],
+            None,
+        );
+    }
+
+    #[test]
+    fn days_of_month_are_parsed() {
+        assert_recurrence(
+            "each 1st of the month",
+            Frequency::Monthly,
+            1,
+            vec![],
+            Some(1),
+        );
+        assert_recurrence(
+            "every 15th day of the month",
+            Frequency::Monthly,
+            1,
+            vec![],
+            Some(15),
+        );
+        assert_recurrence(
+            "on the 3rd of every month",
+            Frequency::Monthly,
+            1,
+            vec![],
+            Some(3),
+        );
+    }
+
+    #[test]
+    fn no_recurrence_is_found() {
+        let invalid_cases = vec!["on monday", "next week", "every 0 days", "tomorrow"];
+
+        for input in invalid_cases {
+            assert_eq!(
+                parse_recurrence(input),
+                None,
+                "Expected None for input: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn range_of_recurrence_is_returned() {
+        let recurrence = parse_recurrence("water the plants every monday please").unwrap();
+
+        assert_eq!(recurrence.range, 17..29);
+        assert_eq!(recurrence.text, "every monday");
+    }
+
+    fn assert_recurrence(
+        text: &str,
+        frequency: Frequency,
+        interval: u32,
+        weekdays: Vec<Weekday>,
+        month_day: Option<u32>,
+    ) {
+        assert_eq!(
+            parse_recurrence(text).map(|recurrence| recurrence.recurrence),
+            Some(Recurrence {
+                frequency,
+                interval,
+                weekdays,
+                month_day
+            }),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
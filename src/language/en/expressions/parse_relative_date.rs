@@ -35,27 +35,43 @@ Modifications by Outlawraspberry UG (haftungsbeschränkt)
 
 */
 
+use std::sync::LazyLock;
+
 use regex::Regex;
 
-use crate::language::{en::en_date_parser::string_to_num_english, shared::DateExpression};
+use crate::language::{
+    en::en_date_parser::{number_words, string_to_num_english},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+pub const RELATIVE_DAY_PATTERN: &str = concat!(
+    r"(?i)(in\s(?P<num>([\d]{1,3}|",
+    number_words!(),
+    r"))\s(days?))"
+);
+
+static RELATIVE_DAY: LazyLock<Regex> = LazyLock::new(|| Regex::new(RELATIVE_DAY_PATTERN).unwrap());
 
 /// Parses a `str` into an `Option` containing a `DateExpr::InNDays(i32)`
-pub fn parse_relative_day(text: &str) -> Option<DateExpression> {
+pub fn parse_relative_day(text: &str) -> Option<RecognizedDateExpression> {
     // Define the regex pattern
-    let re = Regex::new(r"(in\s(?P<num>([\d]{1,3}|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve))\s(days?))").unwrap();
 
     // Check for captures
-    if let Some(caps) = re.captures(&String::from(text).trim().to_lowercase()) {
+    if let Some(caps) = RELATIVE_DAY.captures(text) {
         if let Some(num_match) = caps.name("num") {
             // Match numeric values
-            if let Ok(num) = num_match.as_str().parse::<i32>() {
-                return Some(DateExpression::InXDays(num));
+            let num = if let Ok(num) = num_match.as_str().parse::<i32>() {
+                num
             } else {
                 // Match textual representations
-                let num_str = num_match.as_str();
-                let num = string_to_num_english(num_str).unwrap();
-                return Some(DateExpression::InXDays(num));
-            }
+                let num_str = num_match.as_str().to_lowercase();
+                string_to_num_english(&num_str)?
+            };
+
+            return Some(RecognizedDateExpression::from_captures(
+                DateExpression::InXDays(num),
+                &caps,
+            ));
         }
     }
     None
@@ -94,10 +110,16 @@ mod parse_relative_date_works_when {
             ("in ten days", Some(DateExpression::InXDays(10))),
             ("in eleven days", Some(DateExpression::InXDays(11))),
             ("in twelve days", Some(DateExpression::InXDays(12))),
+            ("in thirteen days", Some(DateExpression::InXDays(13))),
+            ("in fifteen days", Some(DateExpression::InXDays(15))),
+            ("in twenty-one days", Some(DateExpression::InXDays(21))),
+            ("in a hundred days", Some(DateExpression::InXDays(100))),
+            ("in a couple of days", Some(DateExpression::InXDays(2))),
+            ("in a few days", Some(DateExpression::InXDays(3))),
         ];
 
         for (input, expected) in cases {
-            let result = parse_relative_day(input);
+            let result = parse_relative_day(input).map(|recognized| recognized.expression);
             assert_eq!(result, expected, "Failed for input: {}", input);
         }
     }
@@ -107,7 +129,7 @@ mod parse_relative_date_works_when {
         // Test cases for invalid inputs
         let invalid_cases = vec![
             "in zero days",
-            "in thirteen days",
+            "in some days",
             "in days",
             "in one",
             "in twelve",
@@ -115,12 +137,15 @@ mod parse_relative_date_works_when {
         ];
 
         for input in invalid_cases {
-            let result = parse_relative_day(input);
+            let result = parse_relative_day(input).map(|recognized| recognized.expression);
             assert_eq!(result, None, "Expected None for input: {}", input);
         }
     }
 
     fn assert_in_n_days(text: &str, n: i32) {
-        assert_eq!(parse_relative_day(text), Some(DateExpression::InXDays(n)))
+        assert_eq!(
+            parse_relative_day(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(n))
+        )
     }
 }
@@ -1,15 +1,23 @@
+use std::sync::LazyLock;
+
 use regex::Regex;
 
-use crate::{language::shared::DateExpression, DateFormat};
+use crate::{
+    language::shared::{DateExpression, RecognizedDateExpression},
+    DateFormat,
+};
+
+pub const KEYWORD_RELATIVE_WEEK_PATTERN: &str = r"(?i)(?P<prep>next|last|this)\s(?P<week>week)";
+
+static KEYWORD_RELATIVE_WEEK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(KEYWORD_RELATIVE_WEEK_PATTERN).unwrap());
 
 /// Parses a `str` into an `Option` containing a `DateExpression::InXWeeks(i8)`
 pub fn parse_keyword_relative_week(
     text: &str,
     _date_format: &DateFormat,
-) -> Option<DateExpression> {
-    let re = Regex::new(r"(?i)(?P<prep>next|last|this)\s(?P<week>week)").unwrap();
-
-    if let Some(caps) = re.captures(text) {
+) -> Option<RecognizedDateExpression> {
+    if let Some(caps) = KEYWORD_RELATIVE_WEEK.captures(text) {
         if let Some(prep_match) = caps.name("prep") {
             let relative_week = match prep_match.as_str().to_lowercase().as_ref() {
                 "next" => 1,
@@ -18,7 +26,10 @@ pub fn parse_keyword_relative_week(
                 _ => 0,
             };
 
-            return Some(DateExpression::InXWeeks(relative_week));
+            return Some(RecognizedDateExpression::from_captures(
+                DateExpression::InXWeeks(relative_week),
+                &caps,
+            ));
         }
     }
 
@@ -38,7 +49,8 @@ mod parse_keyword_based_relative_week {
 
     fn assert_day_in_n_weeks(text: &str, relative_week: i32) {
         assert_eq!(
-            parse_keyword_relative_week(text, &DateFormat::DayMonthYear),
+            parse_keyword_relative_week(text, &DateFormat::DayMonthYear)
+                .map(|recognized| recognized.expression),
             Some(DateExpression::InXWeeks(relative_week,))
         )
     }
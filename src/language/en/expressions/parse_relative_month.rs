@@ -34,16 +34,22 @@ Modifications by Outlawraspberry UG (haftungsbeschränkt)
 
 */
 
+use std::sync::LazyLock;
+
 use regex::Regex;
 
-use crate::language::shared::DateExpression;
+use crate::language::shared::{DateExpression, RecognizedDateExpression};
+
+pub const RELATIVE_MONTH_PATTERN: &str = r"(?i)(?P<prep>next|last|this)\smonth";
+
+static RELATIVE_MONTH: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(RELATIVE_MONTH_PATTERN).unwrap());
 
 /// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
-pub fn parse_relative_month(text: &str) -> Option<DateExpression> {
+pub fn parse_relative_month(text: &str) -> Option<RecognizedDateExpression> {
     // this month, next month, last month
-    let re = Regex::new(r"(?i)(?P<prep>next|last|this)\smonth").unwrap();
 
-    if let Some(caps) = re.captures(text) {
+    if let Some(caps) = RELATIVE_MONTH.captures(text) {
         if let Some(prep_match) = caps.name("prep") {
             let p = match prep_match.as_str().to_lowercase().as_ref() {
                 "next" => 1,
@@ -52,7 +58,10 @@ pub fn parse_relative_month(text: &str) -> Option<DateExpression> {
                 _ => 0,
             };
 
-            return Some(DateExpression::InXMonths(p));
+            return Some(RecognizedDateExpression::from_captures(
+                DateExpression::InXMonths(p),
+                &caps,
+            ));
         }
     }
 
@@ -72,7 +81,7 @@ mod parse_relative_month_works_when {
 
     fn assert_relative_month(text: &str, expected_n: i32) {
         assert_eq!(
-            parse_relative_month(text),
+            parse_relative_month(text).map(|recognized| recognized.expression),
             Some(DateExpression::InXMonths(expected_n))
         )
     }
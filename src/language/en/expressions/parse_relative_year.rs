@@ -0,0 +1,199 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::{
+    language::{
+        en::en_date_parser::{
+            day_of_month_english, number_words, ordinal_words, string_to_halves_english,
+        },
+        shared::{DateExpression, Month, RecognizedDateExpression},
+    },
+    recognizable::Recognizable,
+    DateFormat,
+};
+
+pub const RELATIVE_YEAR_PATTERN: &str = concat!(
+    r"(?i)\b(?:(?:this\stime\s)?(?P<prep>next|last|this)\syear|in\s(?P<num>half\san?|(?:\d{1,3}|",
+    number_words!(),
+    r")(?:\sand\sa\shalf)?)\syears?)\b"
+);
+
+static RELATIVE_YEAR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(RELATIVE_YEAR_PATTERN).unwrap());
+
+pub const MONTH_IN_RELATIVE_YEAR_PATTERN: &str = concat!(
+    r"(?i)\b(?:in\s)?(?:(?:the\s)?(?P<date>(?:[12][0-9]|3[01]|0?[1-9])(?:st|nd|rd|th)?|",
+    ordinal_words!(),
+    r")\s(?:of\s)?)?(?P<month>jan(?:uary)?|feb(?:ruary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|aug(?:ust)?|sep(?:t|tember)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?)(?:\s(?:the\s)?(?P<date2>(?:[12][0-9]|3[01]|0?[1-9])(?:st|nd|rd|th)?|",
+    ordinal_words!(),
+    r"))?,?\s(?P<prep>next|last|this)\syear\b"
+);
+
+static MONTH_IN_RELATIVE_YEAR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(MONTH_IN_RELATIVE_YEAR_PATTERN).unwrap());
+
+/// Returns the years of next, last or this.
+fn relative_word_to_years(text: &str) -> i32 {
+    match text.to_lowercase().as_ref() {
+        "next" => 1,
+        "last" => -1,
+        _ => 0,
+    }
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InXYears(i32)`
+/// or a `DateExpr::InXMonths(i32)` for half years.
+pub fn parse_relative_year(text: &str) -> Option<RecognizedDateExpression> {
+    // this year, next year, last year, this time next year, in 2 years, in half a year
+
+    let caps = RELATIVE_YEAR.captures(text)?;
+
+    let expression = match (caps.name("prep"), caps.name("num")) {
+        (Some(prep), _) => DateExpression::InXYears(relative_word_to_years(prep.as_str())),
+        (None, Some(num)) => match string_to_halves_english(num.as_str())? {
+            halves if halves % 2 == 0 => DateExpression::InXYears(halves / 2),
+            // half a year, two and a half years
+            halves => DateExpression::InXMonths(halves.checked_mul(6)?),
+        },
+        (None, None) => return None,
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::MonthInXYears(Month, i32)`
+/// or a `DateExpr::DayInMonthInXYears(Month, u32, i32)`.
+pub fn parse_month_in_relative_year(
+    text: &str,
+    date_format: &DateFormat,
+) -> Option<RecognizedDateExpression> {
+    // in december next year, june 5th next year, the first of june last year
+
+    let caps = MONTH_IN_RELATIVE_YEAR.captures(text)?;
+
+    let month = Month::recognize(&caps["month"], date_format)?;
+    let years = relative_word_to_years(&caps["prep"]);
+
+    let expression = match caps.name("date").or(caps.name("date2")) {
+        Some(date) => {
+            DateExpression::DayInMonthInXYears(month, day_of_month_english(date.as_str())?, years)
+        }
+        None => DateExpression::MonthInXYears(month, years),
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_relative_year_works_when {
+    use super::{parse_month_in_relative_year, parse_relative_year};
+    use crate::{
+        language::shared::{DateExpression, Month},
+        DateFormat,
+    };
+
+    #[test]
+    fn relative_years_are_parsed() {
+        assert_relative_year("next year", 1);
+        assert_relative_year("What did we do last year?", -1);
+        assert_relative_year("this Year", 0);
+        assert_relative_year("this time next year", 1);
+        assert_relative_year("in 2 years", 2);
+        assert_relative_year("in one year", 1);
+        assert_relative_year("in twelve years", 12);
+        assert_relative_year("in twenty-five years", 25);
+        assert_relative_year("in a year", 1);
+        assert_relative_year("in a couple of years", 2);
+    }
+
+    #[test]
+    fn half_years_are_parsed_into_months() {
+        for (text, months) in [
+            ("in half a year", 6),
+            ("in two and a half years", 30),
+            ("in 1 and a half years", 18),
+        ] {
+            assert_eq!(
+                parse_relative_year(text).map(|recognized| recognized.expression),
+                Some(DateExpression::InXMonths(months)),
+                "failed to parse {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn the_range_covers_this_time() {
+        assert_eq!(
+            parse_relative_year("see you this time next year!").map(|recognized| recognized.range),
+            Some(8..27)
+        );
+    }
+
+    #[test]
+    fn no_relative_year_is_found() {
+        assert_eq!(parse_relative_year("yearly"), None);
+        assert_eq!(parse_relative_year("next years"), None);
+        assert_eq!(parse_relative_year("2 years"), None);
+    }
+
+    #[test]
+    fn months_in_relative_years_are_parsed() {
+        assert_month_in_relative_year(
+            "in december next year",
+            DateExpression::MonthInXYears(Month::December, 1),
+        );
+        assert_month_in_relative_year(
+            "Dec last year",
+            DateExpression::MonthInXYears(Month::December, -1),
+        );
+        assert_month_in_relative_year(
+            "june 5th next year",
+            DateExpression::DayInMonthInXYears(Month::June, 5, 1),
+        );
+        assert_month_in_relative_year(
+            "on the 5th of June next year",
+            DateExpression::DayInMonthInXYears(Month::June, 5, 1),
+        );
+        assert_month_in_relative_year(
+            "the first of june last year",
+            DateExpression::DayInMonthInXYears(Month::June, 1, -1),
+        );
+        assert_month_in_relative_year(
+            "March 31, this year",
+            DateExpression::DayInMonthInXYears(Month::March, 31, 0),
+        );
+    }
+
+    #[test]
+    fn no_month_in_relative_year_is_found() {
+        assert_eq!(
+            parse_month_in_relative_year("next year", &DateFormat::DayMonthYear),
+            None
+        );
+        assert_eq!(
+            parse_month_in_relative_year("june 5th", &DateFormat::DayMonthYear),
+            None
+        );
+    }
+
+    fn assert_relative_year(text: &str, expected_years: i32) {
+        assert_eq!(
+            parse_relative_year(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXYears(expected_years)),
+            "failed to parse {}",
+            text
+        )
+    }
+
+    fn assert_month_in_relative_year(text: &str, expected: DateExpression) {
+        assert_eq!(
+            parse_month_in_relative_year(text, &DateFormat::DayMonthYear)
+                .map(|recognized| recognized.expression),
+            Some(expected),
+            "failed to parse {}",
+            text
+        )
+    }
+}
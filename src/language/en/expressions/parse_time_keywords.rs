@@ -0,0 +1,54 @@
+use std::sync::LazyLock;
+
+use chrono::NaiveTime;
+use regex::Regex;
+
+static TIME_KEYWORDS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(?P<key>noon|midday|midnight)\b").unwrap());
+
+/// Parses common time keywords like noon or midnight into an `Option` containing a `NaiveTime`.
+pub fn parse_time_keywords(text: &str) -> Option<NaiveTime> {
+    // noon, midday, midnight
+
+    if let Some(caps) = TIME_KEYWORDS.captures(text) {
+        if let Some(key_match) = caps.name("key") {
+            let hour = match key_match.as_str().to_lowercase().as_ref() {
+                "noon" | "midday" => 12,
+                "midnight" => 0,
+                _ => return None,
+            };
+            return NaiveTime::from_hms_opt(hour, 0, 0);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod parse_time_keywords_works_when {
+    use chrono::NaiveTime;
+
+    use super::parse_time_keywords;
+
+    #[test]
+    fn time_keywords() {
+        assert_time("lunch at noon", 12, 0);
+        assert_time("lunch at Midday", 12, 0);
+        assert_time("call me at midnight", 0, 0);
+    }
+
+    #[test]
+    fn no_time_keyword_is_found() {
+        assert_eq!(parse_time_keywords("lunch afternoon"), None);
+        assert_eq!(parse_time_keywords("nothing here"), None);
+    }
+
+    fn assert_time(text: &str, hour: u32, minute: u32) {
+        assert_eq!(
+            parse_time_keywords(text),
+            NaiveTime::from_hms_opt(hour, minute, 0),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
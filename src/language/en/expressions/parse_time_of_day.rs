@@ -0,0 +1,124 @@
+use std::sync::LazyLock;
+
+use chrono::NaiveTime;
+use regex::Regex;
+
+use crate::language::en::en_time_parser::hour_to_24_hour_clock;
+
+static CLOCK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(?P<hour>[01]?\d|2[0-3]):(?P<minute>[0-5]\d)(?:\s?(?P<meridiem>[ap])\.?m\b\.?)?",
+    )
+    .unwrap()
+});
+
+static HOUR_WITH_MERIDIEM: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?P<hour>1[0-2]|0?[1-9])\s?(?P<meridiem>[ap])\.?m\b\.?").unwrap()
+});
+
+static AT_HOUR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bat\s(?P<hour>[01]?\d|2[0-3])(?:\s?o'?clock\b|\s*(?:[,;!?)]|\.(?:\s|$)|$))")
+        .unwrap()
+});
+
+/// Parses clock times like 17:30, 5:30pm, 5pm or at 17 into an `Option` containing a `NaiveTime`.
+///
+/// A bare hour after `at` is only a time at the end of a sentence or before `o'clock`,
+/// so `at 3 different places` isn't three o'clock.
+pub fn parse_time_of_day(text: &str) -> Option<NaiveTime> {
+    // 17:30, 5:30 pm, 5pm, 5 a.m., at 17, at 5 o'clock
+
+    if let Some(caps) = CLOCK.captures(text) {
+        let hour: u32 = caps["hour"].parse().unwrap();
+        let minute: u32 = caps["minute"].parse().unwrap();
+        let meridiem = caps.name("meridiem").map(|m| m.as_str());
+
+        let hour = hour_to_24_hour_clock(hour, meridiem)?;
+        return NaiveTime::from_hms_opt(hour, minute, 0);
+    }
+
+    if let Some(caps) = HOUR_WITH_MERIDIEM.captures(text) {
+        let hour: u32 = caps["hour"].parse().unwrap();
+        let hour = hour_to_24_hour_clock(hour, Some(&caps["meridiem"]))?;
+        return NaiveTime::from_hms_opt(hour, 0, 0);
+    }
+
+    if let Some(caps) = AT_HOUR.captures(text) {
+        let hour: u32 = caps["hour"].parse().unwrap();
+        return NaiveTime::from_hms_opt(hour, 0, 0);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod parse_time_of_day_works_when {
+    use chrono::NaiveTime;
+
+    use super::parse_time_of_day;
+
+    #[test]
+    fn clock_times_are_parsed() {
+        assert_time("meeting at 17:30", 17, 30);
+        assert_time("meeting at 09:05", 9, 5);
+        assert_time("meeting at 9:05", 9, 5);
+        assert_time("meeting at 0:00", 0, 0);
+        assert_time("meeting at 5:30pm", 17, 30);
+        assert_time("meeting at 5:30 PM", 17, 30);
+        assert_time("meeting at 12:15 am", 0, 15);
+    }
+
+    #[test]
+    fn hours_with_meridiem_are_parsed() {
+        assert_time("at 5pm", 17, 0);
+        assert_time("at 5 pm", 17, 0);
+        assert_time("at 5 p.m.", 17, 0);
+        assert_time("at 11am", 11, 0);
+        assert_time("at 12pm", 12, 0);
+        assert_time("at 12am", 0, 0);
+    }
+
+    #[test]
+    fn bare_hours_after_at_are_parsed() {
+        assert_time("at 17", 17, 0);
+        assert_time("call mom at 8, please", 8, 0);
+        assert_time("at 9 o'clock", 9, 0);
+        assert_time("at 9 oclock tomorrow", 9, 0);
+        assert_time("dinner at 8.", 8, 0);
+        assert_time("dinner at 8!", 8, 0);
+    }
+
+    #[test]
+    fn bare_hours_followed_by_words_are_no_time() {
+        assert_no_time("meet at 3 different places");
+        assert_no_time("arrive at 5 stations");
+        assert_no_time("at 12 o'clocks");
+    }
+
+    #[test]
+    fn no_time_is_found() {
+        assert_no_time("");
+        assert_no_time("call mom");
+        assert_no_time("at 5.12.2026");
+        assert_no_time("in 5 days");
+        assert_no_time("meeting at 25:00");
+    }
+
+    fn assert_time(text: &str, hour: u32, minute: u32) {
+        assert_eq!(
+            parse_time_of_day(text),
+            NaiveTime::from_hms_opt(hour, minute, 0),
+            "Failed for input: {}",
+            text
+        )
+    }
+
+    fn assert_no_time(text: &str) {
+        assert_eq!(
+            parse_time_of_day(text),
+            None,
+            "Expected None for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::LazyLock;
+
+use chrono::NaiveTime;
+use regex::{Captures, Regex};
+
+use crate::language::en::{
+    en_date_parser::string_to_num_english, en_time_parser::hour_to_24_hour_clock,
+};
+
+static TIME_RELATIVE_TO_HOUR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?P<minutes>half|quarter|[1-5]?\d|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)\s(?P<unit>minutes?\s)?(?P<direction>past|after|to|before)\s(?P<hour>1[0-2]|0?[1-9]|one|two|three|four|five|six|seven|eight|nine|ten|eleven|twelve)\b(?:\s?(?P<meridiem>[ap])\.?m\b\.?|\s(?P<oclock>o['’]clock)\b)?").unwrap()
+});
+
+/// Parses times relative to a full hour like half past nine or quarter to 8 into an `Option` containing a `NaiveTime`.
+///
+/// Digits before the hour are only read as minutes together with `minutes`, a meridiem or `o'clock`,
+/// so counts like `2 to 3 apples` are no times.
+pub fn parse_time_relative_to_hour(text: &str) -> Option<NaiveTime> {
+    // half past nine, quarter to 8, ten past five, 20 minutes to 6 pm, 5 to 10 o'clock
+
+    TIME_RELATIVE_TO_HOUR
+        .captures_iter(text)
+        .find_map(|caps| time_relative_to_hour(&caps))
+}
+
+fn time_relative_to_hour(caps: &Captures) -> Option<NaiveTime> {
+    let is_digit_count = caps["minutes"].starts_with(|c: char| c.is_ascii_digit());
+    let is_time = caps.name("unit").is_some()
+        || caps.name("meridiem").is_some()
+        || caps.name("oclock").is_some();
+
+    if is_digit_count && !is_time {
+        return None;
+    }
+
+    let minutes = match caps["minutes"].to_lowercase().as_ref() {
+        "half" => 30,
+        "quarter" => 15,
+        minutes => parse_number(minutes)?,
+    };
+    let hour = parse_number(&caps["hour"].to_lowercase())?;
+
+    if minutes == 0 || minutes >= 60 {
+        return None;
+    }
+
+    let meridiem = caps.name("meridiem").map(|m| m.as_str());
+    let hour = hour_to_24_hour_clock(hour, meridiem)?;
+
+    match caps["direction"].to_lowercase().as_ref() {
+        "past" | "after" => NaiveTime::from_hms_opt(hour, minutes, 0),
+        _ => NaiveTime::from_hms_opt((hour + 23) % 24, 60 - minutes, 0),
+    }
+}
+
+fn parse_number(input: &str) -> Option<u32> {
+    if let Ok(num) = input.parse::<u32>() {
+        return Some(num);
+    }
+
+    string_to_num_english(input).map(|num| num as u32)
+}
+
+#[cfg(test)]
+mod parse_time_relative_to_hour_works_when {
+    use chrono::NaiveTime;
+
+    use super::parse_time_relative_to_hour;
+
+    #[test]
+    fn times_past_an_hour_are_parsed() {
+        assert_time("half past nine", 9, 30);
+        assert_time("Half past 9", 9, 30);
+        assert_time("quarter past three", 3, 15);
+        assert_time("ten past five", 5, 10);
+        assert_time("20 minutes past 6", 6, 20);
+        assert_time("half past nine pm", 21, 30);
+        assert_time("ten after twelve", 12, 10);
+    }
+
+    #[test]
+    fn times_to_an_hour_are_parsed() {
+        assert_time("quarter to 8", 7, 45);
+        assert_time("quarter to eight", 7, 45);
+        assert_time("five to twelve", 11, 55);
+        assert_time("ten to one", 0, 50);
+        assert_time("quarter to 8 pm", 19, 45);
+        assert_time("10 minutes before 3", 2, 50);
+    }
+
+    #[test]
+    fn no_time_is_found() {
+        assert_eq!(parse_time_relative_to_hour("half an hour"), None);
+        assert_eq!(parse_time_relative_to_hour("go to bed"), None);
+        assert_eq!(parse_time_relative_to_hour("0 past 5"), None);
+    }
+
+    #[test]
+    fn counts_are_no_times() {
+        assert_eq!(parse_time_relative_to_hour("Invite 2 to 3 friends"), None);
+        assert_eq!(parse_time_relative_to_hour("5 to 10 people"), None);
+        assert_eq!(
+            parse_time_relative_to_hour("buy 2 to 3 apples tomorrow"),
+            None
+        );
+        assert_eq!(parse_time_relative_to_hour("page 20 to 30"), None);
+    }
+
+    #[test]
+    fn digits_with_a_unit_are_times() {
+        assert_time("5 to 10 pm", 21, 55);
+        assert_time("5 to 10 o'clock", 9, 55);
+        assert_time("buy 2 to 3 apples at quarter to 8", 7, 45);
+    }
+
+    fn assert_time(text: &str, hour: u32, minute: u32) {
+        assert_eq!(
+            parse_time_relative_to_hour(text),
+            NaiveTime::from_hms_opt(hour, minute, 0),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -1,3 +1,4 @@
 pub mod en_date_parser;
+pub mod en_date_time_parser;
 pub mod en_time_parser;
-mod expressions;
+pub(crate) mod expressions;
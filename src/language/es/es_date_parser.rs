@@ -0,0 +1,477 @@
+use std::ops::Range;
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::{
+    date_match::{DateMatch, StrippedDateExpression},
+    date_parser::DateParser,
+    language::{
+        shared::{recognize_all_date_expressions, Month, RecognizedDateExpression},
+        shared_date_parser::{
+            parse_date_month_year, parse_iso_date, parse_month_date_year, parse_year_month_day,
+        },
+        shared_title::strip_date_phrase,
+    },
+    parse_error::ParseError,
+    DateFormat, StartDayOfWeek,
+};
+
+use super::expressions::{
+    parse_ago::parse_ago,
+    parse_date_in_week::parse_date_in_week,
+    parse_date_in_x_weeks::parse_in_x_weeks,
+    parse_day_alone::parse_day_alone,
+    parse_day_in_explicit_weeks::parse_day_in_explicit_week,
+    parse_day_in_relative_week::parse_day_in_relative_week,
+    parse_in_n_months::parse_in_n_months,
+    parse_keywords::parse_keywords,
+    parse_month_date::parse_month_date,
+    parse_month_year::parse_month_year,
+    parse_relative_date::parse_relative_day,
+    parse_relative_keyword_week::parse_keyword_relative_week,
+    parse_relative_month::parse_relative_month,
+    parse_relative_year::{parse_month_in_relative_year, parse_relative_year},
+};
+
+/// The spanish weekdays for regular expressions, written with or without accents.
+pub const WEEKDAYS: &str = "lunes|martes|mi[eé]rcoles|jueves|viernes|s[aá]bado|domingo";
+
+/// The forms of next and last which follow a noun, like `que viene` in `la semana que viene`.
+pub const RELATIVE_WORDS: &str = r"pr[oó]xim[oa]|siguiente|que\sviene|pasad[oa]";
+
+/// The forms of next and last which precede a noun, like `próximo` in `el próximo lunes`.
+pub const PRECEDING_RELATIVE_WORDS: &str = r"pr[oó]xim[oa]|pasad[oa]";
+
+/// The spanish number words up to ninety-nine for regular expressions, like `quince`, `veintiún` or `treinta y una`.
+pub const NUMBER_WORDS: &str = r"(?:treinta|cuarenta|cincuenta|sesenta|setenta|ochenta|noventa)(?:\sy\s(?:una|uno|un|dos|tres|cuatro|cinco|seis|siete|ocho|nueve))?|veinti(?:una|uno|[uú]n|d[oó]s|tr[eé]s|cuatro|cinco|s[eé]is|siete|ocho|nueve)|dieci(?:s[eé]is|siete|ocho|nueve)|veinte|una|uno|un|dos|tres|cuatro|cinco|seis|siete|ocho|nueve|diez|once|doce|trece|catorce|quince";
+
+/// The spanish months and their abbreviations for regular expressions.
+pub const MONTHS: &str = r"(?:enero|febrero|marzo|abril|mayo|junio|julio|agosto|septiembre|setiembre|octubre|noviembre|diciembre)\b|(?:ene|feb|mar|abr|may|jun|jul|ago|sept|sep|set|oct|nov|dic)\b\.?";
+
+/// The days of a month for regular expressions, like `5`, `1º` or `primero`.
+pub const DAYS: &str = r"primero|1[º°]|[12]\d|3[01]|0?[1-9]";
+
+/// The words which introduce a time span in the future, like `en` or `dentro de`.
+pub const IN_WORDS: &str = r"dentro\sde|en";
+
+/// Lowercases `text`, removes the accents and the tilde of `ñ` and collapses whitespace into single spaces,
+/// so `Mañana` and `manana` or `próximo` and `proximo` are looked up the same way.
+///
+/// Only the looked up words are normalized, the input stays untouched to keep the ranges valid.
+pub fn normalize_spanish(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' => 'a',
+            'é' => 'e',
+            'í' => 'i',
+            'ó' => 'o',
+            'ú' | 'ü' => 'u',
+            'ñ' => 'n',
+            c if c.is_whitespace() => ' ',
+            c => c,
+        })
+        .collect()
+}
+
+/// Parses a spanish weekday like `lunes` or `sábado`.
+pub fn parse_weekday_spanish(text: &str) -> Option<Weekday> {
+    match normalize_spanish(text).as_ref() {
+        "lunes" => Some(Weekday::Mon),
+        "martes" => Some(Weekday::Tue),
+        "miercoles" => Some(Weekday::Wed),
+        "jueves" => Some(Weekday::Thu),
+        "viernes" => Some(Weekday::Fri),
+        "sabado" => Some(Weekday::Sat),
+        "domingo" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Parses a spanish month name or its abbreviation like `diciembre` or `dic.` into a `Month`.
+pub fn parse_month_of_year_spanish(text: &str) -> Option<Month> {
+    match normalize_spanish(text).trim_end_matches('.') {
+        "enero" | "ene" => Some(Month::January),
+        "febrero" | "feb" => Some(Month::February),
+        "marzo" | "mar" => Some(Month::March),
+        "abril" | "abr" => Some(Month::April),
+        "mayo" | "may" => Some(Month::May),
+        "junio" | "jun" => Some(Month::June),
+        "julio" | "jul" => Some(Month::July),
+        "agosto" | "ago" => Some(Month::August),
+        "septiembre" | "setiembre" | "sept" | "sep" | "set" => Some(Month::September),
+        "octubre" | "oct" => Some(Month::October),
+        "noviembre" | "nov" => Some(Month::November),
+        "diciembre" | "dic" => Some(Month::December),
+        _ => None,
+    }
+}
+
+/// Returns the relative week, month or year of a form of next or last.
+///
+/// e.g. `próximo` => 1, `que viene` => 1, `siguiente` => 1, `pasada` => -1
+pub fn relative_word_to_num(text: &str) -> Option<i32> {
+    match normalize_spanish(text).as_ref() {
+        "proximo" | "proxima" | "siguiente" | "que viene" => Some(1),
+        "pasado" | "pasada" => Some(-1),
+        _ => None,
+    }
+}
+
+/// Parses a day of a month like `5`, `1º`, `primero` or `veinte`.
+pub fn day_of_month_spanish(input: &str) -> Option<u32> {
+    match input.to_lowercase().trim_end_matches(['º', '°']) {
+        "primero" => Some(1),
+        day => string_to_num_spanish(day)
+            .filter(|day| (1..=31).contains(day))
+            .map(|day| day as u32),
+    }
+}
+
+/// Parses a number written with digits or as spanish words like `tres`, `veintiún` or `treinta y una`.
+pub fn string_to_num_spanish(input: &str) -> Option<i32> {
+    if let Ok(num) = input.parse::<i32>() {
+        return Some(num);
+    }
+
+    let input = normalize_spanish(input);
+
+    // the tens from thirty on are joined to the ones with y
+    if let Some((tens, ones)) = input.split_once(" y ") {
+        let tens = spanish_number_word(tens).filter(|tens| *tens >= 30 && tens % 10 == 0)?;
+        let ones = spanish_number_word(ones).filter(|ones| *ones < 10)?;
+
+        return Some(tens + ones);
+    }
+
+    // sixteen to nineteen and twenty-one to twenty-nine are written as one word
+    if let Some(ones) = input.strip_prefix("dieci") {
+        return spanish_number_word(ones)
+            .filter(|ones| (6..10).contains(ones))
+            .map(|ones| 10 + ones);
+    }
+    if let Some(ones) = input.strip_prefix("veinti") {
+        return spanish_number_word(ones)
+            .filter(|ones| *ones < 10)
+            .map(|ones| 20 + ones);
+    }
+
+    spanish_number_word(&input)
+}
+
+fn spanish_number_word(word: &str) -> Option<i32> {
+    let num = match word {
+        "un" | "una" | "uno" => 1,
+        "dos" => 2,
+        "tres" => 3,
+        "cuatro" => 4,
+        "cinco" => 5,
+        "seis" => 6,
+        "siete" => 7,
+        "ocho" => 8,
+        "nueve" => 9,
+        "diez" => 10,
+        "once" => 11,
+        "doce" => 12,
+        "trece" => 13,
+        "catorce" => 14,
+        "quince" => 15,
+        "veinte" => 20,
+        "treinta" => 30,
+        "cuarenta" => 40,
+        "cincuenta" => 50,
+        "sesenta" => 60,
+        "setenta" => 70,
+        "ochenta" => 80,
+        "noventa" => 90,
+        _ => return None,
+    };
+
+    Some(num)
+}
+
+/// Prepositions which are removed together with a date phrase, like `para` in `pagar el alquiler para el lunes`.
+const PREPOSITIONS: [&str; 7] = ["el", "la", "para", "hasta", "desde", "del", "al"];
+
+/// Phrases at the beginning of an input which are not part of a title.
+const LEADING_PHRASES: [&str; 5] = [
+    "recuérdame que",
+    "recuerdame que",
+    "recuérdame",
+    "recuerdame",
+    "no olvides",
+];
+
+pub struct EsDateParser {}
+
+impl EsDateParser {
+    /// Dates are written day first in spanish, e.g. 5/12/2026.
+    pub const DATE_FORMAT: DateFormat = DateFormat::DayMonthYear;
+
+    /// Weeks start on monday in spanish speaking countries.
+    pub const START_DAY_OF_WEEK: StartDayOfWeek = StartDayOfWeek::Monday;
+}
+
+/// Recognizes the first spanish date expression in `input`.
+///
+/// The parsers are tried from the most specific to the least specific one.
+pub fn recognize_date_expression(
+    input: &str,
+    date_format: &DateFormat,
+) -> Option<RecognizedDateExpression> {
+    // hoy, mañana, pasado mañana, ayer, anteayer, but not la mañana
+    if let Some(date) = parse_keywords(input) {
+        return Some(date);
+    }
+
+    // hace tres días, hace 2 semanas
+    if let Some(date) = parse_ago(input) {
+        return Some(date);
+    }
+
+    // el lunes de la semana que viene, la próxima semana el lunes
+    if let Some(date) = parse_day_in_relative_week(input) {
+        return Some(date);
+    }
+
+    // el lunes dentro de dos semanas, en 2 semanas el lunes
+    if let Some(date) = parse_day_in_explicit_week(input) {
+        return Some(date);
+    }
+
+    // el próximo lunes, el viernes pasado, este sábado
+    if let Some(date) = parse_date_in_week(input) {
+        return Some(date);
+    }
+
+    // a single day of the week
+    if let Some(date) = parse_day_alone(input) {
+        return Some(date);
+    }
+
+    // la semana que viene, esta semana, la semana pasada
+    if let Some(date) = parse_keyword_relative_week(input) {
+        return Some(date);
+    }
+
+    // en dos semanas, dentro de 3 semanas
+    if let Some(date) = parse_in_x_weeks(input) {
+        return Some(date);
+    }
+
+    // dentro de tres días, en 3 días
+    if let Some(date) = parse_relative_day(input) {
+        return Some(date);
+    }
+
+    // ISO dates like 2026-12-05, 2026-W49-3 or 2026-339 regardless of the date format
+    if let Some(date) = parse_iso_date(input) {
+        return Some(date);
+    }
+
+    // full dates in DD.MM.YYYY, MM.DD.YYYY or YYYY.MM.DD formats
+    match date_format {
+        DateFormat::DayMonthYear => {
+            if let Some(date) = parse_date_month_year(input) {
+                return Some(date);
+            }
+        }
+        DateFormat::MonthDayYear => {
+            if let Some(date) = parse_month_date_year(input) {
+                return Some(date);
+            }
+        }
+        DateFormat::YearMonthDay => {
+            if let Some(date) = parse_year_month_day(input) {
+                return Some(date);
+            }
+        }
+    }
+
+    // en diciembre del año que viene, el 5 de junio del año pasado
+    if let Some(date) = parse_month_in_relative_year(input) {
+        return Some(date);
+    }
+
+    // el 5 de diciembre, el primero de mayo, 1 de dic. de 2026
+    if let Some(date) = parse_month_date(input) {
+        return Some(date);
+    }
+
+    // diciembre de 2027, en junio del 2027
+    if let Some(date) = parse_month_year(input) {
+        return Some(date);
+    }
+
+    // en dos meses, dentro de 2 meses
+    if let Some(date) = parse_in_n_months(input) {
+        return Some(date);
+    }
+
+    // el mes que viene, este mes, el mes pasado
+    if let Some(date) = parse_relative_month(input) {
+        return Some(date);
+    }
+
+    // el año que viene, este año, dentro de dos años
+    if let Some(date) = parse_relative_year(input) {
+        return Some(date);
+    }
+
+    None
+}
+
+/// Removes the date phrase in `range` from `text` together with the words around it which belong to the date.
+pub fn title_without_date_phrase(text: &str, range: Option<Range<usize>>) -> String {
+    match range {
+        Some(range) => strip_date_phrase(text, range, &PREPOSITIONS, &LEADING_PHRASES),
+        None => strip_date_phrase(text, text.len()..text.len(), &[], &LEADING_PHRASES),
+    }
+}
+
+/// The EsDateParser can be used to parse spanish date information out of strings.
+///
+/// ```
+/// use date_time_parser_multi_language::{DateParser, EsDateParser};
+/// use chrono::NaiveDate;
+//
+/// fn main() {
+///     let some_input = "Llamar al dentista mañana por la mañana.";
+///
+///     let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+///
+///     let date_match = EsDateParser::search_date_expression(some_input, &now, &EsDateParser::DATE_FORMAT, &EsDateParser::START_DAY_OF_WEEK).unwrap();
+///
+///     assert_eq!(date_match.date, NaiveDate::from_ymd_opt(2026, 10, 19).unwrap());
+///     assert_eq!(date_match.text, "mañana por la mañana");
+/// }
+/// ```
+impl DateParser for EsDateParser {
+    fn try_search_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Result<DateMatch, ParseError> {
+        let recognized =
+            recognize_date_expression(text, date_format).ok_or(ParseError::NoExpressionFound)?;
+
+        DateMatch::resolve(text, recognized, now, start_day_week)
+    }
+
+    fn search_all_date_expressions(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Vec<DateMatch> {
+        recognize_all_date_expressions(text, date_format, recognize_date_expression)
+            .into_iter()
+            .filter_map(|recognized| DateMatch::resolve(text, recognized, now, start_day_week).ok())
+            .collect()
+    }
+
+    fn strip_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> StrippedDateExpression {
+        let date_match = Self::search_date_expression(text, now, date_format, start_day_week);
+        let title = title_without_date_phrase(
+            text,
+            date_match
+                .as_ref()
+                .map(|date_match| date_match.range.clone()),
+        );
+
+        StrippedDateExpression { title, date_match }
+    }
+}
+
+#[cfg(test)]
+mod es_date_parser_works_when {
+    use chrono::Weekday;
+
+    use super::{
+        day_of_month_spanish, normalize_spanish, parse_month_of_year_spanish,
+        parse_weekday_spanish, relative_word_to_num, string_to_num_spanish,
+    };
+    use crate::language::shared::Month;
+
+    #[test]
+    fn accents_and_whitespace_are_normalized() {
+        assert_eq!(normalize_spanish("Mañana"), "manana");
+        assert_eq!(normalize_spanish("PRÓXIMO"), "proximo");
+        assert_eq!(normalize_spanish("que\u{a0}viene"), "que viene");
+    }
+
+    #[test]
+    fn weekdays_are_parsed() {
+        assert_eq!(parse_weekday_spanish("lunes"), Some(Weekday::Mon));
+        assert_eq!(parse_weekday_spanish("Miércoles"), Some(Weekday::Wed));
+        assert_eq!(parse_weekday_spanish("sabado"), Some(Weekday::Sat));
+        assert_eq!(parse_weekday_spanish("Monday"), None);
+    }
+
+    #[test]
+    fn months_are_parsed() {
+        assert_eq!(
+            parse_month_of_year_spanish("diciembre"),
+            Some(Month::December)
+        );
+        assert_eq!(parse_month_of_year_spanish("Dic."), Some(Month::December));
+        assert_eq!(
+            parse_month_of_year_spanish("setiembre"),
+            Some(Month::September)
+        );
+        assert_eq!(parse_month_of_year_spanish("May"), Some(Month::May));
+        assert_eq!(parse_month_of_year_spanish("December"), None);
+    }
+
+    #[test]
+    fn relative_words_are_parsed() {
+        assert_eq!(relative_word_to_num("próximo"), Some(1));
+        assert_eq!(relative_word_to_num("proxima"), Some(1));
+        assert_eq!(relative_word_to_num("que viene"), Some(1));
+        assert_eq!(relative_word_to_num("siguiente"), Some(1));
+        assert_eq!(relative_word_to_num("pasada"), Some(-1));
+        assert_eq!(relative_word_to_num("mañana"), None);
+    }
+
+    #[test]
+    fn number_words_are_parsed() {
+        assert_eq!(string_to_num_spanish("una"), Some(1));
+        assert_eq!(string_to_num_spanish("Tres"), Some(3));
+        assert_eq!(string_to_num_spanish("12"), Some(12));
+        assert_eq!(string_to_num_spanish("quince"), Some(15));
+        assert_eq!(string_to_num_spanish("trece"), Some(13));
+        assert_eq!(string_to_num_spanish("dieciséis"), Some(16));
+        assert_eq!(string_to_num_spanish("diecinueve"), Some(19));
+        assert_eq!(string_to_num_spanish("veinte"), Some(20));
+        assert_eq!(string_to_num_spanish("veintiún"), Some(21));
+        assert_eq!(string_to_num_spanish("veintiuna"), Some(21));
+        assert_eq!(string_to_num_spanish("veintidos"), Some(22));
+        assert_eq!(string_to_num_spanish("treinta y un"), Some(31));
+        assert_eq!(string_to_num_spanish("noventa y nueve"), Some(99));
+    }
+
+    #[test]
+    fn no_number_words_are_found() {
+        assert_eq!(string_to_num_spanish("diecidos"), None);
+        assert_eq!(string_to_num_spanish("veinte y uno"), None);
+        assert_eq!(string_to_num_spanish("treinta y diez"), None);
+        assert_eq!(string_to_num_spanish("cien"), None);
+    }
+
+    #[test]
+    fn days_of_month_are_parsed() {
+        assert_eq!(day_of_month_spanish("primero"), Some(1));
+        assert_eq!(day_of_month_spanish("1º"), Some(1));
+        assert_eq!(day_of_month_spanish("31"), Some(31));
+        assert_eq!(day_of_month_spanish("veinte"), Some(20));
+        assert_eq!(day_of_month_spanish("treinta y uno"), Some(31));
+        assert_eq!(day_of_month_spanish("32"), None);
+        assert_eq!(day_of_month_spanish("cuarenta"), None);
+    }
+}
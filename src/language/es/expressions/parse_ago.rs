@@ -0,0 +1,67 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{normalize_spanish, string_to_num_spanish, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static AGO: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\bhace\s(?P<num>\d{{1,3}}|{})\s(?P<unit>d[ií]as?|semanas?|mes|meses|a[nñ]os?)\b",
+        NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InXDays(i32)`, `DateExpr::InXMonths(i32)`
+/// or `DateExpr::InXYears(i32)` with a negative offset.
+pub fn parse_ago(text: &str) -> Option<RecognizedDateExpression> {
+    // hace tres días, hace 2 semanas, hace un mes, hace dos años
+
+    let caps = AGO.captures(text)?;
+
+    let num = string_to_num_spanish(&caps["num"])?;
+
+    let expression = match normalize_spanish(&caps["unit"]).as_ref() {
+        "dia" | "dias" => DateExpression::InXDays(-num),
+        "semana" | "semanas" => DateExpression::InXDays(num.checked_mul(-7)?),
+        "mes" | "meses" => DateExpression::InXMonths(-num),
+        "ano" | "anos" => DateExpression::InXYears(-num),
+        _ => return None,
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_ago_works_when {
+    use super::parse_ago;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn past_expressions_are_parsed() {
+        assert_ago("hace 3 días", DateExpression::InXDays(-3));
+        assert_ago("Hace un dia", DateExpression::InXDays(-1));
+        assert_ago("hace dos semanas", DateExpression::InXDays(-14));
+        assert_ago("hace un mes", DateExpression::InXMonths(-1));
+        assert_ago("hace tres meses", DateExpression::InXMonths(-3));
+        assert_ago("hace dos años", DateExpression::InXYears(-2));
+    }
+
+    #[test]
+    fn no_past_expression_is_found() {
+        assert_eq!(parse_ago("hace frío"), None);
+        assert_eq!(parse_ago("en 3 días"), None);
+    }
+
+    fn assert_ago(text: &str, expected: DateExpression) {
+        assert_eq!(
+            parse_ago(text).map(|recognized| recognized.expression),
+            Some(expected),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{
+        parse_weekday_spanish, relative_word_to_num, PRECEDING_RELATIVE_WORDS, RELATIVE_WORDS,
+        WEEKDAYS,
+    },
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DATE_IN_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?:el\s)?(?P<prep>{preceding})\s(?P<day>{weekdays})|(?:el\s)?(?P<day2>{weekdays})\s(?P<prep2>{relative})|este\s(?P<day3>{weekdays}))\b",
+        weekdays = WEEKDAYS,
+        preceding = PRECEDING_RELATIVE_WORDS,
+        relative = RELATIVE_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_date_in_week(text: &str) -> Option<RecognizedDateExpression> {
+    // el próximo lunes, el lunes que viene, el viernes pasado, este sábado
+
+    let caps = DATE_IN_WEEK.captures(text)?;
+
+    let relative_week = match caps.name("prep").or(caps.name("prep2")) {
+        Some(prep_match) => relative_word_to_num(prep_match.as_str())?,
+        None => 0,
+    };
+    let day_match = caps
+        .name("day")
+        .or(caps.name("day2"))
+        .or(caps.name("day3"))?;
+    let day = parse_weekday_spanish(day_match.as_str())?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(relative_week, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_date_in_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_date_in_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn day_in_n_weeks() {
+        assert_day_in_n_weeks("el próximo lunes", Weekday::Mon, 1);
+        assert_day_in_n_weeks("el proximo martes", Weekday::Tue, 1);
+        assert_day_in_n_weeks("el miércoles que viene", Weekday::Wed, 1);
+        assert_day_in_n_weeks("el viernes pasado", Weekday::Fri, -1);
+        assert_day_in_n_weeks("el pasado jueves", Weekday::Thu, -1);
+        assert_day_in_n_weeks("este sábado", Weekday::Sat, 0);
+    }
+
+    #[test]
+    fn range_includes_the_article() {
+        let text = "Dentista el próximo lunes a las 10";
+        let recognized = parse_date_in_week(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "el próximo lunes");
+    }
+
+    fn assert_day_in_n_weeks(text: &str, day: Weekday, relative_week: i32) {
+        assert_eq!(
+            parse_date_in_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(relative_week, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
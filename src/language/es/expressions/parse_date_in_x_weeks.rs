@@ -0,0 +1,58 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{string_to_num_spanish, IN_WORDS, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static IN_X_WEEKS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:{})\s(?P<num>\d{{1,3}}|{})\ssemanas?\b",
+        IN_WORDS, NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::InXWeeks(i32)`
+pub fn parse_in_x_weeks(text: &str) -> Option<RecognizedDateExpression> {
+    // en dos semanas, en 3 semanas, dentro de una semana
+
+    let caps = IN_X_WEEKS.captures(text)?;
+    let num = string_to_num_spanish(&caps["num"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXWeeks(num),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_in_x_weeks_works_when {
+    use super::parse_in_x_weeks;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn weeks_are_parsed() {
+        assert_in_x_weeks("en 3 semanas", 3);
+        assert_in_x_weeks("en dos semanas", 2);
+        assert_in_x_weeks("En una semana", 1);
+        assert_in_x_weeks("dentro de tres semanas", 3);
+    }
+
+    #[test]
+    fn no_weeks_are_found() {
+        assert_eq!(parse_in_x_weeks("en unas semanas"), None);
+        assert_eq!(parse_in_x_weeks("en 3 semanarios"), None);
+    }
+
+    fn assert_in_x_weeks(text: &str, weeks: i32) {
+        assert_eq!(
+            parse_in_x_weeks(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXWeeks(weeks)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
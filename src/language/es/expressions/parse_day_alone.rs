@@ -0,0 +1,53 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{parse_weekday_spanish, WEEKDAYS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DAY_ALONE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?i)\b(?P<day>{})\b", WEEKDAYS)).unwrap());
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(0, Weekday)`
+pub fn parse_day_alone(text: &str) -> Option<RecognizedDateExpression> {
+    // sábado
+
+    let caps = DAY_ALONE.captures(text)?;
+    let day = parse_weekday_spanish(&caps["day"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(0, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_alone_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_alone;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn weekdays_are_parsed() {
+        assert_day_alone("lunes", Weekday::Mon);
+        assert_day_alone("Compras el sábado", Weekday::Sat);
+        assert_day_alone("MIERCOLES", Weekday::Wed);
+    }
+
+    #[test]
+    fn weekdays_inside_words_are_ignored() {
+        assert_eq!(parse_day_alone("domingos"), None);
+    }
+
+    fn assert_day_alone(text: &str, day: Weekday) {
+        assert_eq!(
+            parse_day_alone(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(0, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
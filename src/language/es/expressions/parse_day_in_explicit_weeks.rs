@@ -0,0 +1,70 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{
+        parse_weekday_spanish, string_to_num_spanish, IN_WORDS, NUMBER_WORDS, WEEKDAYS,
+    },
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DAY_IN_EXPLICIT_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?:el\s)?(?P<day>{weekdays})\s(?:{in_words})\s(?P<num>\d{{1,3}}|{numbers})\ssemanas?|(?:{in_words})\s(?P<num2>\d{{1,3}}|{numbers})\ssemanas?,?\s(?:el\s)?(?P<day2>{weekdays}))\b",
+        weekdays = WEEKDAYS,
+        in_words = IN_WORDS,
+        numbers = NUMBER_WORDS
+    )).unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_day_in_explicit_week(text: &str) -> Option<RecognizedDateExpression> {
+    // el lunes dentro de dos semanas, en 2 semanas el lunes
+
+    let caps = DAY_IN_EXPLICIT_WEEK.captures(text)?;
+
+    let (num_match, day_match) = match caps.name("num") {
+        Some(num_match) => (num_match, caps.name("day")?),
+        None => (caps.name("num2")?, caps.name("day2")?),
+    };
+
+    let in_weeks = string_to_num_spanish(num_match.as_str())?;
+    let day = parse_weekday_spanish(day_match.as_str())?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(in_weeks, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_in_explicit_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_in_explicit_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn day_in_explicit_week() {
+        assert_day_in_explicit_week("el lunes dentro de dos semanas", Weekday::Mon, 2);
+        assert_day_in_explicit_week("Viernes en 3 semanas", Weekday::Fri, 3);
+        assert_day_in_explicit_week("en una semana el martes", Weekday::Tue, 1);
+        assert_day_in_explicit_week("dentro de dos semanas, el jueves", Weekday::Thu, 2);
+    }
+
+    #[test]
+    fn no_day_in_explicit_week_is_found() {
+        assert_eq!(parse_day_in_explicit_week("el lunes en unas semanas"), None);
+        assert_eq!(parse_day_in_explicit_week("en dos semanas"), None);
+    }
+
+    fn assert_day_in_explicit_week(text: &str, day: Weekday, in_weeks: i32) {
+        assert_eq!(
+            parse_day_in_explicit_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(in_weeks, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{
+        parse_weekday_spanish, relative_word_to_num, PRECEDING_RELATIVE_WORDS, RELATIVE_WORDS,
+        WEEKDAYS,
+    },
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DAY_IN_RELATIVE_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?:el\s)?(?P<day>{weekdays})\s(?:de\s)?(?:la\s)?(?:(?P<prep>{preceding})\ssemana|semana\s(?P<prep2>{relative}))|(?:la\s)?(?:(?P<prep3>{preceding})\ssemana|semana\s(?P<prep4>{relative})),?\s(?:el\s)?(?P<day2>{weekdays}))\b",
+        weekdays = WEEKDAYS,
+        preceding = PRECEDING_RELATIVE_WORDS,
+        relative = RELATIVE_WORDS
+    )).unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_day_in_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    // el lunes de la semana que viene, el lunes de la próxima semana, la semana que viene el lunes
+
+    let caps = DAY_IN_RELATIVE_WEEK.captures(text)?;
+
+    let prep_match = caps
+        .name("prep")
+        .or(caps.name("prep2"))
+        .or(caps.name("prep3"))
+        .or(caps.name("prep4"))?;
+    let day_match = caps.name("day").or(caps.name("day2"))?;
+
+    let relative_week = relative_word_to_num(prep_match.as_str())?;
+    let day = parse_weekday_spanish(day_match.as_str())?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(relative_week, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_in_relative_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_in_relative_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn day_in_relative_week() {
+        assert_day_in_relative_week("el lunes de la semana que viene", Weekday::Mon, 1);
+        assert_day_in_relative_week("Martes de la próxima semana", Weekday::Tue, 1);
+        assert_day_in_relative_week("el jueves de la semana siguiente", Weekday::Thu, 1);
+        assert_day_in_relative_week("la semana que viene, el viernes", Weekday::Fri, 1);
+        assert_day_in_relative_week("la proxima semana el sabado", Weekday::Sat, 1);
+        assert_day_in_relative_week("el domingo de la semana pasada", Weekday::Sun, -1);
+    }
+
+    #[test]
+    fn range_includes_the_week() {
+        let text = "Reunión el miércoles de la semana que viene.";
+        let recognized = parse_day_in_relative_week(text).unwrap();
+
+        assert_eq!(
+            &text[recognized.range],
+            "el miércoles de la semana que viene"
+        );
+    }
+
+    fn assert_day_in_relative_week(text: &str, day: Weekday, relative_week: i32) {
+        assert_eq!(
+            parse_day_in_relative_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(relative_week, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{string_to_num_spanish, IN_WORDS, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static IN_N_MONTHS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:{})\s(?P<num>\d{{1,3}}|{})\s(?:meses|mes)\b",
+        IN_WORDS, NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+pub fn parse_in_n_months(text: &str) -> Option<RecognizedDateExpression> {
+    // en dos meses, dentro de 2 meses, en un mes
+
+    let caps = IN_N_MONTHS.captures(text)?;
+    let num = string_to_num_spanish(&caps["num"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXMonths(num),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_in_n_months_works_when {
+    use super::parse_in_n_months;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn months_are_parsed() {
+        assert_in_n_months("en 2 meses", 2);
+        assert_in_n_months("en dos meses", 2);
+        assert_in_n_months("En un mes", 1);
+        assert_in_n_months("dentro de seis meses", 6);
+    }
+
+    #[test]
+    fn no_months_are_found() {
+        assert_eq!(parse_in_n_months("en unos meses"), None);
+        assert_eq!(parse_in_n_months("en 2 mesas"), None);
+    }
+
+    fn assert_in_n_months(text: &str, months: i32) {
+        assert_eq!(
+            parse_in_n_months(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXMonths(months)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
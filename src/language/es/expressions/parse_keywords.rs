@@ -0,0 +1,97 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::normalize_spanish,
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static KEYWORDS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:(?P<morning>(?:las?|unas?|esa|cada|toda)\sma[nñ]anas?)|(?P<this_morning>esta\sma[nñ]ana)|(?P<key>pasado\sma[nñ]ana|ma[nñ]ana|hoy|anteayer|antes\sde\sayer|ayer)(?:\s(?:por|en)\sla\s(?:ma[nñ]ana|tarde|noche))?)\b").unwrap()
+});
+
+/// Parses common keywords into an `Option` containing a `DateExpr::InNDays(i32)`.
+///
+/// `mañana` is tomorrow, unless an article or a determiner like `la` or `cada` makes it the morning.
+/// The morning is skipped, so in `mañana por la mañana` only the first `mañana` is a date.
+/// `esta mañana` is this morning and therefore today.
+pub fn parse_keywords(text: &str) -> Option<RecognizedDateExpression> {
+    // hoy, mañana, pasado mañana, ayer, anteayer, mañana por la mañana, esta mañana
+
+    KEYWORDS.captures_iter(text).find_map(|caps| {
+        let n = if caps.name("this_morning").is_some() {
+            0
+        } else {
+            match normalize_spanish(caps.name("key")?.as_str()).as_ref() {
+                "hoy" => 0,
+                "manana" => 1,
+                "pasado manana" => 2,
+                "ayer" => -1,
+                "anteayer" | "antes de ayer" => -2,
+                _ => return None,
+            }
+        };
+
+        Some(RecognizedDateExpression::from_captures(
+            DateExpression::InXDays(n),
+            &caps,
+        ))
+    })
+}
+
+#[cfg(test)]
+mod parse_keywords_works_when {
+    use crate::language::{
+        es::expressions::parse_keywords::parse_keywords, shared::DateExpression,
+    };
+
+    #[test]
+    fn day_keywords() {
+        assert_in_n_days("hoy", 0);
+        assert_in_n_days("mañana", 1);
+        assert_in_n_days("Manana", 1);
+        assert_in_n_days("pasado mañana", 2);
+        assert_in_n_days("ayer", -1);
+        assert_in_n_days("anteayer", -2);
+        assert_in_n_days("antes de ayer", -2);
+    }
+
+    #[test]
+    fn the_morning_is_no_date() {
+        assert_eq!(parse_keywords("Correr por la mañana"), None);
+        assert_eq!(parse_keywords("cada mañana"), None);
+        assert_eq!(parse_keywords("las mañanas"), None);
+        assert_in_n_days("Correr por la mañana y cenar mañana", 1);
+        assert_in_n_days("esta mañana", 0);
+    }
+
+    #[test]
+    fn the_part_of_the_day_belongs_to_the_date() {
+        let text = "Llamar a Bob mañana por la mañana.";
+        let recognized = parse_keywords(text).unwrap();
+
+        assert_eq!(recognized.expression, DateExpression::InXDays(1));
+        assert_eq!(&text[recognized.range], "mañana por la mañana");
+
+        let text = "Cine hoy por la noche";
+        let recognized = parse_keywords(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "hoy por la noche");
+    }
+
+    #[test]
+    fn keywords_inside_words_are_ignored() {
+        assert_eq!(parse_keywords("hoyo"), None);
+        assert_eq!(parse_keywords("mañanero"), None);
+    }
+
+    fn assert_in_n_days(text: &str, n: i32) {
+        assert_eq!(
+            parse_keywords(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(n)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,104 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{
+        day_of_month_spanish, parse_month_of_year_spanish, DAYS, MONTHS, NUMBER_WORDS,
+    },
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static MONTH_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:el\s)?(?P<date>{days}|{numbers})\s(?:de\s)?(?P<month>{months})(?:\s(?:de\s|del\s)?(?P<year>\d{{4}})\b)?",
+        days = DAYS,
+        numbers = NUMBER_WORDS,
+        months = MONTHS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::DayInMonth(Month, u32)` or a
+/// `DateExpr::DayInMonthInYear(Month, u32, i32)`.
+pub fn parse_month_date(text: &str) -> Option<RecognizedDateExpression> {
+    // el 5 de diciembre, el primero de mayo, el veinte de mayo, 1º de dic. de 2026
+
+    let caps = MONTH_DATE.captures(text)?;
+
+    let date = day_of_month_spanish(&caps["date"])?;
+    let month = parse_month_of_year_spanish(&caps["month"])?;
+
+    let expression = match caps.name("year") {
+        Some(year) => DateExpression::DayInMonthInYear(month, date, year.as_str().parse().ok()?),
+        None => DateExpression::DayInMonth(month, date),
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_month_date_spanish_works_when {
+    use super::parse_month_date;
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn day_and_month_are_parsed() {
+        assert_month_date(
+            "el 5 de diciembre",
+            DateExpression::DayInMonth(Month::December, 5),
+        );
+        assert_month_date(
+            "5 diciembre",
+            DateExpression::DayInMonth(Month::December, 5),
+        );
+        assert_month_date(
+            "24 de dic.",
+            DateExpression::DayInMonth(Month::December, 24),
+        );
+        assert_month_date(
+            "el primero de mayo",
+            DateExpression::DayInMonth(Month::May, 1),
+        );
+        assert_month_date("1º de marzo", DateExpression::DayInMonth(Month::March, 1));
+        assert_month_date(
+            "15 de setiembre",
+            DateExpression::DayInMonth(Month::September, 15),
+        );
+    }
+
+    #[test]
+    fn day_month_and_year_are_parsed() {
+        assert_month_date(
+            "el 5 de diciembre de 2026",
+            DateExpression::DayInMonthInYear(Month::December, 5, 2026),
+        );
+        assert_month_date(
+            "1 oct. 2027",
+            DateExpression::DayInMonthInYear(Month::October, 1, 2027),
+        );
+    }
+
+    #[test]
+    fn range_includes_the_article() {
+        let text = "Fiesta el 5 de diciembre.";
+        let recognized = parse_month_date(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "el 5 de diciembre");
+    }
+
+    #[test]
+    fn no_date_is_found() {
+        assert_eq!(parse_month_date("5 de mayonesa"), None);
+        assert_eq!(parse_month_date("diciembre"), None);
+    }
+
+    fn assert_month_date(text: &str, expected: DateExpression) {
+        assert_eq!(
+            parse_month_date(text).map(|recognized| recognized.expression),
+            Some(expected),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
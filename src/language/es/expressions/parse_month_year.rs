@@ -0,0 +1,66 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{parse_month_of_year_spanish, MONTHS},
+    shared::{date_in_written_year, RecognizedDateExpression},
+};
+
+static MONTH_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:en\s)?(?P<month>{})\s(?:de\s|del\s)?(?P<year>\d{{4}})\b",
+        MONTHS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InMonthInYear(Month, i32)`
+pub fn parse_month_year(text: &str) -> Option<RecognizedDateExpression> {
+    // diciembre de 2027, en junio del 2027, dic. 2026
+
+    let caps = MONTH_YEAR.captures(text)?;
+
+    let month = parse_month_of_year_spanish(&caps["month"])?;
+    let expression = date_in_written_year(month, None, &caps["year"])?;
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_month_year_spanish_works_when {
+    use super::parse_month_year;
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn months_with_a_year_are_parsed() {
+        assert_month_year("diciembre de 2027", Month::December, 2027);
+        assert_month_year("en junio del 2027", Month::June, 2027);
+        assert_month_year("Renovar el contrato en dic. 2026", Month::December, 2026);
+        assert_month_year("setiembre 2030", Month::September, 2030);
+    }
+
+    #[test]
+    fn range_includes_en() {
+        let text = "Vacaciones en febrero de 2027.";
+        let recognized = parse_month_year(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "en febrero de 2027");
+    }
+
+    #[test]
+    fn no_month_with_a_year_is_found() {
+        assert_eq!(parse_month_year("diciembre"), None);
+        assert_eq!(parse_month_year("diciembre de 20271"), None);
+        assert_eq!(parse_month_year("mayonesa 2027"), None);
+    }
+
+    fn assert_month_year(text: &str, expected_month: Month, expected_year: i32) {
+        assert_eq!(
+            parse_month_year(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InMonthInYear(expected_month, expected_year)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
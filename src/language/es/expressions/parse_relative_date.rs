@@ -0,0 +1,59 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{string_to_num_spanish, IN_WORDS, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static RELATIVE_DAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:{})\s(?P<num>\d{{1,3}}|{})\sd[ií]as?\b",
+        IN_WORDS, NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNDays(i32)`
+pub fn parse_relative_day(text: &str) -> Option<RecognizedDateExpression> {
+    // dentro de tres días, en 3 días, en un día
+
+    let caps = RELATIVE_DAY.captures(text)?;
+    let num = string_to_num_spanish(&caps["num"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXDays(num),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_relative_day_works_when {
+    use super::parse_relative_day;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn days_are_parsed() {
+        assert_in_x_days("dentro de tres días", 3);
+        assert_in_x_days("en 3 días", 3);
+        assert_in_x_days("en 3 dias", 3);
+        assert_in_x_days("en un día", 1);
+        assert_in_x_days("En quince días", 15);
+    }
+
+    #[test]
+    fn no_days_are_found() {
+        assert_eq!(parse_relative_day("en unos días"), None);
+        assert_eq!(parse_relative_day("en 3 diarios"), None);
+    }
+
+    fn assert_in_x_days(text: &str, days: i32) {
+        assert_eq!(
+            parse_relative_day(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(days)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
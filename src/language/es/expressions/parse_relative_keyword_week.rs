@@ -0,0 +1,65 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{relative_word_to_num, PRECEDING_RELATIVE_WORDS, RELATIVE_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static KEYWORD_RELATIVE_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?:la\s)?(?P<prep>{})\ssemana|(?:la\s)?semana\s(?P<prep2>{})|esta\ssemana)\b",
+        PRECEDING_RELATIVE_WORDS, RELATIVE_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::InXWeeks(i32)`
+pub fn parse_keyword_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    // la semana que viene, la próxima semana, esta semana, la semana pasada
+
+    let caps = KEYWORD_RELATIVE_WEEK.captures(text)?;
+    let relative_week = match caps.name("prep").or(caps.name("prep2")) {
+        Some(prep) => relative_word_to_num(prep.as_str())?,
+        None => 0,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXWeeks(relative_week),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_keyword_relative_week_works_when {
+    use super::parse_keyword_relative_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn relative_weeks_are_parsed() {
+        assert_in_x_weeks("la semana que viene", 1);
+        assert_in_x_weeks("la próxima semana", 1);
+        assert_in_x_weeks("la semana próxima", 1);
+        assert_in_x_weeks("semana siguiente", 1);
+        assert_in_x_weeks("esta semana", 0);
+        assert_in_x_weeks("la semana pasada", -1);
+    }
+
+    #[test]
+    fn range_includes_the_article() {
+        let text = "Reunión la semana que viene";
+        let recognized = parse_keyword_relative_week(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "la semana que viene");
+    }
+
+    fn assert_in_x_weeks(text: &str, weeks: i32) {
+        assert_eq!(
+            parse_keyword_relative_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXWeeks(weeks)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
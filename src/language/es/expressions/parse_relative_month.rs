@@ -0,0 +1,61 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{relative_word_to_num, PRECEDING_RELATIVE_WORDS, RELATIVE_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static RELATIVE_MONTH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?:el\s)?(?P<prep>{})\smes|(?:el\s)?mes\s(?P<prep2>{})|este\smes)\b",
+        PRECEDING_RELATIVE_WORDS, RELATIVE_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+pub fn parse_relative_month(text: &str) -> Option<RecognizedDateExpression> {
+    // el mes que viene, el próximo mes, este mes, el mes pasado
+
+    let caps = RELATIVE_MONTH.captures(text)?;
+    let relative_month = match caps.name("prep").or(caps.name("prep2")) {
+        Some(prep) => relative_word_to_num(prep.as_str())?,
+        None => 0,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXMonths(relative_month),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_relative_month_works_when {
+    use super::parse_relative_month;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn relative_months_are_parsed() {
+        assert_in_x_months("el mes que viene", 1);
+        assert_in_x_months("el próximo mes", 1);
+        assert_in_x_months("mes siguiente", 1);
+        assert_in_x_months("este mes", 0);
+        assert_in_x_months("el mes pasado", -1);
+    }
+
+    #[test]
+    fn no_relative_month_is_found() {
+        assert_eq!(parse_relative_month("el mesón que viene"), None);
+    }
+
+    fn assert_in_x_months(text: &str, months: i32) {
+        assert_eq!(
+            parse_relative_month(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXMonths(months)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,167 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    es::es_date_parser::{
+        day_of_month_spanish, parse_month_of_year_spanish, relative_word_to_num,
+        string_to_num_spanish, DAYS, IN_WORDS, MONTHS, NUMBER_WORDS, PRECEDING_RELATIVE_WORDS,
+        RELATIVE_WORDS,
+    },
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static RELATIVE_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?:el\s)?(?P<prep>{preceding})\sa[nñ]o|(?:el\s)?a[nñ]o\s(?P<prep2>{relative})|este\sa[nñ]o|(?:{in_words})\s(?P<num>\d{{1,3}}|{numbers})\sa[nñ]os?)\b",
+        preceding = PRECEDING_RELATIVE_WORDS,
+        relative = RELATIVE_WORDS,
+        in_words = IN_WORDS,
+        numbers = NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+static MONTH_IN_RELATIVE_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:en\s)?(?:(?:el\s)?(?P<date>{days}|{numbers})\s(?:de\s)?)?(?P<month>{months})\s(?:del\s(?:(?P<prep>{preceding})\sa[nñ]o|a[nñ]o\s(?P<prep2>{relative}))|de\seste\sa[nñ]o)\b",
+        days = DAYS,
+        numbers = NUMBER_WORDS,
+        months = MONTHS,
+        preceding = PRECEDING_RELATIVE_WORDS,
+        relative = RELATIVE_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InXYears(i32)`
+pub fn parse_relative_year(text: &str) -> Option<RecognizedDateExpression> {
+    // el año que viene, el próximo año, este año, el año pasado, dentro de dos años
+
+    let caps = RELATIVE_YEAR.captures(text)?;
+
+    let years = match (caps.name("prep").or(caps.name("prep2")), caps.name("num")) {
+        (Some(prep), _) => relative_word_to_num(prep.as_str())?,
+        (None, Some(num)) => string_to_num_spanish(num.as_str())?,
+        (None, None) => 0,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXYears(years),
+        &caps,
+    ))
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::MonthInXYears(Month, i32)`
+/// or a `DateExpr::DayInMonthInXYears(Month, u32, i32)`.
+pub fn parse_month_in_relative_year(text: &str) -> Option<RecognizedDateExpression> {
+    // en diciembre del año que viene, el 5 de junio del año pasado, en marzo de este año
+
+    let caps = MONTH_IN_RELATIVE_YEAR.captures(text)?;
+
+    let month = parse_month_of_year_spanish(&caps["month"])?;
+    let years = match caps.name("prep").or(caps.name("prep2")) {
+        Some(prep) => relative_word_to_num(prep.as_str())?,
+        None => 0,
+    };
+
+    let expression = match caps.name("date") {
+        Some(date) => {
+            DateExpression::DayInMonthInXYears(month, day_of_month_spanish(date.as_str())?, years)
+        }
+        None => DateExpression::MonthInXYears(month, years),
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_relative_year_works_when {
+    use super::{parse_month_in_relative_year, parse_relative_year};
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn relative_years_are_parsed() {
+        assert_in_x_years("el año que viene", 1);
+        assert_in_x_years("el próximo año", 1);
+        assert_in_x_years("el ano proximo", 1);
+        assert_in_x_years("este año", 0);
+        assert_in_x_years("el año pasado", -1);
+        assert_in_x_years("dentro de dos años", 2);
+        assert_in_x_years("en 1 año", 1);
+    }
+
+    #[test]
+    fn range_includes_the_article() {
+        let text = "Vacaciones el año que viene";
+        let recognized = parse_relative_year(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "el año que viene");
+    }
+
+    #[test]
+    fn no_relative_year_is_found() {
+        assert_eq!(parse_relative_year("en unos años"), None);
+        assert_eq!(parse_relative_year("el añorado pasado"), None);
+    }
+
+    #[test]
+    fn months_in_relative_years_are_parsed() {
+        for (text, expected) in [
+            (
+                "en diciembre del año que viene",
+                DateExpression::MonthInXYears(Month::December, 1),
+            ),
+            (
+                "en diciembre del próximo año",
+                DateExpression::MonthInXYears(Month::December, 1),
+            ),
+            (
+                "en marzo del ano pasado",
+                DateExpression::MonthInXYears(Month::March, -1),
+            ),
+            (
+                "en junio de este año",
+                DateExpression::MonthInXYears(Month::June, 0),
+            ),
+            (
+                "el 5 de junio del año que viene",
+                DateExpression::DayInMonthInXYears(Month::June, 5, 1),
+            ),
+            (
+                "el primero de mayo del año pasado",
+                DateExpression::DayInMonthInXYears(Month::May, 1, -1),
+            ),
+        ] {
+            assert_eq!(
+                parse_month_in_relative_year(text).map(|recognized| recognized.expression),
+                Some(expected),
+                "Failed for input: {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn range_of_month_in_relative_year_includes_en() {
+        let text = "Vacaciones en diciembre del año que viene";
+        let recognized = parse_month_in_relative_year(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "en diciembre del año que viene");
+    }
+
+    #[test]
+    fn no_month_in_relative_year_is_found() {
+        assert_eq!(parse_month_in_relative_year("el año que viene"), None);
+        assert_eq!(parse_month_in_relative_year("en diciembre"), None);
+    }
+
+    fn assert_in_x_years(text: &str, years: i32) {
+        assert_eq!(
+            parse_relative_year(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXYears(years)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
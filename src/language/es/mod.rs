@@ -0,0 +1,2 @@
+pub mod es_date_parser;
+mod expressions;
@@ -0,0 +1,15 @@
+pub mod parse_ago;
+pub mod parse_date_in_week;
+pub mod parse_date_in_x_weeks;
+pub mod parse_day_alone;
+pub mod parse_day_in_explicit_weeks;
+pub mod parse_day_in_relative_week;
+pub mod parse_day_of_month;
+pub mod parse_in_n_months;
+pub mod parse_keywords;
+pub mod parse_month_date;
+pub mod parse_month_year;
+pub mod parse_relative_date;
+pub mod parse_relative_keyword_week;
+pub mod parse_relative_month;
+pub mod parse_relative_year;
@@ -0,0 +1,68 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::{normalize_french, string_to_num_french, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static AGO: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\bil\sy\sa\s(?P<num>\d{{1,3}}|{})\s(?P<unit>jours?|semaines?|mois|ans|an|ann[eé]es?)\b",
+        NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InXDays(i32)`, `DateExpr::InXMonths(i32)`
+/// or `DateExpr::InXYears(i32)` with a negative offset.
+pub fn parse_ago(text: &str) -> Option<RecognizedDateExpression> {
+    // il y a trois jours, il y a 2 semaines, il y a un mois, il y a deux ans
+
+    let caps = AGO.captures(text)?;
+
+    let num = string_to_num_french(&caps["num"])?;
+
+    let expression = match normalize_french(&caps["unit"]).as_ref() {
+        "jour" | "jours" => DateExpression::InXDays(-num),
+        "semaine" | "semaines" => DateExpression::InXDays(num.checked_mul(-7)?),
+        "mois" => DateExpression::InXMonths(-num),
+        "an" | "ans" | "annee" | "annees" => DateExpression::InXYears(-num),
+        _ => return None,
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_ago_works_when {
+    use super::parse_ago;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn past_expressions_are_parsed() {
+        assert_ago("il y a 3 jours", DateExpression::InXDays(-3));
+        assert_ago("Il y a un jour", DateExpression::InXDays(-1));
+        assert_ago("il y a deux semaines", DateExpression::InXDays(-14));
+        assert_ago("il y a un mois", DateExpression::InXMonths(-1));
+        assert_ago("il y a deux ans", DateExpression::InXYears(-2));
+        assert_ago("il y a une année", DateExpression::InXYears(-1));
+        assert_ago("il y a trois annees", DateExpression::InXYears(-3));
+    }
+
+    #[test]
+    fn no_past_expression_is_found() {
+        assert_eq!(parse_ago("il y a des jours"), None);
+        assert_eq!(parse_ago("dans 3 jours"), None);
+    }
+
+    fn assert_ago(text: &str, expected: DateExpression) {
+        assert_eq!(
+            parse_ago(text).map(|recognized| recognized.expression),
+            Some(expected),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
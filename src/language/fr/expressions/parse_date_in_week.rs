@@ -0,0 +1,73 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::{parse_weekday_french, relative_word_to_num, RELATIVE_WORDS, WEEKDAYS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DATE_IN_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?P<day>{weekdays})\s(?P<prep>{relative})|ce\s(?P<day2>{weekdays}))\b",
+        weekdays = WEEKDAYS,
+        relative = RELATIVE_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_date_in_week(text: &str) -> Option<RecognizedDateExpression> {
+    // lundi prochain, vendredi dernier, mardi passé, ce samedi
+
+    let caps = DATE_IN_WEEK.captures(text)?;
+
+    let (relative_week, day_match) = match caps.name("prep") {
+        Some(prep_match) => (
+            relative_word_to_num(prep_match.as_str())?,
+            caps.name("day")?,
+        ),
+        None => (0, caps.name("day2")?),
+    };
+    let day = parse_weekday_french(day_match.as_str())?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(relative_week, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_date_in_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_date_in_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn day_in_n_weeks() {
+        assert_day_in_n_weeks("lundi prochain", Weekday::Mon, 1);
+        assert_day_in_n_weeks("Mardi prochain", Weekday::Tue, 1);
+        assert_day_in_n_weeks("vendredi dernier", Weekday::Fri, -1);
+        assert_day_in_n_weeks("jeudi passé", Weekday::Thu, -1);
+        assert_day_in_n_weeks("jeudi passe", Weekday::Thu, -1);
+        assert_day_in_n_weeks("ce samedi", Weekday::Sat, 0);
+    }
+
+    #[test]
+    fn range_includes_the_relative_word() {
+        let text = "Dentiste lundi prochain à 10h";
+        let recognized = parse_date_in_week(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "lundi prochain");
+    }
+
+    fn assert_day_in_n_weeks(text: &str, day: Weekday, relative_week: i32) {
+        assert_eq!(
+            parse_date_in_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(relative_week, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::{string_to_num_french, IN_WORDS, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static IN_X_WEEKS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:{})\s(?P<num>\d{{1,3}}|{})\ssemaines?\b",
+        IN_WORDS, NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::InXWeeks(i32)`
+pub fn parse_in_x_weeks(text: &str) -> Option<RecognizedDateExpression> {
+    // dans trois semaines, dans 3 semaines, d'ici une semaine
+
+    let caps = IN_X_WEEKS.captures(text)?;
+    let num = string_to_num_french(&caps["num"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXWeeks(num),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_in_x_weeks_works_when {
+    use super::parse_in_x_weeks;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn weeks_are_parsed() {
+        assert_in_x_weeks("dans 3 semaines", 3);
+        assert_in_x_weeks("dans trois semaines", 3);
+        assert_in_x_weeks("Dans une semaine", 1);
+        assert_in_x_weeks("d'ici deux semaines", 2);
+        assert_in_x_weeks("d’ici 2 semaines", 2);
+    }
+
+    #[test]
+    fn no_weeks_are_found() {
+        assert_eq!(parse_in_x_weeks("dans des semaines"), None);
+        assert_eq!(parse_in_x_weeks("dans 3 semainiers"), None);
+    }
+
+    fn assert_in_x_weeks(text: &str, weeks: i32) {
+        assert_eq!(
+            parse_in_x_weeks(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXWeeks(weeks)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
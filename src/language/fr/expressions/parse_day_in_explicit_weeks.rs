@@ -0,0 +1,70 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::{
+        parse_weekday_french, string_to_num_french, IN_WORDS, NUMBER_WORDS, WEEKDAYS,
+    },
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DAY_IN_EXPLICIT_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?P<day>{weekdays})\s(?:{in_words})\s(?P<num>\d{{1,3}}|{numbers})\ssemaines?|(?:{in_words})\s(?P<num2>\d{{1,3}}|{numbers})\ssemaines?,?\s(?:le\s)?(?P<day2>{weekdays}))\b",
+        weekdays = WEEKDAYS,
+        in_words = IN_WORDS,
+        numbers = NUMBER_WORDS
+    )).unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_day_in_explicit_week(text: &str) -> Option<RecognizedDateExpression> {
+    // lundi dans deux semaines, dans 2 semaines le lundi
+
+    let caps = DAY_IN_EXPLICIT_WEEK.captures(text)?;
+
+    let (num_match, day_match) = match caps.name("num") {
+        Some(num_match) => (num_match, caps.name("day")?),
+        None => (caps.name("num2")?, caps.name("day2")?),
+    };
+
+    let in_weeks = string_to_num_french(num_match.as_str())?;
+    let day = parse_weekday_french(day_match.as_str())?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(in_weeks, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_in_explicit_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_in_explicit_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn day_in_explicit_week() {
+        assert_day_in_explicit_week("lundi dans deux semaines", Weekday::Mon, 2);
+        assert_day_in_explicit_week("Vendredi dans 3 semaines", Weekday::Fri, 3);
+        assert_day_in_explicit_week("dans une semaine le mardi", Weekday::Tue, 1);
+        assert_day_in_explicit_week("d'ici deux semaines, jeudi", Weekday::Thu, 2);
+    }
+
+    #[test]
+    fn no_day_in_explicit_week_is_found() {
+        assert_eq!(parse_day_in_explicit_week("lundi dans des semaines"), None);
+        assert_eq!(parse_day_in_explicit_week("dans deux semaines"), None);
+    }
+
+    fn assert_day_in_explicit_week(text: &str, day: Weekday, in_weeks: i32) {
+        assert_eq!(
+            parse_day_in_explicit_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(in_weeks, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
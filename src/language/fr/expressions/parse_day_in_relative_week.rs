@@ -0,0 +1,71 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::{parse_weekday_french, relative_word_to_num, RELATIVE_WORDS, WEEKDAYS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DAY_IN_RELATIVE_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?P<day>{weekdays})\s(?:de\s)?la\ssemaine\s(?P<prep>{relative})|(?:la\s)?semaine\s(?P<prep2>{relative}),?\s(?:le\s)?(?P<day2>{weekdays}))\b",
+        weekdays = WEEKDAYS,
+        relative = RELATIVE_WORDS
+    )).unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_day_in_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    // lundi de la semaine prochaine, lundi la semaine prochaine, la semaine prochaine lundi
+
+    let caps = DAY_IN_RELATIVE_WEEK.captures(text)?;
+
+    let (prep_match, day_match) = match caps.name("prep") {
+        Some(prep_match) => (prep_match, caps.name("day")?),
+        None => (caps.name("prep2")?, caps.name("day2")?),
+    };
+
+    let relative_week = relative_word_to_num(prep_match.as_str())?;
+    let day = parse_weekday_french(day_match.as_str())?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(relative_week, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_in_relative_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_in_relative_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn day_in_relative_week() {
+        assert_day_in_relative_week("lundi de la semaine prochaine", Weekday::Mon, 1);
+        assert_day_in_relative_week("Mardi la semaine prochaine", Weekday::Tue, 1);
+        assert_day_in_relative_week("la semaine prochaine, le jeudi", Weekday::Thu, 1);
+        assert_day_in_relative_week("semaine prochaine vendredi", Weekday::Fri, 1);
+        assert_day_in_relative_week("samedi de la semaine dernière", Weekday::Sat, -1);
+        assert_day_in_relative_week("la semaine derniere dimanche", Weekday::Sun, -1);
+    }
+
+    #[test]
+    fn range_includes_the_week() {
+        let text = "Réunion mercredi de la semaine prochaine.";
+        let recognized = parse_day_in_relative_week(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "mercredi de la semaine prochaine");
+    }
+
+    fn assert_day_in_relative_week(text: &str, day: Weekday, relative_week: i32) {
+        assert_eq!(
+            parse_day_in_relative_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(relative_week, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
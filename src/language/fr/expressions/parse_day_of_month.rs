@@ -0,0 +1,65 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::day_of_month_french,
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DAY_OF_MONTH: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\ble\s(?P<day>1er|[12]\d|3[01]|0?[1-9])\b").unwrap());
+
+/// Parses a day without a month like `le 22` into an `Option` containing a `DateExpr::NextDayOfMonth(u32)`.
+///
+/// Spelled-out days like `le premier` are left out, because they are rarely meant as a date.
+pub fn parse_day_of_month(text: &str) -> Option<RecognizedDateExpression> {
+    // le 22, le 1er, payer le loyer le 5
+
+    let caps = DAY_OF_MONTH.captures(text)?;
+
+    let day = day_of_month_french(&caps["day"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::NextDayOfMonth(day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_of_month_french_works_when {
+    use super::parse_day_of_month;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn days_without_a_month_are_parsed() {
+        assert_day_of_month("le 22", 22);
+        assert_day_of_month("payer le loyer le 1er", 1);
+        assert_day_of_month("Le 5, appeler Paul", 5);
+    }
+
+    #[test]
+    fn range_includes_the_article() {
+        let text = "payer le loyer le 5";
+        let recognized = parse_day_of_month(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "le 5");
+    }
+
+    #[test]
+    fn no_day_of_month_is_found() {
+        assert_eq!(parse_day_of_month("le 32"), None);
+        assert_eq!(parse_day_of_month("le 22h"), None);
+        assert_eq!(parse_day_of_month("le premier ministre"), None);
+        assert_eq!(parse_day_of_month("22 personnes"), None);
+    }
+
+    fn assert_day_of_month(text: &str, expected_day: u32) {
+        assert_eq!(
+            parse_day_of_month(text).map(|recognized| recognized.expression),
+            Some(DateExpression::NextDayOfMonth(expected_day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::{string_to_num_french, IN_WORDS, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static IN_N_MONTHS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:{})\s(?P<num>\d{{1,3}}|{})\smois\b",
+        IN_WORDS, NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+pub fn parse_in_n_months(text: &str) -> Option<RecognizedDateExpression> {
+    // dans deux mois, dans 2 mois, d'ici un mois
+
+    let caps = IN_N_MONTHS.captures(text)?;
+    let num = string_to_num_french(&caps["num"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXMonths(num),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_in_n_months_works_when {
+    use super::parse_in_n_months;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn months_are_parsed() {
+        assert_in_n_months("dans 2 mois", 2);
+        assert_in_n_months("dans deux mois", 2);
+        assert_in_n_months("Dans un mois", 1);
+        assert_in_n_months("d'ici six mois", 6);
+    }
+
+    #[test]
+    fn no_months_are_found() {
+        assert_eq!(parse_in_n_months("dans des mois"), None);
+        assert_eq!(parse_in_n_months("dans 2 moissons"), None);
+    }
+
+    fn assert_in_n_months(text: &str, months: i32) {
+        assert_eq!(
+            parse_in_n_months(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXMonths(months)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,76 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::normalize_french,
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static KEYWORDS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?P<key>aujourd['’]hui|apr[eè]s[-\s]demain|avant[-\s]hier|demain|hier)\b")
+        .unwrap()
+});
+
+/// Parses common keywords into an `Option` containing a `DateExpr::InNDays(i32)`.
+pub fn parse_keywords(text: &str) -> Option<RecognizedDateExpression> {
+    // aujourd'hui, demain, après-demain, hier, avant-hier
+
+    let caps = KEYWORDS.captures(text)?;
+
+    let n = match normalize_french(&caps["key"]).replace(' ', "-").as_ref() {
+        "aujourd'hui" => 0,
+        "demain" => 1,
+        "apres-demain" => 2,
+        "hier" => -1,
+        "avant-hier" => -2,
+        _ => return None,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXDays(n),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_keywords_works_when {
+    use crate::language::{
+        fr::expressions::parse_keywords::parse_keywords, shared::DateExpression,
+    };
+
+    #[test]
+    fn day_keywords() {
+        assert_in_n_days("aujourd'hui", 0);
+        assert_in_n_days("Aujourd’hui", 0);
+        assert_in_n_days("demain", 1);
+        assert_in_n_days("après-demain", 2);
+        assert_in_n_days("apres-demain", 2);
+        assert_in_n_days("Après demain", 2);
+        assert_in_n_days("hier", -1);
+        assert_in_n_days("avant-hier", -2);
+    }
+
+    #[test]
+    fn keywords_inside_words_are_ignored() {
+        assert_eq!(parse_keywords("demains"), None);
+        assert_eq!(parse_keywords("hiers"), None);
+    }
+
+    #[test]
+    fn range_of_keyword_is_returned() {
+        let text = "Appeler Bob après-demain";
+        let recognized = parse_keywords(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "après-demain");
+    }
+
+    fn assert_in_n_days(text: &str, n: i32) {
+        assert_eq!(
+            parse_keywords(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(n)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::{day_of_month_french, parse_month_of_year_french, MONTHS, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static MONTH_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:le\s)?(?P<date>premier|1er|[12]\d|3[01]|0?[1-9]|{numbers})\s(?P<month>{months})(?:\s(?P<year>\d{{4}})\b)?",
+        numbers = NUMBER_WORDS,
+        months = MONTHS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::DayInMonth(Month, u32)` or a
+/// `DateExpr::DayInMonthInYear(Month, u32, i32)`.
+pub fn parse_month_date(text: &str) -> Option<RecognizedDateExpression> {
+    // le 5 décembre, le premier mai, le deux mai, 1er déc. 2026
+
+    let caps = MONTH_DATE.captures(text)?;
+
+    let date = day_of_month_french(&caps["date"])?;
+    let month = parse_month_of_year_french(&caps["month"])?;
+
+    let expression = match caps.name("year") {
+        Some(year) => DateExpression::DayInMonthInYear(month, date, year.as_str().parse().ok()?),
+        None => DateExpression::DayInMonth(month, date),
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_month_date_french_works_when {
+    use super::parse_month_date;
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn day_and_month_are_parsed() {
+        assert_month_date(
+            "le 5 décembre",
+            DateExpression::DayInMonth(Month::December, 5),
+        );
+        assert_month_date("5 decembre", DateExpression::DayInMonth(Month::December, 5));
+        assert_month_date("24 déc.", DateExpression::DayInMonth(Month::December, 24));
+        assert_month_date("le premier mai", DateExpression::DayInMonth(Month::May, 1));
+        assert_month_date("1er mars", DateExpression::DayInMonth(Month::March, 1));
+        assert_month_date("15 août", DateExpression::DayInMonth(Month::August, 15));
+        assert_month_date("15 aout", DateExpression::DayInMonth(Month::August, 15));
+        assert_month_date("3 févr.", DateExpression::DayInMonth(Month::February, 3));
+        assert_month_date("le deux mai", DateExpression::DayInMonth(Month::May, 2));
+        assert_month_date(
+            "le trente et un décembre",
+            DateExpression::DayInMonth(Month::December, 31),
+        );
+    }
+
+    #[test]
+    fn day_month_and_year_are_parsed() {
+        assert_month_date(
+            "le 5 décembre 2026",
+            DateExpression::DayInMonthInYear(Month::December, 5, 2026),
+        );
+        assert_month_date(
+            "1er oct. 2027",
+            DateExpression::DayInMonthInYear(Month::October, 1, 2027),
+        );
+    }
+
+    #[test]
+    fn range_includes_the_article() {
+        let text = "Fête le 5 décembre.";
+        let recognized = parse_month_date(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "le 5 décembre");
+    }
+
+    #[test]
+    fn no_date_is_found() {
+        assert_eq!(parse_month_date("5 maisons"), None);
+        assert_eq!(parse_month_date("décembre"), None);
+    }
+
+    fn assert_month_date(text: &str, expected: DateExpression) {
+        assert_eq!(
+            parse_month_date(text).map(|recognized| recognized.expression),
+            Some(expected),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
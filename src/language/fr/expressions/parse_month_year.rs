@@ -0,0 +1,66 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::{parse_month_of_year_french, MONTHS},
+    shared::{date_in_written_year, RecognizedDateExpression},
+};
+
+static MONTH_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:en\s)?(?P<month>{})\s(?P<year>\d{{4}})\b",
+        MONTHS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InMonthInYear(Month, i32)`
+pub fn parse_month_year(text: &str) -> Option<RecognizedDateExpression> {
+    // décembre 2027, en juin 2027, déc. 2026
+
+    let caps = MONTH_YEAR.captures(text)?;
+
+    let month = parse_month_of_year_french(&caps["month"])?;
+    let expression = date_in_written_year(month, None, &caps["year"])?;
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_month_year_french_works_when {
+    use super::parse_month_year;
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn months_with_a_year_are_parsed() {
+        assert_month_year("décembre 2027", Month::December, 2027);
+        assert_month_year("en juin 2027", Month::June, 2027);
+        assert_month_year("Renouveler le contrat en déc. 2026", Month::December, 2026);
+        assert_month_year("aout 2030", Month::August, 2030);
+    }
+
+    #[test]
+    fn range_includes_en() {
+        let text = "Vacances en février 2027 !";
+        let recognized = parse_month_year(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "en février 2027");
+    }
+
+    #[test]
+    fn no_month_with_a_year_is_found() {
+        assert_eq!(parse_month_year("décembre"), None);
+        assert_eq!(parse_month_year("décembre 20271"), None);
+        assert_eq!(parse_month_year("maison 2027"), None);
+    }
+
+    fn assert_month_year(text: &str, expected_month: Month, expected_year: i32) {
+        assert_eq!(
+            parse_month_year(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InMonthInYear(expected_month, expected_year)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
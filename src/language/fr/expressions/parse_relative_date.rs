@@ -0,0 +1,59 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::{string_to_num_french, IN_WORDS, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static RELATIVE_DAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:{})\s(?P<num>\d{{1,3}}|{})\sjours?\b",
+        IN_WORDS, NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNDays(i32)`
+pub fn parse_relative_day(text: &str) -> Option<RecognizedDateExpression> {
+    // dans trois jours, dans 3 jours, d'ici un jour
+
+    let caps = RELATIVE_DAY.captures(text)?;
+    let num = string_to_num_french(&caps["num"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXDays(num),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_relative_day_works_when {
+    use super::parse_relative_day;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn days_are_parsed() {
+        assert_in_x_days("dans 3 jours", 3);
+        assert_in_x_days("dans trois jours", 3);
+        assert_in_x_days("dans un jour", 1);
+        assert_in_x_days("Dans quinze jours", 15);
+        assert_in_x_days("d'ici douze jours", 12);
+    }
+
+    #[test]
+    fn no_days_are_found() {
+        assert_eq!(parse_relative_day("dans des jours"), None);
+        assert_eq!(parse_relative_day("dans 3 journées"), None);
+    }
+
+    fn assert_in_x_days(text: &str, days: i32) {
+        assert_eq!(
+            parse_relative_day(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(days)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,65 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::{relative_word_to_num, RELATIVE_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static KEYWORD_RELATIVE_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?:la\s)?semaine\s(?P<prep>{})|cette\ssemaine)\b",
+        RELATIVE_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::InXWeeks(i32)`
+pub fn parse_keyword_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    // la semaine prochaine, cette semaine, la semaine dernière
+
+    let caps = KEYWORD_RELATIVE_WEEK.captures(text)?;
+    let relative_week = match caps.name("prep") {
+        Some(prep) => relative_word_to_num(prep.as_str())?,
+        None => 0,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXWeeks(relative_week),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_keyword_relative_week_works_when {
+    use super::parse_keyword_relative_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn relative_weeks_are_parsed() {
+        assert_in_x_weeks("la semaine prochaine", 1);
+        assert_in_x_weeks("semaine prochaine", 1);
+        assert_in_x_weeks("cette semaine", 0);
+        assert_in_x_weeks("la semaine dernière", -1);
+        assert_in_x_weeks("La semaine derniere", -1);
+        assert_in_x_weeks("la semaine passée", -1);
+    }
+
+    #[test]
+    fn range_includes_the_article() {
+        let text = "Réunion la semaine prochaine";
+        let recognized = parse_keyword_relative_week(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "la semaine prochaine");
+    }
+
+    fn assert_in_x_weeks(text: &str, weeks: i32) {
+        assert_eq!(
+            parse_keyword_relative_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXWeeks(weeks)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
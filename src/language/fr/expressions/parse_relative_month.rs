@@ -0,0 +1,65 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::{relative_word_to_num, RELATIVE_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static RELATIVE_MONTH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?:le\s)?mois\s(?P<prep>{})|ce\smois(?:-ci)?)\b",
+        RELATIVE_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+pub fn parse_relative_month(text: &str) -> Option<RecognizedDateExpression> {
+    // le mois prochain, ce mois-ci, le mois dernier
+
+    let caps = RELATIVE_MONTH.captures(text)?;
+    let relative_month = match caps.name("prep") {
+        Some(prep) => relative_word_to_num(prep.as_str())?,
+        None => 0,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXMonths(relative_month),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_relative_month_works_when {
+    use super::parse_relative_month;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn relative_months_are_parsed() {
+        assert_in_x_months("le mois prochain", 1);
+        assert_in_x_months("mois prochain", 1);
+        assert_in_x_months("ce mois-ci", 0);
+        assert_in_x_months("ce mois", 0);
+        assert_in_x_months("le mois dernier", -1);
+        assert_in_x_months("le mois passé", -1);
+    }
+
+    #[test]
+    fn range_includes_the_suffix() {
+        let text = "Payer ce mois-ci.";
+        let recognized = parse_relative_month(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "ce mois-ci");
+    }
+
+    fn assert_in_x_months(text: &str, months: i32) {
+        assert_eq!(
+            parse_relative_month(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXMonths(months)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
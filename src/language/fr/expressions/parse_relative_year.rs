@@ -0,0 +1,162 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    fr::fr_date_parser::{
+        day_of_month_french, parse_month_of_year_french, relative_word_to_num,
+        string_to_num_french, IN_WORDS, MONTHS, NUMBER_WORDS, RELATIVE_WORDS,
+    },
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static RELATIVE_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?:l['’])?(?:ann[eé]e|an)\s(?P<prep>{relative})|cette\sann[eé]e|(?:{in_words})\s(?P<num>\d{{1,3}}|{numbers})\s(?:ans|an|ann[eé]es?))\b",
+        relative = RELATIVE_WORDS,
+        in_words = IN_WORDS,
+        numbers = NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+static MONTH_IN_RELATIVE_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:en\s)?(?:(?:le\s)?(?P<date>premier|1er|[12]\d|3[01]|0?[1-9]|{numbers})\s)?(?P<month>{months})\s(?:de\s)?(?:(?:l['’])?(?:ann[eé]e|an)\s(?P<prep>{relative})|cette\sann[eé]e)\b",
+        numbers = NUMBER_WORDS,
+        months = MONTHS,
+        relative = RELATIVE_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InXYears(i32)`
+pub fn parse_relative_year(text: &str) -> Option<RecognizedDateExpression> {
+    // l'année prochaine, l'an dernier, cette année, dans deux ans
+
+    let caps = RELATIVE_YEAR.captures(text)?;
+
+    let years = match (caps.name("prep"), caps.name("num")) {
+        (Some(prep), _) => relative_word_to_num(prep.as_str())?,
+        (None, Some(num)) => string_to_num_french(num.as_str())?,
+        (None, None) => 0,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXYears(years),
+        &caps,
+    ))
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::MonthInXYears(Month, i32)`
+/// or a `DateExpr::DayInMonthInXYears(Month, u32, i32)`.
+pub fn parse_month_in_relative_year(text: &str) -> Option<RecognizedDateExpression> {
+    // en décembre l'année prochaine, le 5 juin de l'an dernier, en mars de cette année
+
+    let caps = MONTH_IN_RELATIVE_YEAR.captures(text)?;
+
+    let month = parse_month_of_year_french(&caps["month"])?;
+    let years = match caps.name("prep") {
+        Some(prep) => relative_word_to_num(prep.as_str())?,
+        None => 0,
+    };
+
+    let expression = match caps.name("date") {
+        Some(date) => {
+            DateExpression::DayInMonthInXYears(month, day_of_month_french(date.as_str())?, years)
+        }
+        None => DateExpression::MonthInXYears(month, years),
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_relative_year_works_when {
+    use super::{parse_month_in_relative_year, parse_relative_year};
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn relative_years_are_parsed() {
+        assert_in_x_years("l'année prochaine", 1);
+        assert_in_x_years("l’annee prochaine", 1);
+        assert_in_x_years("l'an prochain", 1);
+        assert_in_x_years("cette année", 0);
+        assert_in_x_years("l'année dernière", -1);
+        assert_in_x_years("l'an passé", -1);
+        assert_in_x_years("dans deux ans", 2);
+        assert_in_x_years("dans 1 an", 1);
+        assert_in_x_years("d'ici trois années", 3);
+        assert_in_x_years("dans vingt et un ans", 21);
+    }
+
+    #[test]
+    fn months_in_relative_years_are_parsed() {
+        for (text, expected) in [
+            (
+                "en décembre l'année prochaine",
+                DateExpression::MonthInXYears(Month::December, 1),
+            ),
+            (
+                "en mars de l’an dernier",
+                DateExpression::MonthInXYears(Month::March, -1),
+            ),
+            (
+                "en juin de cette année",
+                DateExpression::MonthInXYears(Month::June, 0),
+            ),
+            (
+                "le 5 juin de l'année prochaine",
+                DateExpression::DayInMonthInXYears(Month::June, 5, 1),
+            ),
+            (
+                "le premier mai l'an prochain",
+                DateExpression::DayInMonthInXYears(Month::May, 1, 1),
+            ),
+        ] {
+            assert_eq!(
+                parse_month_in_relative_year(text).map(|recognized| recognized.expression),
+                Some(expected),
+                "Failed for input: {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn range_of_month_in_relative_year_includes_en() {
+        let text = "Vacances en décembre l'année prochaine";
+        let recognized = parse_month_in_relative_year(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "en décembre l'année prochaine");
+    }
+
+    #[test]
+    fn no_month_in_relative_year_is_found() {
+        assert_eq!(parse_month_in_relative_year("l'année prochaine"), None);
+        assert_eq!(parse_month_in_relative_year("en décembre"), None);
+    }
+
+    #[test]
+    fn range_includes_the_elided_article() {
+        let text = "Vacances l'année prochaine";
+        let recognized = parse_relative_year(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "l'année prochaine");
+    }
+
+    #[test]
+    fn no_relative_year_is_found() {
+        assert_eq!(parse_relative_year("dans des années"), None);
+        assert_eq!(parse_relative_year("annexe prochaine"), None);
+    }
+
+    fn assert_in_x_years(text: &str, years: i32) {
+        assert_eq!(
+            parse_relative_year(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXYears(years)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,477 @@
+use std::ops::Range;
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::{
+    date_match::{DateMatch, StrippedDateExpression},
+    date_parser::DateParser,
+    language::{
+        shared::{recognize_all_date_expressions, Month, RecognizedDateExpression},
+        shared_date_parser::{
+            parse_date_month_year, parse_iso_date, parse_month_date_year, parse_year_month_day,
+        },
+        shared_title::strip_date_phrase,
+    },
+    parse_error::ParseError,
+    DateFormat, StartDayOfWeek,
+};
+
+use super::expressions::{
+    parse_ago::parse_ago,
+    parse_date_in_week::parse_date_in_week,
+    parse_date_in_x_weeks::parse_in_x_weeks,
+    parse_day_alone::parse_day_alone,
+    parse_day_in_explicit_weeks::parse_day_in_explicit_week,
+    parse_day_in_relative_week::parse_day_in_relative_week,
+    parse_day_of_month::parse_day_of_month,
+    parse_in_n_months::parse_in_n_months,
+    parse_keywords::parse_keywords,
+    parse_month_date::parse_month_date,
+    parse_month_year::parse_month_year,
+    parse_relative_date::parse_relative_day,
+    parse_relative_keyword_week::parse_keyword_relative_week,
+    parse_relative_month::parse_relative_month,
+    parse_relative_year::{parse_month_in_relative_year, parse_relative_year},
+};
+
+/// The french weekdays for regular expressions.
+pub const WEEKDAYS: &str = "lundi|mardi|mercredi|jeudi|vendredi|samedi|dimanche";
+
+/// The forms of next and last for regular expressions, written with or without accents, e.g. `prochaine` or `derniere`.
+pub const RELATIVE_WORDS: &str = r"prochaine?|derni(?:er|[eè]re)|pass[eé]e?";
+
+/// The french number words up to a hundred for regular expressions, as in `dans quinze jours`,
+/// `vingt et un` or `quatre-vingt-dix`.
+pub const NUMBER_WORDS: &str = r"(?:vingt|trente|quarante|cinquante|soixante)(?:[-\s]et[-\s](?:une?|onze)|-(?:dix-sept|dix-huit|dix-neuf|deux|trois|quatre|cinq|six|sept|huit|neuf|dix|douze|treize|quatorze|quinze|seize))?|quatre-vingts?(?:-(?:dix-sept|dix-huit|dix-neuf|une?|deux|trois|quatre|cinq|six|sept|huit|neuf|dix|onze|douze|treize|quatorze|quinze|seize))?|cent|dix-sept|dix-huit|dix-neuf|une|un|deux|trois|quatre|cinq|six|sept|huit|neuf|dix|onze|douze|treize|quatorze|quinze|seize";
+
+/// The french months and their abbreviations for regular expressions.
+pub const MONTHS: &str = r"(?:janvier|f[eé]vrier|mars|avril|mai|juin|juillet|ao[uû]t|septembre|octobre|novembre|d[eé]cembre)\b|(?:janv|f[eé]vr?|avr|juil|sept|oct|nov|d[eé]c)\b\.?";
+
+/// The words which introduce a time span in the future, like `dans` or `d'ici`.
+pub const IN_WORDS: &str = r"dans|d['’]ici";
+
+/// Lowercases `text`, removes the accents and replaces typographic apostrophes,
+/// so `Après-demain` and `apres-demain` or `aujourd’hui` and `aujourd'hui` are looked up the same way.
+///
+/// Only the looked up words are normalized, the input stays untouched to keep the ranges valid.
+pub fn normalize_french(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'à' | 'â' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'î' | 'ï' => 'i',
+            'ô' | 'ö' => 'o',
+            'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            '’' => '\'',
+            c => c,
+        })
+        .collect()
+}
+
+/// Parses a french weekday like `lundi` or `Dimanche`.
+pub fn parse_weekday_french(text: &str) -> Option<Weekday> {
+    match text.to_lowercase().as_ref() {
+        "lundi" => Some(Weekday::Mon),
+        "mardi" => Some(Weekday::Tue),
+        "mercredi" => Some(Weekday::Wed),
+        "jeudi" => Some(Weekday::Thu),
+        "vendredi" => Some(Weekday::Fri),
+        "samedi" => Some(Weekday::Sat),
+        "dimanche" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Parses a french month name or its abbreviation like `décembre`, `decembre` or `déc.` into a `Month`.
+pub fn parse_month_of_year_french(text: &str) -> Option<Month> {
+    match normalize_french(text).trim_end_matches('.') {
+        "janvier" | "janv" => Some(Month::January),
+        "fevrier" | "fevr" | "fev" => Some(Month::February),
+        "mars" => Some(Month::March),
+        "avril" | "avr" => Some(Month::April),
+        "mai" => Some(Month::May),
+        "juin" => Some(Month::June),
+        "juillet" | "juil" => Some(Month::July),
+        "aout" => Some(Month::August),
+        "septembre" | "sept" => Some(Month::September),
+        "octobre" | "oct" => Some(Month::October),
+        "novembre" | "nov" => Some(Month::November),
+        "decembre" | "dec" => Some(Month::December),
+        _ => None,
+    }
+}
+
+/// Returns the relative week, month or year of a form of next or last.
+///
+/// e.g. `prochain` => 1, `prochaine` => 1, `dernière` => -1, `passé` => -1
+pub fn relative_word_to_num(text: &str) -> Option<i32> {
+    match normalize_french(text).as_ref() {
+        "prochain" | "prochaine" => Some(1),
+        "dernier" | "derniere" | "passe" | "passee" => Some(-1),
+        _ => None,
+    }
+}
+
+/// Parses a number written with digits or as french words like `trois`, `vingt et un` or `quatre-vingt-dix`.
+pub fn string_to_num_french(input: &str) -> Option<i32> {
+    if let Ok(num) = input.parse::<i32>() {
+        return Some(num);
+    }
+
+    let input = input.to_lowercase();
+    let mut words = input
+        .split(|c: char| c == '-' || c.is_whitespace())
+        .filter(|word| *word != "et")
+        .peekable();
+    let mut num = 0;
+
+    while let Some(word) = words.next() {
+        num += match word {
+            // quatre-vingts is four times twenty
+            "quatre" if matches!(words.peek(), Some(&"vingt" | &"vingts")) => {
+                words.next();
+                80
+            }
+            word => french_number_word(word)?,
+        };
+    }
+
+    Some(num)
+}
+
+fn french_number_word(word: &str) -> Option<i32> {
+    let num = match word {
+        "un" | "une" => 1,
+        "deux" => 2,
+        "trois" => 3,
+        "quatre" => 4,
+        "cinq" => 5,
+        "six" => 6,
+        "sept" => 7,
+        "huit" => 8,
+        "neuf" => 9,
+        "dix" => 10,
+        "onze" => 11,
+        "douze" => 12,
+        "treize" => 13,
+        "quatorze" => 14,
+        "quinze" => 15,
+        "seize" => 16,
+        "vingt" => 20,
+        "trente" => 30,
+        "quarante" => 40,
+        "cinquante" => 50,
+        "soixante" => 60,
+        "cent" => 100,
+        _ => return None,
+    };
+
+    Some(num)
+}
+
+/// Parses a day of a month like `5`, `1er`, `premier` or `deux`.
+pub fn day_of_month_french(input: &str) -> Option<u32> {
+    match input.to_lowercase().as_ref() {
+        "premier" | "1er" => Some(1),
+        day => string_to_num_french(day)
+            .filter(|day| (1..=31).contains(day))
+            .map(|day| day as u32),
+    }
+}
+
+/// Prepositions which are removed together with a date phrase, like `pour` in `payer le loyer pour lundi`.
+const PREPOSITIONS: [&str; 11] = [
+    "le",
+    "la",
+    "au",
+    "à",
+    "pour",
+    "avant",
+    "dès",
+    "jusqu'à",
+    "jusqu'au",
+    "jusqu’à",
+    "jusqu’au",
+];
+
+/// Phrases at the beginning of an input which are not part of a title.
+const LEADING_PHRASES: [&str; 6] = [
+    "rappelle-moi de",
+    "rappelle-moi",
+    "n'oublie pas de",
+    "n’oublie pas de",
+    "n'oublie pas",
+    "n’oublie pas",
+];
+
+pub struct FrDateParser {}
+
+impl FrDateParser {
+    /// Dates are written day first in french, e.g. 5/12/2026.
+    pub const DATE_FORMAT: DateFormat = DateFormat::DayMonthYear;
+
+    /// Weeks start on monday in France.
+    pub const START_DAY_OF_WEEK: StartDayOfWeek = StartDayOfWeek::Monday;
+}
+
+/// Recognizes the first french date expression in `input`.
+///
+/// The parsers are tried from the most specific to the least specific one.
+pub fn recognize_date_expression(
+    input: &str,
+    date_format: &DateFormat,
+) -> Option<RecognizedDateExpression> {
+    // aujourd'hui, demain, après-demain, hier, avant-hier
+    if let Some(date) = parse_keywords(input) {
+        return Some(date);
+    }
+
+    // il y a trois jours, il y a 2 semaines
+    if let Some(date) = parse_ago(input) {
+        return Some(date);
+    }
+
+    // lundi de la semaine prochaine, la semaine prochaine lundi
+    if let Some(date) = parse_day_in_relative_week(input) {
+        return Some(date);
+    }
+
+    // lundi dans deux semaines, dans 2 semaines lundi
+    if let Some(date) = parse_day_in_explicit_week(input) {
+        return Some(date);
+    }
+
+    // lundi prochain, vendredi dernier, ce samedi
+    if let Some(date) = parse_date_in_week(input) {
+        return Some(date);
+    }
+
+    // a single day of the week
+    if let Some(date) = parse_day_alone(input) {
+        return Some(date);
+    }
+
+    // la semaine prochaine, cette semaine, la semaine dernière
+    if let Some(date) = parse_keyword_relative_week(input) {
+        return Some(date);
+    }
+
+    // dans trois semaines, dans 3 semaines
+    if let Some(date) = parse_in_x_weeks(input) {
+        return Some(date);
+    }
+
+    // dans trois jours, d'ici 3 jours
+    if let Some(date) = parse_relative_day(input) {
+        return Some(date);
+    }
+
+    // ISO dates like 2026-12-05, 2026-W49-3 or 2026-339 regardless of the date format
+    if let Some(date) = parse_iso_date(input) {
+        return Some(date);
+    }
+
+    // full dates in DD.MM.YYYY, MM.DD.YYYY or YYYY.MM.DD formats
+    match date_format {
+        DateFormat::DayMonthYear => {
+            if let Some(date) = parse_date_month_year(input) {
+                return Some(date);
+            }
+        }
+        DateFormat::MonthDayYear => {
+            if let Some(date) = parse_month_date_year(input) {
+                return Some(date);
+            }
+        }
+        DateFormat::YearMonthDay => {
+            if let Some(date) = parse_year_month_day(input) {
+                return Some(date);
+            }
+        }
+    }
+
+    // en décembre l'année prochaine, le 5 juin de l'an dernier
+    if let Some(date) = parse_month_in_relative_year(input) {
+        return Some(date);
+    }
+
+    // le 5 décembre, le premier mai, le deux mai, 1er déc. 2026
+    if let Some(date) = parse_month_date(input) {
+        return Some(date);
+    }
+
+    // décembre 2027, en juin 2027
+    if let Some(date) = parse_month_year(input) {
+        return Some(date);
+    }
+
+    // le 22, le 1er
+    if let Some(date) = parse_day_of_month(input) {
+        return Some(date);
+    }
+
+    // dans deux mois
+    if let Some(date) = parse_in_n_months(input) {
+        return Some(date);
+    }
+
+    // le mois prochain, ce mois-ci, le mois dernier
+    if let Some(date) = parse_relative_month(input) {
+        return Some(date);
+    }
+
+    // l'année prochaine, cette année, dans deux ans
+    if let Some(date) = parse_relative_year(input) {
+        return Some(date);
+    }
+
+    None
+}
+
+/// Removes the date phrase in `range` from `text` together with the words around it which belong to the date.
+pub fn title_without_date_phrase(text: &str, range: Option<Range<usize>>) -> String {
+    match range {
+        Some(range) => strip_date_phrase(text, range, &PREPOSITIONS, &LEADING_PHRASES),
+        None => strip_date_phrase(text, text.len()..text.len(), &[], &LEADING_PHRASES),
+    }
+}
+
+/// The FrDateParser can be used to parse french date information out of strings.
+///
+/// ```
+/// use date_time_parser_multi_language::{DateParser, FrDateParser};
+/// use chrono::NaiveDate;
+//
+/// fn main() {
+///     let some_input = "Rappelle-moi le 5 décembre d'appeler le dentiste.";
+///
+///     let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+///
+///     let date_match = FrDateParser::search_date_expression(some_input, &now, &FrDateParser::DATE_FORMAT, &FrDateParser::START_DAY_OF_WEEK).unwrap();
+///
+///     assert_eq!(date_match.date, NaiveDate::from_ymd_opt(2026, 12, 5).unwrap());
+///     assert_eq!(date_match.text, "le 5 décembre");
+/// }
+/// ```
+impl DateParser for FrDateParser {
+    fn try_search_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Result<DateMatch, ParseError> {
+        let recognized =
+            recognize_date_expression(text, date_format).ok_or(ParseError::NoExpressionFound)?;
+
+        DateMatch::resolve(text, recognized, now, start_day_week)
+    }
+
+    fn search_all_date_expressions(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Vec<DateMatch> {
+        recognize_all_date_expressions(text, date_format, recognize_date_expression)
+            .into_iter()
+            .filter_map(|recognized| DateMatch::resolve(text, recognized, now, start_day_week).ok())
+            .collect()
+    }
+
+    fn strip_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> StrippedDateExpression {
+        let date_match = Self::search_date_expression(text, now, date_format, start_day_week);
+        let title = title_without_date_phrase(
+            text,
+            date_match
+                .as_ref()
+                .map(|date_match| date_match.range.clone()),
+        );
+
+        StrippedDateExpression { title, date_match }
+    }
+}
+
+#[cfg(test)]
+mod fr_date_parser_works_when {
+    use chrono::Weekday;
+
+    use super::{
+        day_of_month_french, normalize_french, parse_month_of_year_french, parse_weekday_french,
+        relative_word_to_num, string_to_num_french,
+    };
+    use crate::language::shared::Month;
+
+    #[test]
+    fn accents_and_apostrophes_are_normalized() {
+        assert_eq!(normalize_french("Après-demain"), "apres-demain");
+        assert_eq!(normalize_french("aujourd’hui"), "aujourd'hui");
+        assert_eq!(normalize_french("Février"), "fevrier");
+        assert_eq!(normalize_french("août"), "aout");
+    }
+
+    #[test]
+    fn weekdays_are_parsed() {
+        assert_eq!(parse_weekday_french("lundi"), Some(Weekday::Mon));
+        assert_eq!(parse_weekday_french("Dimanche"), Some(Weekday::Sun));
+        assert_eq!(parse_weekday_french("Monday"), None);
+    }
+
+    #[test]
+    fn months_are_parsed() {
+        assert_eq!(
+            parse_month_of_year_french("décembre"),
+            Some(Month::December)
+        );
+        assert_eq!(
+            parse_month_of_year_french("decembre"),
+            Some(Month::December)
+        );
+        assert_eq!(parse_month_of_year_french("Déc."), Some(Month::December));
+        assert_eq!(parse_month_of_year_french("février"), Some(Month::February));
+        assert_eq!(parse_month_of_year_french("aout"), Some(Month::August));
+        assert_eq!(parse_month_of_year_french("May"), None);
+    }
+
+    #[test]
+    fn relative_words_are_parsed() {
+        assert_eq!(relative_word_to_num("prochain"), Some(1));
+        assert_eq!(relative_word_to_num("Prochaine"), Some(1));
+        assert_eq!(relative_word_to_num("dernier"), Some(-1));
+        assert_eq!(relative_word_to_num("dernière"), Some(-1));
+        assert_eq!(relative_word_to_num("derniere"), Some(-1));
+        assert_eq!(relative_word_to_num("passée"), Some(-1));
+        assert_eq!(relative_word_to_num("demain"), None);
+    }
+
+    #[test]
+    fn number_words_are_parsed() {
+        assert_eq!(string_to_num_french("une"), Some(1));
+        assert_eq!(string_to_num_french("Trois"), Some(3));
+        assert_eq!(string_to_num_french("12"), Some(12));
+        assert_eq!(string_to_num_french("quinze"), Some(15));
+        assert_eq!(string_to_num_french("treize"), Some(13));
+        assert_eq!(string_to_num_french("vingt et un"), Some(21));
+        assert_eq!(string_to_num_french("trente-deux"), Some(32));
+        assert_eq!(string_to_num_french("soixante-et-onze"), Some(71));
+        assert_eq!(string_to_num_french("quatre-vingts"), Some(80));
+        assert_eq!(string_to_num_french("Quatre-vingt-dix-neuf"), Some(99));
+        assert_eq!(string_to_num_french("vingtaine"), None);
+    }
+
+    #[test]
+    fn days_of_month_are_parsed() {
+        assert_eq!(day_of_month_french("1er"), Some(1));
+        assert_eq!(day_of_month_french("Premier"), Some(1));
+        assert_eq!(day_of_month_french("deux"), Some(2));
+        assert_eq!(day_of_month_french("trente et un"), Some(31));
+        assert_eq!(day_of_month_french("32"), None);
+        assert_eq!(day_of_month_french("quarante"), None);
+    }
+}
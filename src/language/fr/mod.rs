@@ -0,0 +1,2 @@
+mod expressions;
+pub mod fr_date_parser;
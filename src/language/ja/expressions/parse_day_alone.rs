@@ -0,0 +1,54 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ja::ja_date_parser::{parse_weekday_japanese, WEEKDAYS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DAY_ALONE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?P<day>{})曜日?", WEEKDAYS)).unwrap());
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(0, Weekday)`
+pub fn parse_day_alone(text: &str) -> Option<RecognizedDateExpression> {
+    // 金曜日, 土曜
+
+    let caps = DAY_ALONE.captures(text)?;
+    let day = parse_weekday_japanese(&caps["day"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(0, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_alone_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_alone;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn weekdays_are_parsed() {
+        assert_day_alone("月曜日", Weekday::Mon);
+        assert_day_alone("土曜", Weekday::Sat);
+        assert_day_alone("日曜日に会う", Weekday::Sun);
+    }
+
+    #[test]
+    fn no_weekday_is_found() {
+        assert_eq!(parse_day_alone("月"), None);
+        assert_eq!(parse_day_alone("曜日"), None);
+    }
+
+    fn assert_day_alone(text: &str, day: Weekday) {
+        assert_eq!(
+            parse_day_alone(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(0, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ja::ja_date_parser::{
+        direction_to_sign, parse_weekday_japanese, relative_prefix_to_num, RELATIVE_PREFIXES,
+        WEEKDAYS,
+    },
+    shared::{DateExpression, RecognizedDateExpression},
+    shared_cjk::{parse_cjk_number, CJK_NUMBER},
+};
+
+static DAY_IN_RELATIVE_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?:(?P<prefix>{prefixes})週|(?P<num>{number})週間?(?P<direction>後|前))の?(?P<day>{weekdays})曜日?",
+        prefixes = RELATIVE_PREFIXES,
+        number = CJK_NUMBER,
+        weekdays = WEEKDAYS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_day_in_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    // 来週の月曜日, 先週金曜, 2週間後の水曜日
+
+    let caps = DAY_IN_RELATIVE_WEEK.captures(text)?;
+
+    let weeks = match caps.name("prefix") {
+        Some(prefix) => relative_prefix_to_num(prefix.as_str())?,
+        None => parse_cjk_number(&caps["num"])? * direction_to_sign(&caps["direction"])?,
+    };
+    let day = parse_weekday_japanese(&caps["day"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(weeks, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_in_relative_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_in_relative_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn days_in_relative_weeks_are_parsed() {
+        assert_day_in_x_weeks("来週の月曜日", 1, Weekday::Mon);
+        assert_day_in_x_weeks("来週月曜", 1, Weekday::Mon);
+        assert_day_in_x_weeks("今週の金曜日", 0, Weekday::Fri);
+        assert_day_in_x_weeks("先週の日曜日", -1, Weekday::Sun);
+        assert_day_in_x_weeks("再来週の水曜", 2, Weekday::Wed);
+        assert_day_in_x_weeks("先々週の火曜日", -2, Weekday::Tue);
+    }
+
+    #[test]
+    fn days_in_explicit_weeks_are_parsed() {
+        assert_day_in_x_weeks("2週間後の水曜日", 2, Weekday::Wed);
+        assert_day_in_x_weeks("三週間後の月曜", 3, Weekday::Mon);
+        assert_day_in_x_weeks("1週間前の金曜日", -1, Weekday::Fri);
+    }
+
+    #[test]
+    fn range_covers_the_expression() {
+        let text = "来週の月曜日に歯医者";
+        let recognized = parse_day_in_relative_week(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "来週の月曜日");
+    }
+
+    #[test]
+    fn no_day_is_found() {
+        assert_eq!(parse_day_in_relative_week("来週"), None);
+        assert_eq!(parse_day_in_relative_week("月曜日"), None);
+    }
+
+    fn assert_day_in_x_weeks(text: &str, weeks: i32, day: Weekday) {
+        assert_eq!(
+            parse_day_in_relative_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(weeks, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ja::ja_date_parser::direction_to_sign,
+    shared::{DateExpression, RecognizedDateExpression},
+    shared_cjk::{parse_cjk_number, CJK_NUMBER},
+};
+
+static IN_N_MONTHS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?P<num>{})[ヶケヵカか箇]月(?P<direction>後|前)",
+        CJK_NUMBER
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+pub fn parse_in_n_months(text: &str) -> Option<RecognizedDateExpression> {
+    // 2ヶ月後, 三か月後, 1カ月前
+
+    let caps = IN_N_MONTHS.captures(text)?;
+    let months = parse_cjk_number(&caps["num"])? * direction_to_sign(&caps["direction"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXMonths(months),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_in_n_months_works_when {
+    use super::parse_in_n_months;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn months_are_parsed() {
+        assert_in_n_months("2ヶ月後", 2);
+        assert_in_n_months("三か月後", 3);
+        assert_in_n_months("六カ月後に", 6);
+        assert_in_n_months("1ヵ月前", -1);
+    }
+
+    #[test]
+    fn no_months_are_found() {
+        assert_eq!(parse_in_n_months("3月後"), None);
+        assert_eq!(parse_in_n_months("2ヶ月"), None);
+    }
+
+    fn assert_in_n_months(text: &str, months: i32) {
+        assert_eq!(
+            parse_in_n_months(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXMonths(months)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::shared::{DateExpression, RecognizedDateExpression};
+
+static KEYWORDS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"明々後日|明明後日|明後日|明日|本日|今日|一昨日|昨日").unwrap());
+
+/// Parses common keywords into an `Option` containing a `DateExpr::InNDays(i32)`.
+pub fn parse_keywords(text: &str) -> Option<RecognizedDateExpression> {
+    // 今日, 明日, 明後日, 昨日, 一昨日
+
+    let keyword = KEYWORDS.find(text)?;
+
+    let n = match keyword.as_str() {
+        "今日" | "本日" => 0,
+        "明日" => 1,
+        "明後日" => 2,
+        "明々後日" | "明明後日" => 3,
+        "昨日" => -1,
+        "一昨日" => -2,
+        _ => return None,
+    };
+
+    Some(RecognizedDateExpression {
+        expression: DateExpression::InXDays(n),
+        range: keyword.range(),
+    })
+}
+
+#[cfg(test)]
+mod parse_keywords_works_when {
+    use crate::language::{
+        ja::expressions::parse_keywords::parse_keywords, shared::DateExpression,
+    };
+
+    #[test]
+    fn day_keywords() {
+        assert_in_n_days("今日", 0);
+        assert_in_n_days("本日", 0);
+        assert_in_n_days("明日", 1);
+        assert_in_n_days("明後日", 2);
+        assert_in_n_days("明々後日", 3);
+        assert_in_n_days("昨日", -1);
+        assert_in_n_days("一昨日", -2);
+    }
+
+    #[test]
+    fn keywords_are_found_without_spaces() {
+        assert_in_n_days("明日に電話する", 1);
+        assert_in_n_days("ボブは一昨日来た", -2);
+    }
+
+    #[test]
+    fn range_covers_the_keyword() {
+        let text = "牛乳を明後日買う";
+
+        assert_eq!(
+            parse_keywords(text).map(|recognized| recognized.range),
+            Some(9..18)
+        );
+    }
+
+    #[test]
+    fn no_keyword_is_found() {
+        assert_eq!(parse_keywords("日曜日"), None);
+        assert_eq!(parse_keywords("毎日"), None);
+    }
+
+    fn assert_in_n_days(text: &str, days: i32) {
+        assert_eq!(
+            parse_keywords(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(days)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
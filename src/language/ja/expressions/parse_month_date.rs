@@ -0,0 +1,112 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    shared::{date_in_written_year, num_to_month, DateExpression, RecognizedDateExpression},
+    shared_cjk::{parse_cjk_number, CJK_NUMBER},
+};
+
+static MONTH_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?:(?P<year>{number})年)?(?P<month>{number})月(?:(?P<day>{number})日)?",
+        number = CJK_NUMBER
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::DayInMonth(Month, u32)`, a
+/// `DateExpr::DayInMonthInYear(Month, u32, i32)` or a `DateExpr::InMonthInYear(Month, i32)`.
+///
+/// A month without a day or a year like `12月` isn't a date.
+pub fn parse_month_date(text: &str) -> Option<RecognizedDateExpression> {
+    // 12月5日, 2026年12月5日, 十二月五日, 2026年12月
+
+    MONTH_DATE.captures_iter(text).find_map(|caps| {
+        let month = num_to_month(parse_cjk_number(&caps["month"])?.try_into().ok()?)?;
+        let day = match caps.name("day") {
+            Some(day) => Some(parse_cjk_number(day.as_str())?.try_into().ok()?),
+            None => None,
+        };
+
+        let expression = match (caps.name("year"), day) {
+            (Some(year), day) => {
+                date_in_written_year(month, day, &parse_cjk_number(year.as_str())?.to_string())?
+            }
+            (None, Some(day)) => DateExpression::DayInMonth(month, day),
+            (None, None) => return None,
+        };
+
+        Some(RecognizedDateExpression::from_captures(expression, &caps))
+    })
+}
+
+#[cfg(test)]
+mod parse_month_date_japanese_works_when {
+    use super::parse_month_date;
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn day_and_month_are_parsed() {
+        assert_month_date("12月5日", DateExpression::DayInMonth(Month::December, 5));
+        assert_month_date("十二月五日", DateExpression::DayInMonth(Month::December, 5));
+        assert_month_date(
+            "１２月２４日に",
+            DateExpression::DayInMonth(Month::December, 24),
+        );
+        assert_month_date("5月1日", DateExpression::DayInMonth(Month::May, 1));
+    }
+
+    #[test]
+    fn day_month_and_year_are_parsed() {
+        assert_month_date(
+            "2026年12月5日",
+            DateExpression::DayInMonthInYear(Month::December, 5, 2026),
+        );
+        assert_month_date(
+            "二〇二六年十二月五日",
+            DateExpression::DayInMonthInYear(Month::December, 5, 2026),
+        );
+        assert_month_date(
+            "2026年12月",
+            DateExpression::InMonthInYear(Month::December, 2026),
+        );
+        assert_month_date(
+            "26年12月5日",
+            DateExpression::DayInMonthInTwoDigitYear(Month::December, 5, 26),
+        );
+    }
+
+    #[test]
+    fn a_month_alone_is_skipped() {
+        assert_month_date(
+            "12月の会議は12月5日",
+            DateExpression::DayInMonth(Month::December, 5),
+        );
+        assert_eq!(parse_month_date("12月"), None);
+    }
+
+    #[test]
+    fn range_covers_the_date() {
+        let text = "会議は2026年12月5日です";
+        let recognized = parse_month_date(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "2026年12月5日");
+    }
+
+    #[test]
+    fn no_date_is_found() {
+        assert_eq!(parse_month_date("13月5日"), None);
+        assert_eq!(parse_month_date("来月"), None);
+        assert_eq!(parse_month_date("月曜日"), None);
+    }
+
+    fn assert_month_date(text: &str, expected: DateExpression) {
+        assert_eq!(
+            parse_month_date(text).map(|recognized| recognized.expression),
+            Some(expected),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
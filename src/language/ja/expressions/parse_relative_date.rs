@@ -0,0 +1,55 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ja::ja_date_parser::direction_to_sign,
+    shared::{DateExpression, RecognizedDateExpression},
+    shared_cjk::{parse_cjk_number, CJK_NUMBER},
+};
+
+static RELATIVE_DAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(?P<num>{})日(?P<direction>後|前)", CJK_NUMBER)).unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNDays(i32)`
+pub fn parse_relative_day(text: &str) -> Option<RecognizedDateExpression> {
+    // 3日後, 三日後, 十日前
+
+    let caps = RELATIVE_DAY.captures(text)?;
+    let days = parse_cjk_number(&caps["num"])? * direction_to_sign(&caps["direction"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXDays(days),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_relative_day_works_when {
+    use super::parse_relative_day;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn days_are_parsed() {
+        assert_in_x_days("3日後", 3);
+        assert_in_x_days("三日後", 3);
+        assert_in_x_days("３日後に", 3);
+        assert_in_x_days("十日前", -10);
+    }
+
+    #[test]
+    fn no_days_are_found() {
+        assert_eq!(parse_relative_day("3日"), None);
+        assert_eq!(parse_relative_day("数日後"), None);
+    }
+
+    fn assert_in_x_days(text: &str, days: i32) {
+        assert_eq!(
+            parse_relative_day(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(days)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
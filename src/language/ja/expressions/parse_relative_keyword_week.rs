@@ -0,0 +1,55 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ja::ja_date_parser::{relative_prefix_to_num, RELATIVE_PREFIXES},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static KEYWORD_RELATIVE_WEEK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?P<prefix>{})週", RELATIVE_PREFIXES)).unwrap());
+
+/// Parses a `str` into an `Option` containing a `DateExpression::InXWeeks(i32)`
+pub fn parse_keyword_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    // 来週, 今週, 先週, 再来週
+
+    let caps = KEYWORD_RELATIVE_WEEK.captures(text)?;
+    let weeks = relative_prefix_to_num(&caps["prefix"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXWeeks(weeks),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_keyword_relative_week_works_when {
+    use super::parse_keyword_relative_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn relative_weeks_are_parsed() {
+        assert_in_x_weeks("来週", 1);
+        assert_in_x_weeks("翌週", 1);
+        assert_in_x_weeks("今週末", 0);
+        assert_in_x_weeks("先週", -1);
+        assert_in_x_weeks("再来週", 2);
+        assert_in_x_weeks("先々週", -2);
+    }
+
+    #[test]
+    fn no_relative_week_is_found() {
+        assert_eq!(parse_keyword_relative_week("毎週"), None);
+        assert_eq!(parse_keyword_relative_week("2週間後"), None);
+    }
+
+    fn assert_in_x_weeks(text: &str, weeks: i32) {
+        assert_eq!(
+            parse_keyword_relative_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXWeeks(weeks)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ja::ja_date_parser::{relative_prefix_to_num, RELATIVE_PREFIXES},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static RELATIVE_MONTH: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?P<prefix>{})月", RELATIVE_PREFIXES)).unwrap());
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+pub fn parse_relative_month(text: &str) -> Option<RecognizedDateExpression> {
+    // 来月, 今月, 先月, 再来月
+
+    let caps = RELATIVE_MONTH.captures(text)?;
+    let months = relative_prefix_to_num(&caps["prefix"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXMonths(months),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_relative_month_works_when {
+    use super::parse_relative_month;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn relative_months_are_parsed() {
+        assert_in_x_months("来月", 1);
+        assert_in_x_months("翌月", 1);
+        assert_in_x_months("今月中に", 0);
+        assert_in_x_months("先月", -1);
+        assert_in_x_months("再来月", 2);
+    }
+
+    #[test]
+    fn no_relative_month_is_found() {
+        assert_eq!(parse_relative_month("毎月"), None);
+        assert_eq!(parse_relative_month("12月"), None);
+    }
+
+    fn assert_in_x_months(text: &str, months: i32) {
+        assert_eq!(
+            parse_relative_month(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXMonths(months)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,146 @@
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+
+use crate::language::{
+    ja::ja_date_parser::{direction_to_sign, relative_prefix_to_num},
+    shared::{num_to_month, DateExpression, RecognizedDateExpression},
+    shared_cjk::{parse_cjk_number, CJK_NUMBER},
+};
+
+static RELATIVE_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?P<prefix>再来|来|翌|今|本|去|一昨|昨)年|(?P<num>{})年(?P<direction>後|前)",
+        CJK_NUMBER
+    ))
+    .unwrap()
+});
+
+static MONTH_IN_RELATIVE_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?:(?P<prefix>再来|来|翌|今|本|去|一昨|昨)年|(?P<num>{number})年(?P<direction>後|前))の?(?P<month>{number})月(?:(?P<day>{number})日)?",
+        number = CJK_NUMBER
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InXYears(i32)`
+pub fn parse_relative_year(text: &str) -> Option<RecognizedDateExpression> {
+    // 来年, 今年, 去年, 一昨年, 3年後
+
+    let caps = RELATIVE_YEAR.captures(text)?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXYears(years(&caps)?),
+        &caps,
+    ))
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::MonthInXYears(Month, i32)`
+/// or a `DateExpr::DayInMonthInXYears(Month, u32, i32)`.
+pub fn parse_month_in_relative_year(text: &str) -> Option<RecognizedDateExpression> {
+    // 来年12月5日, 来年の12月, 去年の5月1日, 3年後の4月
+
+    MONTH_IN_RELATIVE_YEAR.captures_iter(text).find_map(|caps| {
+        let month = num_to_month(parse_cjk_number(&caps["month"])?.try_into().ok()?)?;
+        let years = years(&caps)?;
+
+        let expression = match caps.name("day") {
+            Some(day) => DateExpression::DayInMonthInXYears(
+                month,
+                parse_cjk_number(day.as_str())?.try_into().ok()?,
+                years,
+            ),
+            None => DateExpression::MonthInXYears(month, years),
+        };
+
+        Some(RecognizedDateExpression::from_captures(expression, &caps))
+    })
+}
+
+/// Returns the years of a relative prefix like `来` or of a time span like `3年後`.
+fn years(caps: &Captures) -> Option<i32> {
+    match caps.name("prefix") {
+        Some(prefix) => relative_prefix_to_num(prefix.as_str()),
+        None => parse_cjk_number(&caps["num"])?.checked_mul(direction_to_sign(&caps["direction"])?),
+    }
+}
+
+#[cfg(test)]
+mod parse_relative_year_works_when {
+    use super::{parse_month_in_relative_year, parse_relative_year};
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn relative_years_are_parsed() {
+        assert_in_x_years("来年", 1);
+        assert_in_x_years("今年", 0);
+        assert_in_x_years("去年", -1);
+        assert_in_x_years("昨年", -1);
+        assert_in_x_years("一昨年", -2);
+        assert_in_x_years("再来年", 2);
+        assert_in_x_years("3年後", 3);
+        assert_in_x_years("二年前", -2);
+    }
+
+    #[test]
+    fn no_relative_year_is_found() {
+        assert_eq!(parse_relative_year("毎年"), None);
+        assert_eq!(parse_relative_year("2026年"), None);
+    }
+
+    #[test]
+    fn months_in_relative_years_are_parsed() {
+        for (text, expected) in [
+            (
+                "来年12月5日",
+                DateExpression::DayInMonthInXYears(Month::December, 5, 1),
+            ),
+            (
+                "来年の12月",
+                DateExpression::MonthInXYears(Month::December, 1),
+            ),
+            (
+                "去年の五月一日",
+                DateExpression::DayInMonthInXYears(Month::May, 1, -1),
+            ),
+            ("今年の４月", DateExpression::MonthInXYears(Month::April, 0)),
+            ("3年後の4月", DateExpression::MonthInXYears(Month::April, 3)),
+            (
+                "再来年の1月10日",
+                DateExpression::DayInMonthInXYears(Month::January, 10, 2),
+            ),
+        ] {
+            assert_eq!(
+                parse_month_in_relative_year(text).map(|recognized| recognized.expression),
+                Some(expected),
+                "Failed for input: {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn range_of_month_in_relative_year_covers_the_year() {
+        let text = "旅行は来年の12月です";
+        let recognized = parse_month_in_relative_year(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "来年の12月");
+    }
+
+    #[test]
+    fn no_month_in_relative_year_is_found() {
+        assert_eq!(parse_month_in_relative_year("来年"), None);
+        assert_eq!(parse_month_in_relative_year("12月5日"), None);
+        assert_eq!(parse_month_in_relative_year("来年13月"), None);
+    }
+
+    fn assert_in_x_years(text: &str, years: i32) {
+        assert_eq!(
+            parse_relative_year(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXYears(years)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,276 @@
+use std::ops::Range;
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::{
+    date_match::{DateMatch, StrippedDateExpression},
+    date_parser::DateParser,
+    language::{
+        shared::{recognize_all_date_expressions, RecognizedDateExpression},
+        shared_date_parser::{
+            parse_date_month_year, parse_iso_date, parse_month_date_year, parse_year_month_day,
+        },
+        shared_title::strip_date_phrase_unspaced,
+    },
+    parse_error::ParseError,
+    DateFormat, StartDayOfWeek,
+};
+
+use super::expressions::{
+    parse_date_in_x_weeks::parse_in_x_weeks,
+    parse_day_alone::parse_day_alone,
+    parse_day_in_relative_week::parse_day_in_relative_week,
+    parse_in_n_months::parse_in_n_months,
+    parse_keywords::parse_keywords,
+    parse_month_date::parse_month_date,
+    parse_relative_date::parse_relative_day,
+    parse_relative_keyword_week::parse_keyword_relative_week,
+    parse_relative_month::parse_relative_month,
+    parse_relative_year::{parse_month_in_relative_year, parse_relative_year},
+};
+
+/// The first characters of the japanese weekdays for regular expressions, like `月` in `月曜日`.
+pub const WEEKDAYS: &str = "[月火水木金土日]";
+
+/// The prefixes of a relative week or month, like `来` in `来週` or `先` in `先月`.
+pub const RELATIVE_PREFIXES: &str = "再来|来|翌|今|先々|先先|先";
+
+/// Parses the first character of a japanese weekday like `月` or `金`.
+pub fn parse_weekday_japanese(text: &str) -> Option<Weekday> {
+    match text {
+        "月" => Some(Weekday::Mon),
+        "火" => Some(Weekday::Tue),
+        "水" => Some(Weekday::Wed),
+        "木" => Some(Weekday::Thu),
+        "金" => Some(Weekday::Fri),
+        "土" => Some(Weekday::Sat),
+        "日" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Returns the relative week, month or year of a prefix.
+///
+/// e.g. `来` => 1, `再来` => 2, `今` => 0, `先` => -1, `一昨` => -2
+pub fn relative_prefix_to_num(text: &str) -> Option<i32> {
+    match text {
+        "再来" => Some(2),
+        "来" | "翌" => Some(1),
+        "今" | "本" => Some(0),
+        "先" | "去" | "昨" => Some(-1),
+        "先々" | "先先" | "一昨" => Some(-2),
+        _ => None,
+    }
+}
+
+/// Returns the sign of a time span, `後` is later and `前` is earlier.
+pub fn direction_to_sign(text: &str) -> Option<i32> {
+    match text {
+        "後" => Some(1),
+        "前" => Some(-1),
+        _ => None,
+    }
+}
+
+/// Particles behind a date phrase which are removed together with it, like `に` in `明日に電話する`.
+const PARTICLES: [&str; 6] = ["までに", "まで", "から", "には", "に", "の"];
+
+pub struct JaDateParser {}
+
+impl JaDateParser {
+    /// Dates are written year first in japanese, e.g. 2026/12/05.
+    pub const DATE_FORMAT: DateFormat = DateFormat::YearMonthDay;
+
+    /// Weeks start on monday, like in ISO 8601 and its japanese counterpart JIS X 0301.
+    pub const START_DAY_OF_WEEK: StartDayOfWeek = StartDayOfWeek::Monday;
+}
+
+/// Recognizes the first japanese date expression in `input`.
+///
+/// Japanese doesn't separate words by spaces, so the expressions are matched without word boundaries.
+/// The parsers are tried from the most specific to the least specific one.
+pub fn recognize_date_expression(
+    input: &str,
+    date_format: &DateFormat,
+) -> Option<RecognizedDateExpression> {
+    // 今日, 明日, 明後日, 昨日, 一昨日
+    if let Some(date) = parse_keywords(input) {
+        return Some(date);
+    }
+
+    // ISO dates like 2026-12-05, 2026-W49-3 or 2026-339 regardless of the date format
+    if let Some(date) = parse_iso_date(input) {
+        return Some(date);
+    }
+
+    // full dates in DD.MM.YYYY, MM.DD.YYYY or YYYY.MM.DD formats
+    match date_format {
+        DateFormat::DayMonthYear => {
+            if let Some(date) = parse_date_month_year(input) {
+                return Some(date);
+            }
+        }
+        DateFormat::MonthDayYear => {
+            if let Some(date) = parse_month_date_year(input) {
+                return Some(date);
+            }
+        }
+        DateFormat::YearMonthDay => {
+            if let Some(date) = parse_year_month_day(input) {
+                return Some(date);
+            }
+        }
+    }
+
+    // 来年12月5日, 来年の12月, before the year is dropped from the month and the day
+    if let Some(date) = parse_month_in_relative_year(input) {
+        return Some(date);
+    }
+
+    // 12月5日, 2026年12月5日, 十二月五日, before `5日前` is mistaken for five days ago
+    if let Some(date) = parse_month_date(input) {
+        return Some(date);
+    }
+
+    // 来週の月曜日, 2週間後の金曜日
+    if let Some(date) = parse_day_in_relative_week(input) {
+        return Some(date);
+    }
+
+    // a single day of the week
+    if let Some(date) = parse_day_alone(input) {
+        return Some(date);
+    }
+
+    // 来週, 今週, 先週
+    if let Some(date) = parse_keyword_relative_week(input) {
+        return Some(date);
+    }
+
+    // 2週間後, 三週間前
+    if let Some(date) = parse_in_x_weeks(input) {
+        return Some(date);
+    }
+
+    // 3日後, 三日前
+    if let Some(date) = parse_relative_day(input) {
+        return Some(date);
+    }
+
+    // 2ヶ月後, 三か月前
+    if let Some(date) = parse_in_n_months(input) {
+        return Some(date);
+    }
+
+    // 来月, 今月, 先月
+    if let Some(date) = parse_relative_month(input) {
+        return Some(date);
+    }
+
+    // 来年, 今年, 去年, 3年後
+    if let Some(date) = parse_relative_year(input) {
+        return Some(date);
+    }
+
+    None
+}
+
+/// Removes the date phrase in `range` from `text` together with the words around it which belong to the date.
+pub fn title_without_date_phrase(text: &str, range: Option<Range<usize>>) -> String {
+    match range {
+        Some(range) => strip_date_phrase_unspaced(text, range, &[], &PARTICLES),
+        None => strip_date_phrase_unspaced(text, text.len()..text.len(), &[], &[]),
+    }
+}
+
+/// The JaDateParser can be used to parse japanese date information out of strings.
+///
+/// ```
+/// use date_time_parser_multi_language::{DateParser, JaDateParser};
+/// use chrono::NaiveDate;
+//
+/// fn main() {
+///     let some_input = "来週の月曜日に歯医者に行く";
+///
+///     let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+///
+///     let date_match = JaDateParser::search_date_expression(some_input, &now, &JaDateParser::DATE_FORMAT, &JaDateParser::START_DAY_OF_WEEK).unwrap();
+///
+///     assert_eq!(date_match.date, NaiveDate::from_ymd_opt(2026, 10, 19).unwrap());
+///     assert_eq!(date_match.text, "来週の月曜日");
+/// }
+/// ```
+impl DateParser for JaDateParser {
+    fn try_search_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Result<DateMatch, ParseError> {
+        let recognized =
+            recognize_date_expression(text, date_format).ok_or(ParseError::NoExpressionFound)?;
+
+        DateMatch::resolve(text, recognized, now, start_day_week)
+    }
+
+    fn search_all_date_expressions(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Vec<DateMatch> {
+        recognize_all_date_expressions(text, date_format, recognize_date_expression)
+            .into_iter()
+            .filter_map(|recognized| DateMatch::resolve(text, recognized, now, start_day_week).ok())
+            .collect()
+    }
+
+    fn strip_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> StrippedDateExpression {
+        let date_match = Self::search_date_expression(text, now, date_format, start_day_week);
+        let title = title_without_date_phrase(
+            text,
+            date_match
+                .as_ref()
+                .map(|date_match| date_match.range.clone()),
+        );
+
+        StrippedDateExpression { title, date_match }
+    }
+}
+
+#[cfg(test)]
+mod ja_date_parser_works_when {
+    use chrono::Weekday;
+
+    use super::{direction_to_sign, parse_weekday_japanese, relative_prefix_to_num};
+
+    #[test]
+    fn weekdays_are_parsed() {
+        assert_eq!(parse_weekday_japanese("月"), Some(Weekday::Mon));
+        assert_eq!(parse_weekday_japanese("金"), Some(Weekday::Fri));
+        assert_eq!(parse_weekday_japanese("日"), Some(Weekday::Sun));
+        assert_eq!(parse_weekday_japanese("曜"), None);
+    }
+
+    #[test]
+    fn relative_prefixes_are_parsed() {
+        assert_eq!(relative_prefix_to_num("来"), Some(1));
+        assert_eq!(relative_prefix_to_num("再来"), Some(2));
+        assert_eq!(relative_prefix_to_num("今"), Some(0));
+        assert_eq!(relative_prefix_to_num("先"), Some(-1));
+        assert_eq!(relative_prefix_to_num("先々"), Some(-2));
+        assert_eq!(relative_prefix_to_num("次"), None);
+    }
+
+    #[test]
+    fn directions_are_parsed() {
+        assert_eq!(direction_to_sign("後"), Some(1));
+        assert_eq!(direction_to_sign("前"), Some(-1));
+        assert_eq!(direction_to_sign("中"), None);
+    }
+}
@@ -0,0 +1,2 @@
+mod expressions;
+pub mod ja_date_parser;
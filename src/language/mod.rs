@@ -1,5 +1,14 @@
 pub mod date_format;
+pub mod de;
 pub mod en;
+pub mod es;
+pub mod fr;
+pub mod ja;
+pub mod ru;
 pub mod shared;
+pub mod shared_cjk;
 pub mod shared_date_parser;
+pub mod shared_title;
 pub mod start_day_of_week;
+pub mod supported_language;
+pub mod zh;
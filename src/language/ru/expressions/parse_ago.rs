@@ -0,0 +1,77 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{normalize_russian, string_to_num_russian, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static AGO: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?P<num>\d{{1,3}}|{})\s)?(?P<unit>д(?:ень|ня|ней)|недел(?:ю|и|ь)|месяц(?:а|ев)?|год(?:а)?|лет)\sназад\b",
+        NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InXDays(i32)`, `DateExpr::InXMonths(i32)`
+/// or `DateExpr::InXYears(i32)` with a negative offset.
+///
+/// Without a number the unit counts once, like in `неделю назад`.
+pub fn parse_ago(text: &str) -> Option<RecognizedDateExpression> {
+    // три дня назад, 5 дней назад, неделю назад, два месяца назад, год назад
+
+    let caps = AGO.captures(text)?;
+
+    let num = match caps.name("num") {
+        Some(num) => string_to_num_russian(num.as_str())?,
+        None => 1,
+    };
+
+    let expression = match normalize_russian(&caps["unit"]).as_ref() {
+        "день" | "дня" | "дней" => DateExpression::InXDays(-num),
+        "неделю" | "недели" | "недель" => {
+            DateExpression::InXDays(num.checked_mul(-7)?)
+        }
+        "месяц" | "месяца" | "месяцев" => DateExpression::InXMonths(-num),
+        "год" | "года" | "лет" => DateExpression::InXYears(-num),
+        _ => return None,
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_ago_works_when {
+    use super::parse_ago;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn past_expressions_are_parsed() {
+        assert_ago("три дня назад", DateExpression::InXDays(-3));
+        assert_ago("5 дней назад", DateExpression::InXDays(-5));
+        assert_ago("день назад", DateExpression::InXDays(-1));
+        assert_ago("неделю назад", DateExpression::InXDays(-7));
+        assert_ago("две недели назад", DateExpression::InXDays(-14));
+        assert_ago("месяц назад", DateExpression::InXMonths(-1));
+        assert_ago("пять месяцев назад", DateExpression::InXMonths(-5));
+        assert_ago("два года назад", DateExpression::InXYears(-2));
+        assert_ago("10 лет назад", DateExpression::InXYears(-10));
+    }
+
+    #[test]
+    fn no_past_expression_is_found() {
+        assert_eq!(parse_ago("три дня"), None);
+        assert_eq!(parse_ago("через три дня"), None);
+    }
+
+    fn assert_ago(text: &str, expected: DateExpression) {
+        assert_eq!(
+            parse_ago(text).map(|recognized| recognized.expression),
+            Some(expected),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,67 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{parse_weekday_russian, relative_word_to_num, RELATIVE_WORDS, WEEKDAYS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DATE_IN_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:во?\s)?(?P<prep>{relative})\s(?P<day>{weekdays})\b",
+        relative = RELATIVE_WORDS,
+        weekdays = WEEKDAYS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_date_in_week(text: &str) -> Option<RecognizedDateExpression> {
+    // в следующий понедельник, в прошлую пятницу, в эту среду
+
+    let caps = DATE_IN_WEEK.captures(text)?;
+
+    let relative_week = relative_word_to_num(&caps["prep"])?;
+    let day = parse_weekday_russian(&caps["day"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(relative_week, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_date_in_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_date_in_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn days_in_relative_weeks_are_parsed() {
+        assert_date_in_week("в следующий понедельник", 1, Weekday::Mon);
+        assert_date_in_week("в следующую среду", 1, Weekday::Wed);
+        assert_date_in_week("в прошлую пятницу", -1, Weekday::Fri);
+        assert_date_in_week("в прошлое воскресенье", -1, Weekday::Sun);
+        assert_date_in_week("в эту субботу", 0, Weekday::Sat);
+        assert_date_in_week("в этот вторник", 0, Weekday::Tue);
+    }
+
+    #[test]
+    fn range_includes_the_preposition() {
+        let text = "Позвонить в следующий понедельник.";
+        let recognized = parse_date_in_week(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "в следующий понедельник");
+    }
+
+    fn assert_date_in_week(text: &str, weeks: i32, day: Weekday) {
+        assert_eq!(
+            parse_date_in_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(weeks, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
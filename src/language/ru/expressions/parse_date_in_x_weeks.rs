@@ -0,0 +1,63 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{string_to_num_russian, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static IN_X_WEEKS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\bчерез\s(?:(?P<num>\d{{1,3}}|{})\s)?недел(?:ю|и|ь)\b",
+        NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::InXWeeks(i32)`
+///
+/// Without a number the week counts once, like in `через неделю`.
+pub fn parse_in_x_weeks(text: &str) -> Option<RecognizedDateExpression> {
+    // через неделю, через две недели, через 5 недель
+
+    let caps = IN_X_WEEKS.captures(text)?;
+    let num = match caps.name("num") {
+        Some(num) => string_to_num_russian(num.as_str())?,
+        None => 1,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXWeeks(num),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_in_x_weeks_works_when {
+    use super::parse_in_x_weeks;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn weeks_are_parsed() {
+        assert_in_x_weeks("через неделю", 1);
+        assert_in_x_weeks("через две недели", 2);
+        assert_in_x_weeks("Через 3 недели", 3);
+        assert_in_x_weeks("через пять недель", 5);
+    }
+
+    #[test]
+    fn no_weeks_are_found() {
+        assert_eq!(parse_in_x_weeks("через несколько недель"), None);
+        assert_eq!(parse_in_x_weeks("две недели"), None);
+    }
+
+    fn assert_in_x_weeks(text: &str, weeks: i32) {
+        assert_eq!(
+            parse_in_x_weeks(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXWeeks(weeks)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
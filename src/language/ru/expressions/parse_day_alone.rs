@@ -0,0 +1,56 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{parse_weekday_russian, WEEKDAYS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DAY_ALONE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?i)\b(?:во?\s)?(?P<day>{})\b", WEEKDAYS)).unwrap());
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(0, Weekday)`
+pub fn parse_day_alone(text: &str) -> Option<RecognizedDateExpression> {
+    // в пятницу, во вторник, суббота
+
+    let caps = DAY_ALONE.captures(text)?;
+    let day = parse_weekday_russian(&caps["day"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(0, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_alone_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_alone;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn weekdays_are_parsed() {
+        assert_day_alone("понедельник", Weekday::Mon);
+        assert_day_alone("во вторник", Weekday::Tue);
+        assert_day_alone("в среду", Weekday::Wed);
+        assert_day_alone("до пятницы", Weekday::Fri);
+        assert_day_alone("В воскресенье", Weekday::Sun);
+    }
+
+    #[test]
+    fn no_weekday_is_found() {
+        assert_eq!(parse_day_alone("середина"), None);
+        assert_eq!(parse_day_alone("пятничный"), None);
+    }
+
+    fn assert_day_alone(text: &str, day: Weekday) {
+        assert_eq!(
+            parse_day_alone(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(0, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{parse_weekday_russian, relative_word_to_num, RELATIVE_WORDS, WEEKDAYS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static DAY_IN_RELATIVE_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:во?\s)?(?P<day>{weekdays})\s(?:на\s)?(?P<prep>{relative})\sнедел[еи]\b",
+        weekdays = WEEKDAYS,
+        relative = RELATIVE_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_day_in_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    // в понедельник на следующей неделе, в пятницу на прошлой неделе
+
+    let caps = DAY_IN_RELATIVE_WEEK.captures(text)?;
+
+    let weeks = relative_word_to_num(&caps["prep"])?;
+    let day = parse_weekday_russian(&caps["day"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(weeks, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_in_relative_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_in_relative_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn days_in_relative_weeks_are_parsed() {
+        assert_day_in_x_weeks("в понедельник на следующей неделе", 1, Weekday::Mon);
+        assert_day_in_x_weeks("во вторник на этой неделе", 0, Weekday::Tue);
+        assert_day_in_x_weeks("в пятницу на прошлой неделе", -1, Weekday::Fri);
+        assert_day_in_x_weeks("среду следующей недели", 1, Weekday::Wed);
+    }
+
+    #[test]
+    fn range_includes_the_preposition() {
+        let text = "Встреча в среду на следующей неделе.";
+        let recognized = parse_day_in_relative_week(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "в среду на следующей неделе");
+    }
+
+    #[test]
+    fn no_day_is_found() {
+        assert_eq!(parse_day_in_relative_week("на следующей неделе"), None);
+        assert_eq!(parse_day_in_relative_week("в понедельник"), None);
+    }
+
+    fn assert_day_in_x_weeks(text: &str, weeks: i32, day: Weekday) {
+        assert_eq!(
+            parse_day_in_relative_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(weeks, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
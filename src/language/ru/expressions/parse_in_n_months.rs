@@ -0,0 +1,63 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{string_to_num_russian, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static IN_N_MONTHS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\bчерез\s(?:(?P<num>\d{{1,3}}|{})\s)?месяц(?:а|ев)?\b",
+        NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+///
+/// Without a number the month counts once, like in `через месяц`.
+pub fn parse_in_n_months(text: &str) -> Option<RecognizedDateExpression> {
+    // через месяц, через два месяца, через 6 месяцев
+
+    let caps = IN_N_MONTHS.captures(text)?;
+    let num = match caps.name("num") {
+        Some(num) => string_to_num_russian(num.as_str())?,
+        None => 1,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXMonths(num),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_in_n_months_works_when {
+    use super::parse_in_n_months;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn months_are_parsed() {
+        assert_in_n_months("через месяц", 1);
+        assert_in_n_months("через два месяца", 2);
+        assert_in_n_months("через 6 месяцев", 6);
+        assert_in_n_months("Через шесть месяцев", 6);
+    }
+
+    #[test]
+    fn no_months_are_found() {
+        assert_eq!(parse_in_n_months("через несколько месяцев"), None);
+        assert_eq!(parse_in_n_months("два месяца"), None);
+    }
+
+    fn assert_in_n_months(text: &str, months: i32) {
+        assert_eq!(
+            parse_in_n_months(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXMonths(months)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::normalize_russian,
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static KEYWORDS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?P<key>сегодня|послезавтра|завтра|позавчера|вчера)\b").unwrap()
+});
+
+/// Parses common keywords into an `Option` containing a `DateExpr::InNDays(i32)`.
+pub fn parse_keywords(text: &str) -> Option<RecognizedDateExpression> {
+    // сегодня, завтра, послезавтра, вчера, позавчера
+
+    let caps = KEYWORDS.captures(text)?;
+
+    let n = match normalize_russian(&caps["key"]).as_ref() {
+        "сегодня" => 0,
+        "завтра" => 1,
+        "послезавтра" => 2,
+        "вчера" => -1,
+        "позавчера" => -2,
+        _ => return None,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXDays(n),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_keywords_works_when {
+    use crate::language::{
+        ru::expressions::parse_keywords::parse_keywords, shared::DateExpression,
+    };
+
+    #[test]
+    fn day_keywords() {
+        assert_in_n_days("сегодня", 0);
+        assert_in_n_days("Завтра", 1);
+        assert_in_n_days("послезавтра", 2);
+        assert_in_n_days("вчера", -1);
+        assert_in_n_days("позавчера", -2);
+    }
+
+    #[test]
+    fn keywords_are_found_in_sentences() {
+        assert_in_n_days("Позвонить Бобу завтра", 1);
+        assert_in_n_days("Боб звонил позавчера.", -2);
+    }
+
+    #[test]
+    fn no_keyword_is_found() {
+        assert_eq!(parse_keywords("завтрак"), None);
+        assert_eq!(parse_keywords("вчерашний"), None);
+    }
+
+    fn assert_in_n_days(text: &str, days: i32) {
+        assert_eq!(
+            parse_keywords(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(days)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,62 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{parse_month_of_year_russian, MONTHS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static MONTH_ALONE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?i)\bв\s(?P<month>{})\b", MONTHS)).unwrap());
+
+/// Parses a month without a day or a year like `в декабре` into an `Option` containing a
+/// `DateExpr::NextMonthOfYear(Month)`.
+pub fn parse_month_alone(text: &str) -> Option<RecognizedDateExpression> {
+    // в декабре, в мае, в марте
+
+    let caps = MONTH_ALONE.captures(text)?;
+
+    let month = parse_month_of_year_russian(&caps["month"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::NextMonthOfYear(month),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_month_alone_works_when {
+    use super::parse_month_alone;
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn months_are_parsed() {
+        assert_month_alone("в декабре", Month::December);
+        assert_month_alone("Отпуск в мае", Month::May);
+        assert_month_alone("В марте", Month::March);
+    }
+
+    #[test]
+    fn range_includes_the_preposition() {
+        let text = "Отпуск в декабре.";
+        let recognized = parse_month_alone(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "в декабре");
+    }
+
+    #[test]
+    fn no_month_is_found() {
+        assert_eq!(parse_month_alone("декабрь"), None);
+        assert_eq!(parse_month_alone("в майонезе"), None);
+    }
+
+    fn assert_month_alone(text: &str, expected: Month) {
+        assert_eq!(
+            parse_month_alone(text).map(|recognized| recognized.expression),
+            Some(DateExpression::NextMonthOfYear(expected)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{
+        day_of_month_russian, parse_month_of_year_russian, DAYS, MONTHS, MONTH_ABBREVIATIONS,
+    },
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static MONTH_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?P<date>{days})(?:-?го)?\s(?P<month>(?:{months})\b|(?:{abbreviations})\b\.?)(?:\s(?P<year>\d{{4}})\b(?:\s?(?:года\b|г\.))?)?",
+        days = DAYS,
+        months = MONTHS,
+        abbreviations = MONTH_ABBREVIATIONS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::DayInMonth(Month, u32)` or a
+/// `DateExpr::DayInMonthInYear(Month, u32, i32)`.
+pub fn parse_month_date(text: &str) -> Option<RecognizedDateExpression> {
+    // 5 декабря, 5-го декабря, первого мая, 1 янв. 2026, 1 января 2026 года
+
+    let caps = MONTH_DATE.captures(text)?;
+
+    let date = day_of_month_russian(&caps["date"])?;
+    let month = parse_month_of_year_russian(&caps["month"])?;
+
+    let expression = match caps.name("year") {
+        Some(year) => DateExpression::DayInMonthInYear(month, date, year.as_str().parse().ok()?),
+        None => DateExpression::DayInMonth(month, date),
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_month_date_russian_works_when {
+    use super::parse_month_date;
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn day_and_month_are_parsed() {
+        assert_month_date("5 декабря", DateExpression::DayInMonth(Month::December, 5));
+        assert_month_date(
+            "5-го декабря",
+            DateExpression::DayInMonth(Month::December, 5),
+        );
+        assert_month_date("24 дек.", DateExpression::DayInMonth(Month::December, 24));
+        assert_month_date("1 мая", DateExpression::DayInMonth(Month::May, 1));
+        assert_month_date("8 марта", DateExpression::DayInMonth(Month::March, 8));
+        assert_month_date("31 Августа", DateExpression::DayInMonth(Month::August, 31));
+    }
+
+    #[test]
+    fn genitive_ordinals_are_parsed() {
+        assert_month_date("первого мая", DateExpression::DayInMonth(Month::May, 1));
+        assert_month_date(
+            "Третьего марта",
+            DateExpression::DayInMonth(Month::March, 3),
+        );
+        assert_month_date(
+            "двадцать пятого декабря",
+            DateExpression::DayInMonth(Month::December, 25),
+        );
+        assert_month_date(
+            "тридцать первого декабря 2026 года",
+            DateExpression::DayInMonthInYear(Month::December, 31, 2026),
+        );
+    }
+
+    #[test]
+    fn day_month_and_year_are_parsed() {
+        assert_month_date(
+            "1 января 2026",
+            DateExpression::DayInMonthInYear(Month::January, 1, 2026),
+        );
+        assert_month_date(
+            "1 января 2026 года",
+            DateExpression::DayInMonthInYear(Month::January, 1, 2026),
+        );
+    }
+
+    #[test]
+    fn range_includes_the_year() {
+        let text = "Праздник 1 января 2026 г. дома";
+        let recognized = parse_month_date(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "1 января 2026 г.");
+    }
+
+    #[test]
+    fn no_date_is_found() {
+        assert_eq!(parse_month_date("5 майонеза"), None);
+        assert_eq!(parse_month_date("декабрь"), None);
+    }
+
+    fn assert_month_date(text: &str, expected: DateExpression) {
+        assert_eq!(
+            parse_month_date(text).map(|recognized| recognized.expression),
+            Some(expected),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{parse_month_of_year_russian, MONTHS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static MONTH_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:в\s)?(?P<month>{})\s(?P<year>\d{{4}})\b(?:\s?(?:года\b|г\.))?",
+        MONTHS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InMonthInYear(Month, i32)`.
+///
+/// The month may be in the nominative like `декабрь 2026`, or in the prepositional case like `в декабре 2026 года`.
+pub fn parse_month_year(text: &str) -> Option<RecognizedDateExpression> {
+    // декабрь 2026, в декабре 2026 года, в мае 2027 г.
+
+    let caps = MONTH_YEAR.captures(text)?;
+
+    let month = parse_month_of_year_russian(&caps["month"])?;
+    let year = caps["year"].parse().ok()?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InMonthInYear(month, year),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_month_year_works_when {
+    use super::parse_month_year;
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn month_and_year_are_parsed() {
+        assert_month_year(
+            "декабрь 2026",
+            DateExpression::InMonthInYear(Month::December, 2026),
+        );
+        assert_month_year(
+            "в декабре 2026 года",
+            DateExpression::InMonthInYear(Month::December, 2026),
+        );
+        assert_month_year(
+            "В мае 2027 г.",
+            DateExpression::InMonthInYear(Month::May, 2027),
+        );
+        assert_month_year(
+            "в марте 2026",
+            DateExpression::InMonthInYear(Month::March, 2026),
+        );
+    }
+
+    #[test]
+    fn range_includes_the_preposition() {
+        let text = "Отпуск в декабре 2026 года.";
+        let recognized = parse_month_year(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "в декабре 2026 года");
+    }
+
+    #[test]
+    fn no_month_is_found() {
+        assert_eq!(parse_month_year("в декабре"), None);
+        assert_eq!(parse_month_year("декабрь 26"), None);
+    }
+
+    fn assert_month_year(text: &str, expected: DateExpression) {
+        assert_eq!(
+            parse_month_year(text).map(|recognized| recognized.expression),
+            Some(expected),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
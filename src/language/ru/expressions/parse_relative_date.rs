@@ -0,0 +1,65 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{string_to_num_russian, NUMBER_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static RELATIVE_DAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\bчерез\s(?:(?P<num>\d{{1,3}}|{})\s)?д(?:ень|ня|ней)\b",
+        NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNDays(i32)`
+///
+/// The noun agrees with the number, like `дня` in `через три дня` and `дней` in `через пять дней`.
+pub fn parse_relative_day(text: &str) -> Option<RecognizedDateExpression> {
+    // через день, через три дня, через 5 дней
+
+    let caps = RELATIVE_DAY.captures(text)?;
+    let num = match caps.name("num") {
+        Some(num) => string_to_num_russian(num.as_str())?,
+        None => 1,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXDays(num),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_relative_day_works_when {
+    use super::parse_relative_day;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn days_are_parsed() {
+        assert_in_x_days("через день", 1);
+        assert_in_x_days("через один день", 1);
+        assert_in_x_days("через три дня", 3);
+        assert_in_x_days("Через 3 дня", 3);
+        assert_in_x_days("через пять дней", 5);
+        assert_in_x_days("через 15 дней", 15);
+    }
+
+    #[test]
+    fn no_days_are_found() {
+        assert_eq!(parse_relative_day("через несколько дней"), None);
+        assert_eq!(parse_relative_day("три дня"), None);
+    }
+
+    fn assert_in_x_days(text: &str, days: i32) {
+        assert_eq!(
+            parse_relative_day(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(days)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
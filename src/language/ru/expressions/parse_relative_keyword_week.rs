@@ -0,0 +1,68 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{relative_word_to_num, RELATIVE_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static KEYWORD_RELATIVE_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:на\s)?(?P<prep>{})\sнедел(?:я|е|ю|и)\b",
+        RELATIVE_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::InXWeeks(i32)`
+pub fn parse_keyword_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    // на следующей неделе, на этой неделе, на прошлой неделе, следующая неделя
+
+    let caps = KEYWORD_RELATIVE_WEEK.captures(text)?;
+    let weeks = relative_word_to_num(&caps["prep"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXWeeks(weeks),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_keyword_relative_week_works_when {
+    use super::parse_keyword_relative_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn relative_weeks_are_parsed() {
+        assert_in_x_weeks("на следующей неделе", 1);
+        assert_in_x_weeks("следующая неделя", 1);
+        assert_in_x_weeks("на будущей неделе", 1);
+        assert_in_x_weeks("на этой неделе", 0);
+        assert_in_x_weeks("на прошлой неделе", -1);
+        assert_in_x_weeks("На прошлой неделе", -1);
+    }
+
+    #[test]
+    fn range_includes_the_preposition() {
+        let text = "Отпуск на следующей неделе.";
+        let recognized = parse_keyword_relative_week(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "на следующей неделе");
+    }
+
+    #[test]
+    fn no_relative_week_is_found() {
+        assert_eq!(parse_keyword_relative_week("на неделе"), None);
+        assert_eq!(parse_keyword_relative_week("следующий раз"), None);
+    }
+
+    fn assert_in_x_weeks(text: &str, weeks: i32) {
+        assert_eq!(
+            parse_keyword_relative_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXWeeks(weeks)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{relative_word_to_num, RELATIVE_WORDS},
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static RELATIVE_MONTH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:в\s)?(?P<prep>{})\sмесяц(?:а|е)?\b",
+        RELATIVE_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+pub fn parse_relative_month(text: &str) -> Option<RecognizedDateExpression> {
+    // в следующем месяце, в этом месяце, в прошлом месяце, следующий месяц
+
+    let caps = RELATIVE_MONTH.captures(text)?;
+    let months = relative_word_to_num(&caps["prep"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXMonths(months),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_relative_month_works_when {
+    use super::parse_relative_month;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn relative_months_are_parsed() {
+        assert_in_x_months("в следующем месяце", 1);
+        assert_in_x_months("следующий месяц", 1);
+        assert_in_x_months("в этом месяце", 0);
+        assert_in_x_months("в прошлом месяце", -1);
+        assert_in_x_months("В прошлом месяце", -1);
+    }
+
+    #[test]
+    fn no_relative_month_is_found() {
+        assert_eq!(parse_relative_month("в месяце"), None);
+        assert_eq!(parse_relative_month("в следующем месяцеслове"), None);
+    }
+
+    fn assert_in_x_months(text: &str, months: i32) {
+        assert_eq!(
+            parse_relative_month(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXMonths(months)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,149 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    ru::ru_date_parser::{
+        day_of_month_russian, parse_month_of_year_russian, relative_word_to_num,
+        string_to_num_russian, DAYS, MONTHS, NUMBER_WORDS, RELATIVE_WORDS,
+    },
+    shared::{DateExpression, RecognizedDateExpression},
+};
+
+static RELATIVE_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:(?:в\s)?(?P<prep>{relative})\sгод(?:у|а)?|через\s(?:(?P<num>\d{{1,3}}|{numbers})\s)?(?:год(?:а)?|лет))\b",
+        relative = RELATIVE_WORDS,
+        numbers = NUMBER_WORDS
+    ))
+    .unwrap()
+});
+
+static MONTH_IN_RELATIVE_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:в\s)?(?:(?P<date>{days})(?:-?го)?\s)?(?P<month>{months})\s(?P<prep>{relative})\sгода\b",
+        days = DAYS,
+        months = MONTHS,
+        relative = RELATIVE_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InXYears(i32)`
+pub fn parse_relative_year(text: &str) -> Option<RecognizedDateExpression> {
+    // в следующем году, в этом году, в прошлом году, через год, через два года, через 5 лет
+
+    let caps = RELATIVE_YEAR.captures(text)?;
+
+    let years = match (caps.name("prep"), caps.name("num")) {
+        (Some(prep), _) => relative_word_to_num(prep.as_str())?,
+        (None, Some(num)) => string_to_num_russian(num.as_str())?,
+        (None, None) => 1,
+    };
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXYears(years),
+        &caps,
+    ))
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::MonthInXYears(Month, i32)`
+/// or a `DateExpr::DayInMonthInXYears(Month, u32, i32)`.
+///
+/// The year follows the month in the genitive, like `следующего года` in `в декабре следующего года`.
+pub fn parse_month_in_relative_year(text: &str) -> Option<RecognizedDateExpression> {
+    // в декабре следующего года, 5 июня прошлого года, первого мая этого года
+
+    let caps = MONTH_IN_RELATIVE_YEAR.captures(text)?;
+
+    let month = parse_month_of_year_russian(&caps["month"])?;
+    let years = relative_word_to_num(&caps["prep"])?;
+
+    let expression = match caps.name("date") {
+        Some(date) => {
+            DateExpression::DayInMonthInXYears(month, day_of_month_russian(date.as_str())?, years)
+        }
+        None => DateExpression::MonthInXYears(month, years),
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_relative_year_works_when {
+    use super::{parse_month_in_relative_year, parse_relative_year};
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn relative_years_are_parsed() {
+        assert_in_x_years("в следующем году", 1);
+        assert_in_x_years("следующий год", 1);
+        assert_in_x_years("в этом году", 0);
+        assert_in_x_years("в прошлом году", -1);
+        assert_in_x_years("через год", 1);
+        assert_in_x_years("через два года", 2);
+        assert_in_x_years("через 5 лет", 5);
+    }
+
+    #[test]
+    fn no_relative_year_is_found() {
+        assert_eq!(parse_relative_year("через несколько лет"), None);
+        assert_eq!(parse_relative_year("в году"), None);
+    }
+
+    #[test]
+    fn months_in_relative_years_are_parsed() {
+        for (text, expected) in [
+            (
+                "в декабре следующего года",
+                DateExpression::MonthInXYears(Month::December, 1),
+            ),
+            (
+                "в марте прошлого года",
+                DateExpression::MonthInXYears(Month::March, -1),
+            ),
+            (
+                "в мае этого года",
+                DateExpression::MonthInXYears(Month::May, 0),
+            ),
+            (
+                "5 июня следующего года",
+                DateExpression::DayInMonthInXYears(Month::June, 5, 1),
+            ),
+            (
+                "первого мая будущего года",
+                DateExpression::DayInMonthInXYears(Month::May, 1, 1),
+            ),
+        ] {
+            assert_eq!(
+                parse_month_in_relative_year(text).map(|recognized| recognized.expression),
+                Some(expected),
+                "Failed for input: {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn range_of_month_in_relative_year_includes_the_preposition() {
+        let text = "Отпуск в декабре следующего года";
+        let recognized = parse_month_in_relative_year(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "в декабре следующего года");
+    }
+
+    #[test]
+    fn no_month_in_relative_year_is_found() {
+        assert_eq!(parse_month_in_relative_year("в следующем году"), None);
+        assert_eq!(parse_month_in_relative_year("в декабре"), None);
+    }
+
+    fn assert_in_x_years(text: &str, years: i32) {
+        assert_eq!(
+            parse_relative_year(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXYears(years)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
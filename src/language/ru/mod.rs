@@ -0,0 +1,2 @@
+mod expressions;
+pub mod ru_date_parser;
@@ -0,0 +1,525 @@
+use std::ops::Range;
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::{
+    date_match::{DateMatch, StrippedDateExpression},
+    date_parser::DateParser,
+    language::{
+        shared::{recognize_all_date_expressions, Month, RecognizedDateExpression},
+        shared_date_parser::{
+            parse_date_month_year, parse_iso_date, parse_month_date_year, parse_year_month_day,
+        },
+        shared_title::strip_date_phrase,
+    },
+    parse_error::ParseError,
+    DateFormat, StartDayOfWeek,
+};
+
+use super::expressions::{
+    parse_ago::parse_ago,
+    parse_date_in_week::parse_date_in_week,
+    parse_date_in_x_weeks::parse_in_x_weeks,
+    parse_day_alone::parse_day_alone,
+    parse_day_in_relative_week::parse_day_in_relative_week,
+    parse_in_n_months::parse_in_n_months,
+    parse_keywords::parse_keywords,
+    parse_month_alone::parse_month_alone,
+    parse_month_date::parse_month_date,
+    parse_month_year::parse_month_year,
+    parse_relative_date::parse_relative_day,
+    parse_relative_keyword_week::parse_keyword_relative_week,
+    parse_relative_month::parse_relative_month,
+    parse_relative_year::{parse_month_in_relative_year, parse_relative_year},
+};
+
+/// The russian weekdays in the nominative, accusative, genitive, prepositional and instrumental case
+/// for regular expressions, like `среда` or `в среду`.
+pub const WEEKDAYS: &str = "понедельник(?:а|е|ом)?|вторник(?:а|е|ом)?|сред(?:а|у|ы|е|ой)|четверг(?:а|е|ом)?|пятниц(?:а|у|ы|е|ей)|суббот(?:а|у|ы|е|ой)|воскресень(?:е|я|ю|ем)";
+
+/// The russian months in all cases for regular expressions, like `декабрь`, `5 декабря` or `в декабре`.
+///
+/// Every month is written as its stem followed by the case endings.
+pub const MONTHS: &str = "(?:январ|феврал|апрел|июн|июл|сентябр|октябр|ноябр|декабр)(?:ь|я|ю|е|[её]м)|(?:март|август)(?:а|у|е|ом)?|ма(?:й|я|ю|е|ем)";
+
+/// The abbreviated russian months for regular expressions, like `дек` in `5 дек.`.
+pub const MONTH_ABBREVIATIONS: &str = "янв|фев|мар|апр|июн|июл|авг|сент|сен|окт|ноя|дек";
+
+/// The forms of next, last and this in all genders and cases for regular expressions,
+/// like `следующий` in `в следующий понедельник` or `прошлой` in `на прошлой неделе`.
+pub const RELATIVE_WORDS: &str = "(?:следующ|будущ|предыдущ)(?:ий|ая|ее|ую|его|ей|ем)|прошл(?:ый|ая|ое|ую|ого|ой|ом)|эт(?:от|а|о|у|ого|ой|ом)";
+
+/// The russian number words up to ninety-nine in the cases used after `через` or before `назад`,
+/// like `две` in `через две недели` or `двадцать один` in `через двадцать один день`.
+pub const NUMBER_WORDS: &str = r"(?:двадцать|тридцать|сорок|пятьдесят|шестьдесят|семьдесят|восемьдесят|девяносто)(?:\s(?:один|одна|одну|два|две|три|четыре|пять|шесть|семь|восемь|девять))?|одиннадцать|двенадцать|тринадцать|четырнадцать|пятнадцать|шестнадцать|семнадцать|восемнадцать|девятнадцать|один|одна|одну|два|две|три|четыре|пять|шесть|семь|восемь|девять|десять";
+
+/// The days of a month as digits or as genitive ordinals for regular expressions, like `5` or `первого`
+/// in `первого мая`.
+pub const DAYS: &str = r"[12]\d|3[01]|0?[1-9]|(?:(?:двадцать|тридцать)\s)?(?:перв|втор|трет|четв[её]рт|пят|шест|седьм|восьм|девят|десят|одиннадцат|двенадцат|тринадцат|четырнадцат|пятнадцат|шестнадцат|семнадцат|восемнадцат|девятнадцат|двадцат|тридцат)(?:ого|ьего)";
+
+/// The stems of the genitive russian ordinals, like `перв` in `первого`.
+const ORDINAL_STEMS: [(&str, u32); 21] = [
+    ("перв", 1),
+    ("втор", 2),
+    ("трет", 3),
+    ("четверт", 4),
+    ("пят", 5),
+    ("шест", 6),
+    ("седьм", 7),
+    ("восьм", 8),
+    ("девят", 9),
+    ("десят", 10),
+    ("одиннадцат", 11),
+    ("двенадцат", 12),
+    ("тринадцат", 13),
+    ("четырнадцат", 14),
+    ("пятнадцат", 15),
+    ("шестнадцат", 16),
+    ("семнадцат", 17),
+    ("восемнадцат", 18),
+    ("девятнадцат", 19),
+    ("двадцат", 20),
+    ("тридцат", 30),
+];
+
+/// The stems of the russian months, which stay the same in every case.
+///
+/// `мар` comes before `ма`, so `марта` isn't mistaken for `мая`.
+const MONTH_STEMS: [(&str, Month); 12] = [
+    ("янв", Month::January),
+    ("фев", Month::February),
+    ("мар", Month::March),
+    ("апр", Month::April),
+    ("ма", Month::May),
+    ("июн", Month::June),
+    ("июл", Month::July),
+    ("авг", Month::August),
+    ("сен", Month::September),
+    ("окт", Month::October),
+    ("ноя", Month::November),
+    ("дек", Month::December),
+];
+
+/// Lowercases `text`, replaces `ё` with `е` and collapses whitespace into single spaces,
+/// so `Следующий` and `следующий` or `трёх` and `трех` are looked up the same way.
+///
+/// Only the looked up words are normalized, the input stays untouched to keep the ranges valid.
+pub fn normalize_russian(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'ё' => 'е',
+            c if c.is_whitespace() => ' ',
+            c => c,
+        })
+        .collect()
+}
+
+/// Parses a russian weekday in any case like `понедельник`, `среду` or `пятнице` by its stem.
+pub fn parse_weekday_russian(text: &str) -> Option<Weekday> {
+    let text = normalize_russian(text);
+
+    [
+        ("понедельник", Weekday::Mon),
+        ("вторник", Weekday::Tue),
+        ("сред", Weekday::Wed),
+        ("четверг", Weekday::Thu),
+        ("пятниц", Weekday::Fri),
+        ("суббот", Weekday::Sat),
+        ("воскресень", Weekday::Sun),
+    ]
+    .into_iter()
+    .find(|(stem, _)| text.starts_with(stem))
+    .map(|(_, weekday)| weekday)
+}
+
+/// Parses a russian month or its abbreviation in any case like `декабрь`, `декабря`, `декабре` or `дек.`
+/// into a `Month`.
+///
+/// Russian months inflect by case, so they are looked up by their stem instead of a list of suffixes.
+pub fn parse_month_of_year_russian(text: &str) -> Option<Month> {
+    let text = normalize_russian(text);
+
+    MONTH_STEMS
+        .into_iter()
+        .find(|(stem, _)| text.starts_with(stem))
+        .map(|(_, month)| month)
+}
+
+/// Returns the relative week, month or year of a form of next, last or this.
+///
+/// e.g. `следующий` => 1, `прошлой` => -1, `этом` => 0
+pub fn relative_word_to_num(text: &str) -> Option<i32> {
+    let text = normalize_russian(text);
+
+    if text.starts_with("следующ") || text.starts_with("будущ") {
+        Some(1)
+    } else if text.starts_with("прошл") || text.starts_with("предыдущ") {
+        Some(-1)
+    } else if text.starts_with("эт") {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+/// Parses a number written with digits or as russian words like `две` or `двадцать один`.
+pub fn string_to_num_russian(input: &str) -> Option<i32> {
+    if let Ok(num) = input.parse::<i32>() {
+        return Some(num);
+    }
+
+    let input = normalize_russian(input);
+    match input.split_once(' ') {
+        Some((tens, ones)) => {
+            let tens = russian_number_word(tens).filter(|tens| *tens >= 20 && tens % 10 == 0)?;
+            let ones = russian_number_word(ones).filter(|ones| *ones < 10)?;
+
+            Some(tens + ones)
+        }
+        None => russian_number_word(&input),
+    }
+}
+
+fn russian_number_word(word: &str) -> Option<i32> {
+    let num = match word {
+        "один" | "одна" | "одну" => 1,
+        "два" | "две" => 2,
+        "три" => 3,
+        "четыре" => 4,
+        "пять" => 5,
+        "шесть" => 6,
+        "семь" => 7,
+        "восемь" => 8,
+        "девять" => 9,
+        "десять" => 10,
+        "одиннадцать" => 11,
+        "двенадцать" => 12,
+        "тринадцать" => 13,
+        "четырнадцать" => 14,
+        "пятнадцать" => 15,
+        "шестнадцать" => 16,
+        "семнадцать" => 17,
+        "восемнадцать" => 18,
+        "девятнадцать" => 19,
+        "двадцать" => 20,
+        "тридцать" => 30,
+        "сорок" => 40,
+        "пятьдесят" => 50,
+        "шестьдесят" => 60,
+        "семьдесят" => 70,
+        "восемьдесят" => 80,
+        "девяносто" => 90,
+        _ => return None,
+    };
+
+    Some(num)
+}
+
+/// Parses a day of a month written with digits or as a genitive ordinal like `первого` or `двадцать пятого`.
+pub fn day_of_month_russian(input: &str) -> Option<u32> {
+    if let Ok(day) = input.parse::<u32>() {
+        return (1..=31).contains(&day).then_some(day);
+    }
+
+    let input = normalize_russian(input);
+    let (tens, ordinal) = match input.split_once(' ') {
+        Some(("двадцать", ordinal)) => (20, ordinal),
+        Some(("тридцать", ordinal)) => (30, ordinal),
+        Some(_) => return None,
+        None => (0, input.as_ref()),
+    };
+
+    let stem = ordinal
+        .strip_suffix("ьего")
+        .or(ordinal.strip_suffix("ого"))?;
+    let (_, day) = ORDINAL_STEMS.into_iter().find(|(s, _)| *s == stem)?;
+
+    if tens > 0 && day >= 10 {
+        return None;
+    }
+
+    Some(tens + day).filter(|day| *day <= 31)
+}
+
+/// Prepositions which are removed together with a date phrase, like `до` in `сдать отчёт до пятницы`.
+const PREPOSITIONS: [&str; 6] = ["в", "во", "на", "до", "к", "по"];
+
+/// Phrases at the beginning of an input which are not part of a title.
+const LEADING_PHRASES: [&str; 3] = ["напомни мне", "напомни", "не забудь"];
+
+pub struct RuDateParser {}
+
+impl RuDateParser {
+    /// Dates are written day first in russian, e.g. 5.12.2026.
+    pub const DATE_FORMAT: DateFormat = DateFormat::DayMonthYear;
+
+    /// Weeks start on monday in russian speaking countries.
+    pub const START_DAY_OF_WEEK: StartDayOfWeek = StartDayOfWeek::Monday;
+}
+
+/// Recognizes the first russian date expression in `input`.
+///
+/// The parsers are tried from the most specific to the least specific one.
+pub fn recognize_date_expression(
+    input: &str,
+    date_format: &DateFormat,
+) -> Option<RecognizedDateExpression> {
+    // сегодня, завтра, послезавтра, вчера, позавчера
+    if let Some(date) = parse_keywords(input) {
+        return Some(date);
+    }
+
+    // три дня назад, 2 недели назад
+    if let Some(date) = parse_ago(input) {
+        return Some(date);
+    }
+
+    // в понедельник на следующей неделе
+    if let Some(date) = parse_day_in_relative_week(input) {
+        return Some(date);
+    }
+
+    // в следующий понедельник, в прошлую пятницу, в эту среду
+    if let Some(date) = parse_date_in_week(input) {
+        return Some(date);
+    }
+
+    // a single day of the week
+    if let Some(date) = parse_day_alone(input) {
+        return Some(date);
+    }
+
+    // на следующей неделе, на этой неделе, на прошлой неделе
+    if let Some(date) = parse_keyword_relative_week(input) {
+        return Some(date);
+    }
+
+    // через две недели, через неделю
+    if let Some(date) = parse_in_x_weeks(input) {
+        return Some(date);
+    }
+
+    // через три дня, через 5 дней
+    if let Some(date) = parse_relative_day(input) {
+        return Some(date);
+    }
+
+    // ISO dates like 2026-12-05, 2026-W49-3 or 2026-339 regardless of the date format
+    if let Some(date) = parse_iso_date(input) {
+        return Some(date);
+    }
+
+    // full dates in DD.MM.YYYY, MM.DD.YYYY or YYYY.MM.DD formats
+    match date_format {
+        DateFormat::DayMonthYear => {
+            if let Some(date) = parse_date_month_year(input) {
+                return Some(date);
+            }
+        }
+        DateFormat::MonthDayYear => {
+            if let Some(date) = parse_month_date_year(input) {
+                return Some(date);
+            }
+        }
+        DateFormat::YearMonthDay => {
+            if let Some(date) = parse_year_month_day(input) {
+                return Some(date);
+            }
+        }
+    }
+
+    // в декабре следующего года, 5 июня прошлого года
+    if let Some(date) = parse_month_in_relative_year(input) {
+        return Some(date);
+    }
+
+    // 5 декабря, 5-го декабря, первого мая, 1 января 2026 года
+    if let Some(date) = parse_month_date(input) {
+        return Some(date);
+    }
+
+    // декабрь 2026, в декабре 2026 года
+    if let Some(date) = parse_month_year(input) {
+        return Some(date);
+    }
+
+    // в декабре, в мае
+    if let Some(date) = parse_month_alone(input) {
+        return Some(date);
+    }
+
+    // через два месяца, через месяц
+    if let Some(date) = parse_in_n_months(input) {
+        return Some(date);
+    }
+
+    // в следующем месяце, в этом месяце, в прошлом месяце
+    if let Some(date) = parse_relative_month(input) {
+        return Some(date);
+    }
+
+    // в следующем году, в этом году, через два года
+    if let Some(date) = parse_relative_year(input) {
+        return Some(date);
+    }
+
+    None
+}
+
+/// Removes the date phrase in `range` from `text` together with the words around it which belong to the date.
+pub fn title_without_date_phrase(text: &str, range: Option<Range<usize>>) -> String {
+    match range {
+        Some(range) => strip_date_phrase(text, range, &PREPOSITIONS, &LEADING_PHRASES),
+        None => strip_date_phrase(text, text.len()..text.len(), &[], &LEADING_PHRASES),
+    }
+}
+
+/// The RuDateParser can be used to parse russian date information out of strings.
+///
+/// ```
+/// use date_time_parser_multi_language::{DateParser, RuDateParser};
+/// use chrono::NaiveDate;
+//
+/// fn main() {
+///     let some_input = "Позвонить стоматологу в следующий понедельник.";
+///
+///     let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+///
+///     let date_match = RuDateParser::search_date_expression(some_input, &now, &RuDateParser::DATE_FORMAT, &RuDateParser::START_DAY_OF_WEEK).unwrap();
+///
+///     assert_eq!(date_match.date, NaiveDate::from_ymd_opt(2026, 10, 19).unwrap());
+///     assert_eq!(date_match.text, "в следующий понедельник");
+/// }
+/// ```
+impl DateParser for RuDateParser {
+    fn try_search_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Result<DateMatch, ParseError> {
+        let recognized =
+            recognize_date_expression(text, date_format).ok_or(ParseError::NoExpressionFound)?;
+
+        DateMatch::resolve(text, recognized, now, start_day_week)
+    }
+
+    fn search_all_date_expressions(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Vec<DateMatch> {
+        recognize_all_date_expressions(text, date_format, recognize_date_expression)
+            .into_iter()
+            .filter_map(|recognized| DateMatch::resolve(text, recognized, now, start_day_week).ok())
+            .collect()
+    }
+
+    fn strip_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> StrippedDateExpression {
+        let date_match = Self::search_date_expression(text, now, date_format, start_day_week);
+        let title = title_without_date_phrase(
+            text,
+            date_match
+                .as_ref()
+                .map(|date_match| date_match.range.clone()),
+        );
+
+        StrippedDateExpression { title, date_match }
+    }
+}
+
+#[cfg(test)]
+mod ru_date_parser_works_when {
+    use chrono::Weekday;
+
+    use super::{
+        day_of_month_russian, normalize_russian, parse_month_of_year_russian,
+        parse_weekday_russian, relative_word_to_num, string_to_num_russian,
+    };
+    use crate::language::shared::Month;
+
+    #[test]
+    fn letters_and_whitespace_are_normalized() {
+        assert_eq!(normalize_russian("Трёх"), "трех");
+        assert_eq!(normalize_russian("ДЕКАБРЯ"), "декабря");
+        assert_eq!(normalize_russian("через\u{a0}день"), "через день");
+    }
+
+    #[test]
+    fn weekdays_are_parsed_in_every_case() {
+        assert_eq!(parse_weekday_russian("понедельник"), Some(Weekday::Mon));
+        assert_eq!(parse_weekday_russian("Среду"), Some(Weekday::Wed));
+        assert_eq!(parse_weekday_russian("пятнице"), Some(Weekday::Fri));
+        assert_eq!(parse_weekday_russian("воскресенье"), Some(Weekday::Sun));
+        assert_eq!(parse_weekday_russian("неделя"), None);
+    }
+
+    #[test]
+    fn months_are_parsed_by_their_stem() {
+        assert_eq!(
+            parse_month_of_year_russian("декабрь"),
+            Some(Month::December)
+        );
+        assert_eq!(
+            parse_month_of_year_russian("декабря"),
+            Some(Month::December)
+        );
+        assert_eq!(
+            parse_month_of_year_russian("декабре"),
+            Some(Month::December)
+        );
+        assert_eq!(parse_month_of_year_russian("Дек."), Some(Month::December));
+        assert_eq!(parse_month_of_year_russian("марта"), Some(Month::March));
+        assert_eq!(parse_month_of_year_russian("мая"), Some(Month::May));
+        assert_eq!(parse_month_of_year_russian("December"), None);
+    }
+
+    #[test]
+    fn relative_words_are_parsed() {
+        assert_eq!(relative_word_to_num("следующий"), Some(1));
+        assert_eq!(relative_word_to_num("Следующей"), Some(1));
+        assert_eq!(relative_word_to_num("прошлую"), Some(-1));
+        assert_eq!(relative_word_to_num("этом"), Some(0));
+        assert_eq!(relative_word_to_num("завтра"), None);
+    }
+
+    #[test]
+    fn number_words_are_parsed() {
+        assert_eq!(string_to_num_russian("одну"), Some(1));
+        assert_eq!(string_to_num_russian("две"), Some(2));
+        assert_eq!(string_to_num_russian("Три"), Some(3));
+        assert_eq!(string_to_num_russian("12"), Some(12));
+        assert_eq!(string_to_num_russian("тринадцать"), Some(13));
+        assert_eq!(string_to_num_russian("двадцать"), Some(20));
+        assert_eq!(string_to_num_russian("Двадцать один"), Some(21));
+        assert_eq!(string_to_num_russian("девяносто девять"), Some(99));
+    }
+
+    #[test]
+    fn no_number_words_are_found() {
+        assert_eq!(string_to_num_russian("сто"), None);
+        assert_eq!(string_to_num_russian("двадцать тридцать"), None);
+        assert_eq!(string_to_num_russian("пять два"), None);
+        assert_eq!(string_to_num_russian("двадцать одиннадцать"), None);
+    }
+
+    #[test]
+    fn days_of_month_are_parsed() {
+        assert_eq!(day_of_month_russian("5"), Some(5));
+        assert_eq!(day_of_month_russian("первого"), Some(1));
+        assert_eq!(day_of_month_russian("Третьего"), Some(3));
+        assert_eq!(day_of_month_russian("четвёртого"), Some(4));
+        assert_eq!(day_of_month_russian("двадцать пятого"), Some(25));
+        assert_eq!(day_of_month_russian("тридцать первого"), Some(31));
+        assert_eq!(day_of_month_russian("тридцать второго"), None);
+        assert_eq!(day_of_month_russian("двадцать десятого"), None);
+        assert_eq!(day_of_month_russian("32"), None);
+    }
+}
@@ -1,8 +1,12 @@
-use chrono::Weekday;
+use std::{collections::VecDeque, ops::Range};
+
+use chrono::{Datelike, Days, Duration, Months, NaiveDate, Weekday};
+use log::debug;
+use regex::Captures;
 
 use crate::recognizable::Recognizable;
 
-use super::date_format::DateFormat;
+use super::{date_format::DateFormat, start_day_of_week::StartDayOfWeek};
 
 #[derive(PartialEq, Debug, Clone)]
 pub enum Month {
@@ -43,10 +47,6 @@ impl Recognizable for Weekday {
     fn recognize(text: &str, _date_format: &DateFormat) -> Option<Weekday> {
         text.parse::<Weekday>().ok()
     }
-
-    fn describe() -> &'static str {
-        "day of week"
-    }
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -59,12 +59,1159 @@ pub enum DateExpression {
     DayInMonth(Month, u32),    // e.g. June 8th => InMonth(Jun, 8)
     DayInMonthInYear(Month, u32, i32), // e.g. June 8th, 2019 => InYear(Jun, 8, 2019)
     InMonthInYear(Month, i32),
+    InXYears(i32),                             // e.g. next year => InXYears(1)
+    MonthInXYears(Month, i32), // e.g. in december next year => MonthInXYears(Dec, 1)
+    DayInMonthInXYears(Month, u32, i32), // e.g. june 8th next year => DayInMonthInXYears(Jun, 8, 1)
+    NextDayOfMonth(u32),       // e.g. the 22nd => NextDayOfMonth(22)
+    NextMonthOfYear(Month),    // e.g. в декабре => NextMonthOfYear(Dec)
+    DayInIsoWeek(i32, u32, Weekday), // e.g. 2026-W49-3 => DayInIsoWeek(2026, 49, Wed)
+    DayInYear(i32, u32),       // e.g. 2026-339 => DayInYear(2026, 339)
+    DayInMonthInTwoDigitYear(Month, u32, u32), // e.g. 5.12.26 => DayInMonthInTwoDigitYear(Dec, 5, 26)
+    InMonthInTwoDigitYear(Month, u32),         // e.g. Dec '26 => InMonthInTwoDigitYear(Dec, 26)
+}
+
+/// Builds the expression of a date with a year written with four or two digits, like `2026`, `26` or `'26`.
+///
+/// Without a `day` the expression names the whole month.
+pub(crate) fn date_in_written_year(
+    month: Month,
+    day: Option<u32>,
+    year: &str,
+) -> Option<DateExpression> {
+    let digits = year.trim_start_matches(['\'', '’']);
+
+    let expression = match (day, digits.len()) {
+        (Some(day), 2) => {
+            DateExpression::DayInMonthInTwoDigitYear(month, day, digits.parse().ok()?)
+        }
+        (None, 2) => DateExpression::InMonthInTwoDigitYear(month, digits.parse().ok()?),
+        (Some(day), _) => DateExpression::DayInMonthInYear(month, day, digits.parse().ok()?),
+        (None, _) => DateExpression::InMonthInYear(month, digits.parse().ok()?),
+    };
+
+    Some(expression)
+}
+
+/// A recognized `DateExpression` together with the byte range of the input it was recognized in.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RecognizedDateExpression {
+    pub expression: DateExpression,
+    pub range: Range<usize>,
+}
+
+impl RecognizedDateExpression {
+    /// Uses the range of the whole match of `captures`.
+    pub fn from_captures(expression: DateExpression, captures: &Captures) -> Self {
+        RecognizedDateExpression {
+            expression,
+            range: captures.get(0).unwrap().range(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// An abstract syntax for parsing date ranges.
+pub enum DateRangeExpression {
+    FromTo(DateExpression, DateExpression), // e.g. from monday to wednesday => FromTo(DayInXWeeks(0, Mon), DayInXWeeks(0, Wed))
+    Until(DateExpression),                  // e.g. until friday => Until(DayInXWeeks(0, Fri))
+    WholeWeek(i32),                         // e.g. next week => WholeWeek(1)
+}
+
+/// A recognized `DateRangeExpression` together with the byte range of the input it was recognized in.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RecognizedDateRangeExpression {
+    pub expression: DateRangeExpression,
+    pub range: Range<usize>,
+}
+
+/// Recognizes the first date expression of a language in an input.
+pub type RecognizeDateExpression = fn(&str, &DateFormat) -> Option<RecognizedDateExpression>;
+
+/// Recognizes all non-overlapping date expressions in `input` in reading order.
+///
+/// `recognize` finds one expression at a time. Every found expression is masked with spaces before
+/// searching again, so the priority of the recognizer is kept and the byte ranges stay valid.
+pub fn recognize_all_date_expressions(
+    input: &str,
+    date_format: &DateFormat,
+    recognize: RecognizeDateExpression,
+) -> Vec<RecognizedDateExpression> {
+    let mut masked = input.to_string();
+    let mut found: Vec<RecognizedDateExpression> = Vec::new();
+
+    while let Some(recognized) = recognize(&masked, date_format) {
+        if recognized.range.is_empty() {
+            break;
+        }
+
+        masked.replace_range(
+            recognized.range.clone(),
+            &" ".repeat(recognized.range.len()),
+        );
+        found.push(recognized);
+    }
+
+    found.sort_by_key(|recognized| recognized.range.start);
+    found
+}
+
+/// The reason why a recognized `DateExpression` couldn't be resolved into a date.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ResolveError {
+    /// The expression names a day which doesn't exist, like `February 30th`.
+    InvalidDate,
+    /// The date lies outside of the dates `chrono` supports.
+    Overflow,
+}
+
+/// Resolves a `DateExpression` relative to `now` into a `NaiveDate`.
+pub fn resolve_date_expression(
+    date_expr: DateExpression,
+    now: &NaiveDate,
+    start_day_week: &StartDayOfWeek,
+) -> Result<NaiveDate, ResolveError> {
+    resolve_date_expression_with(
+        date_expr,
+        now,
+        start_day_week,
+        &MonthOverflow::default(),
+        &PivotWindow::default(),
+    )
+}
+
+/// Resolves a `DateExpression` relative to `now` into a `NaiveDate`, with the given handling of
+/// shorter target months and two-digit years.
+pub fn resolve_date_expression_with(
+    date_expr: DateExpression,
+    now: &NaiveDate,
+    start_day_week: &StartDayOfWeek,
+    month_overflow: &MonthOverflow,
+    pivot_window: &PivotWindow,
+) -> Result<NaiveDate, ResolveError> {
+    match date_expr {
+        DateExpression::InXDays(days) => {
+            debug!("InXDays; days: {}", days);
+
+            add_days(now, days as i64)
+        }
+
+        DateExpression::DayInMonth(month, day) => {
+            debug!("DayInMonth; Month: {:?}; Day: {}", month, day);
+
+            let date = NaiveDate::from_ymd_opt(now.year(), month as u32, day)
+                .ok_or(ResolveError::InvalidDate)?;
+
+            if date.lt(now) {
+                date.checked_add_months(Months::new(12))
+                    .ok_or(ResolveError::Overflow)
+            } else {
+                Ok(date)
+            }
+        }
+
+        DateExpression::DayInMonthInYear(month, day, year) => {
+            debug!(
+                "DayInMonthInYear; Month: {:?}; Day: {}; Year: {}",
+                month, day, year
+            );
+            NaiveDate::from_ymd_opt(year, month as u32, day).ok_or(ResolveError::InvalidDate)
+        }
+
+        DateExpression::InXWeeks(weeks) => {
+            debug!("InXWeeks; Weeks {}", weeks);
+
+            let difference = 7 * weeks as i64
+                - match start_day_week {
+                    StartDayOfWeek::Sunday => now.weekday().num_days_from_sunday() as i64,
+                    StartDayOfWeek::Monday => now.weekday().num_days_from_monday() as i64,
+                };
+
+            add_days(now, difference)
+        }
+
+        DateExpression::DayInXWeeks(weeks, weekday) => {
+            debug!("DayInXWeeks; Weeks: {}; Weekday: {:?}", weeks, weekday);
+
+            let difference = 7 * weeks as i64
+                + match start_day_week {
+                    StartDayOfWeek::Sunday => {
+                        (weekday.num_days_from_sunday() as i64)
+                            - (now.weekday().num_days_from_sunday() as i64)
+                    }
+                    StartDayOfWeek::Monday => {
+                        (weekday.num_days_from_monday() as i64)
+                            - (now.weekday().num_days_from_monday() as i64)
+                    }
+                };
+
+            add_days(now, difference)
+        }
+
+        DateExpression::InXMonths(months) => {
+            debug!("InXMonths; Months {} ", months);
+
+            resolve_in_x_months(now, months, month_overflow)
+        }
+
+        DateExpression::InMonthInYear(month, year) => {
+            debug!("DayInXWeeks; Months: {:?}; Year: {} ", month, year);
+            NaiveDate::from_ymd_opt(year, month as u32, 1).ok_or(ResolveError::Overflow)
+        }
+
+        DateExpression::InXYears(years) => {
+            debug!("InXYears; Years: {}", years);
+
+            let months = years.checked_mul(12).ok_or(ResolveError::Overflow)?;
+
+            resolve_in_x_months(now, months, month_overflow)
+        }
+
+        DateExpression::MonthInXYears(month, years) => {
+            debug!("MonthInXYears; Month: {:?}; Years: {}", month, years);
+
+            let year = now
+                .year()
+                .checked_add(years)
+                .ok_or(ResolveError::Overflow)?;
+
+            NaiveDate::from_ymd_opt(year, month as u32, 1).ok_or(ResolveError::Overflow)
+        }
+
+        DateExpression::DayInMonthInXYears(month, day, years) => {
+            debug!(
+                "DayInMonthInXYears; Month: {:?}; Day: {}; Years: {}",
+                month, day, years
+            );
+
+            let year = now
+                .year()
+                .checked_add(years)
+                .ok_or(ResolveError::Overflow)?;
+
+            NaiveDate::from_ymd_opt(year, month as u32, day).ok_or(ResolveError::InvalidDate)
+        }
+
+        DateExpression::NextDayOfMonth(day) => {
+            debug!("NextDayOfMonth; Day: {}", day);
+
+            let first = now.with_day(1).ok_or(ResolveError::Overflow)?;
+
+            // skips months without the day, e.g. the 31st in april
+            (0..12)
+                .filter_map(|months| first.checked_add_months(Months::new(months))?.with_day(day))
+                .find(|date| date >= now)
+                .ok_or(ResolveError::InvalidDate)
+        }
+
+        DateExpression::NextMonthOfYear(month) => {
+            debug!("NextMonthOfYear; Month: {:?}", month);
+
+            let month = month as u32;
+
+            // the current month is still this year's month
+            let year = if month < now.month() {
+                now.year().checked_add(1).ok_or(ResolveError::Overflow)?
+            } else {
+                now.year()
+            };
+
+            NaiveDate::from_ymd_opt(year, month, 1).ok_or(ResolveError::Overflow)
+        }
+
+        DateExpression::DayInIsoWeek(year, week, weekday) => {
+            debug!(
+                "DayInIsoWeek; Year: {}; Week: {}; Weekday: {:?}",
+                year, week, weekday
+            );
+
+            NaiveDate::from_isoywd_opt(year, week, weekday).ok_or(ResolveError::InvalidDate)
+        }
+
+        DateExpression::DayInYear(year, day) => {
+            debug!("DayInYear; Year: {}; Day: {}", year, day);
+
+            NaiveDate::from_yo_opt(year, day).ok_or(ResolveError::InvalidDate)
+        }
+
+        DateExpression::DayInMonthInTwoDigitYear(month, day, year) => {
+            debug!(
+                "DayInMonthInTwoDigitYear; Month: {:?}; Day: {}; Year: {}",
+                month, day, year
+            );
+
+            let year = resolve_two_digit_year(now, year, pivot_window)?;
+
+            NaiveDate::from_ymd_opt(year, month as u32, day).ok_or(ResolveError::InvalidDate)
+        }
+
+        DateExpression::InMonthInTwoDigitYear(month, year) => {
+            debug!("InMonthInTwoDigitYear; Month: {:?}; Year: {}", month, year);
+
+            let year = resolve_two_digit_year(now, year, pivot_window)?;
+
+            NaiveDate::from_ymd_opt(year, month as u32, 1).ok_or(ResolveError::Overflow)
+        }
+    }
+}
+
+/// Adds a positive or negative number of days to `date`.
+fn add_days(date: &NaiveDate, days: i64) -> Result<NaiveDate, ResolveError> {
+    Duration::try_days(days)
+        .and_then(|delta| date.checked_add_signed(delta))
+        .ok_or(ResolveError::Overflow)
+}
+
+/// Decides what happens when the day of the month doesn't exist in the target month,
+/// like in `in 1 month` on the 31st of january.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub enum MonthOverflow {
+    /// Uses the last day of the target month, e.g. the 28th of february.
+    #[default]
+    Clamp,
+    /// Continues counting into the next month, e.g. the 3rd of march.
+    NextMonth,
+}
+
+/// Moves `now` by a positive or negative number of months, rolling over into other years.
+pub fn resolve_in_x_months(
+    now: &NaiveDate,
+    months: i32,
+    overflow: &MonthOverflow,
+) -> Result<NaiveDate, ResolveError> {
+    let move_months = |date: NaiveDate| {
+        let delta = Months::new(months.unsigned_abs());
+
+        if months < 0 {
+            date.checked_sub_months(delta)
+        } else {
+            date.checked_add_months(delta)
+        }
+    };
+
+    let date = match overflow {
+        // chrono clamps to the last day of the month itself
+        MonthOverflow::Clamp => move_months(*now),
+        MonthOverflow::NextMonth => move_months(now.with_day(1).ok_or(ResolveError::Overflow)?)
+            .and_then(|first| first.checked_add_days(Days::new(now.day0() as u64))),
+    };
+
+    date.ok_or(ResolveError::Overflow)
+}
+
+/// Decides the century of a two-digit year like `'26`, relative to the reference date.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PivotWindow {
+    /// How many years after the reference date a two-digit year may lie at most,
+    /// later years are moved into the previous century. Values above 99 are treated as 99.
+    pub years_ahead: u32,
+}
+
+impl Default for PivotWindow {
+    /// Reads two-digit years as the year within 50 years around the reference date.
+    fn default() -> Self {
+        PivotWindow { years_ahead: 50 }
+    }
+}
+
+/// Returns the full year of the two-digit `year`, which lies inside the `window` around `now`.
+pub fn resolve_two_digit_year(
+    now: &NaiveDate,
+    year: u32,
+    window: &PivotWindow,
+) -> Result<i32, ResolveError> {
+    let latest = now
+        .year()
+        .checked_add(window.years_ahead.min(99) as i32)
+        .ok_or(ResolveError::Overflow)?;
+
+    Ok(latest - (latest - (year % 100) as i32).rem_euclid(100))
+}
+
+/// Decides if a date without an explicit week or year lies in the future or in the past.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Preference {
+    Future,
+    Past,
+}
+
+/// Moves a resolved date of an expression without an explicit week or year, like `friday` or `June 5th`,
+/// in the direction of the `preference`.
+///
+/// Dates of other expressions are returned unchanged.
+pub fn apply_preference(
+    date_expr: &DateExpression,
+    date: NaiveDate,
+    now: &NaiveDate,
+    preference: &Preference,
+) -> Option<NaiveDate> {
+    let is_wrong_direction = match preference {
+        Preference::Future => date.lt(now),
+        Preference::Past => date.gt(now),
+    };
+
+    if !is_wrong_direction {
+        return Some(date);
+    }
+
+    match (date_expr, preference) {
+        (DateExpression::DayInXWeeks(0, _), Preference::Future) => {
+            date.checked_add_days(Days::new(7))
+        }
+        (DateExpression::DayInXWeeks(0, _), Preference::Past) => {
+            date.checked_sub_days(Days::new(7))
+        }
+        // a day in a month is already resolved to the next year, when it lies in the past
+        (DateExpression::DayInMonth(_, _), Preference::Future) => {
+            date.checked_add_months(Months::new(12))
+        }
+        (DateExpression::DayInMonth(_, _), Preference::Past) => {
+            let mut date = date.checked_sub_months(Months::new(12))?;
+
+            if date.gt(now) {
+                date = date.checked_sub_months(Months::new(12))?;
+            }
+
+            Some(date)
+        }
+        // skips months without the day, e.g. the 31st in september
+        (DateExpression::NextDayOfMonth(day), Preference::Past) => {
+            let first = now.with_day(1)?;
+
+            (0..12)
+                .filter_map(|months| {
+                    first
+                        .checked_sub_months(Months::new(months))?
+                        .with_day(*day)
+                })
+                .find(|date| date <= now)
+        }
+        (DateExpression::NextMonthOfYear(_), Preference::Past) => {
+            date.checked_sub_months(Months::new(12))
+        }
+        _ => Some(date),
+    }
+}
+
+/// Resolves a `DateRangeExpression` relative to `now` into the inclusive `start` and `end` dates.
+///
+/// When the end of a range lies before its start, like in `from friday to monday`, the end is moved
+/// into the next week or the next year, if the end expression leaves this open. The same applies to
+/// the end of an `until` range that lies before `now`.
+pub fn resolve_date_range_expression(
+    range_expr: DateRangeExpression,
+    now: &NaiveDate,
+    start_day_week: &StartDayOfWeek,
+) -> Option<(NaiveDate, NaiveDate)> {
+    match range_expr {
+        DateRangeExpression::FromTo(from, to) => {
+            debug!("FromTo; From: {:?}; To: {:?}", from, to);
+
+            let start = resolve_date_expression(from, now, start_day_week).ok()?;
+            let end = resolve_date_expression(to.clone(), now, start_day_week).ok()?;
+
+            if end.ge(&start) {
+                return Some((start, end));
+            }
+
+            let end = match to {
+                DateExpression::DayInXWeeks(_, _) => end.checked_add_days(Days::new(7))?,
+                DateExpression::DayInMonth(_, _) => end.checked_add_months(Months::new(12))?,
+                _ => return None,
+            };
+
+            if end.ge(&start) {
+                Some((start, end))
+            } else {
+                None
+            }
+        }
+
+        DateRangeExpression::Until(to) => {
+            debug!("Until; To: {:?}", to);
+
+            let end = resolve_date_expression(to.clone(), now, start_day_week).ok()?;
+
+            if end.ge(now) {
+                return Some((*now, end));
+            }
+
+            let end = match to {
+                DateExpression::DayInXWeeks(0, _) => end.checked_add_days(Days::new(7))?,
+                DateExpression::DayInMonth(_, _) => end.checked_add_months(Months::new(12))?,
+                _ => return None,
+            };
+
+            if end.ge(now) {
+                Some((*now, end))
+            } else {
+                None
+            }
+        }
+
+        DateRangeExpression::WholeWeek(weeks) => {
+            debug!("WholeWeek; Weeks: {}", weeks);
+
+            let start =
+                resolve_date_expression(DateExpression::InXWeeks(weeks), now, start_day_week)
+                    .ok()?;
+
+            Some((start, start.checked_add_days(Days::new(6))?))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A rule for recurring dates.
+///
+/// e.g. every other week on monday and thursday => Recurrence { frequency: Weekly, interval: 2, weekdays: [Mon, Thu], month_day: None }
+#[derive(Debug, PartialEq, Clone)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    /// The number of days, weeks, months or years between two occurrences.
+    pub interval: u32,
+    /// The days of a weekly recurrence. When empty, the weekday of the first date is used.
+    pub weekdays: Vec<Weekday>,
+    /// The day of a monthly recurrence. When empty, the day of the first date is used.
+    pub month_day: Option<u32>,
+}
+
+/// The number of periods in a row without an occurrence, before the search for occurrences stops.
+///
+/// This ends recurrences which never occur, like every 12 months on the 30th when starting in february.
+const MAX_EMPTY_PERIODS: u32 = 100;
+
+impl Recurrence {
+    /// Returns the occurrences of the recurrence on or after `from`.
+    ///
+    /// `from` is the first day of the recurrence, e.g. every other week counts weeks starting with the
+    /// week of `from`. The weeks start on the `start_of_week`.
+    pub fn occurrences(&self, from: &NaiveDate, start_of_week: &StartDayOfWeek) -> Occurrences {
+        Occurrences {
+            recurrence: self.clone(),
+            from: *from,
+            start_of_week: start_of_week.clone(),
+            period: 0,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+/// An iterator over the dates of a `Recurrence`.
+pub struct Occurrences {
+    recurrence: Recurrence,
+    from: NaiveDate,
+    start_of_week: StartDayOfWeek,
+    period: u32,
+    pending: VecDeque<NaiveDate>,
+}
+
+impl Occurrences {
+    /// Returns the dates in the `period`th day, week, month or year after `from`.
+    ///
+    /// Returns `None`, when the dates are out of the supported range.
+    fn dates_in_period(&self, period: u32) -> Option<Vec<NaiveDate>> {
+        let offset = period.checked_mul(self.recurrence.interval)?;
+
+        match self.recurrence.frequency {
+            Frequency::Daily => Some(vec![self
+                .from
+                .checked_add_days(Days::new(offset as u64))?]),
+
+            Frequency::Weekly => {
+                let week_start = self
+                    .from
+                    .checked_sub_days(Days::new(days_from_start_of_week(
+                        &self.from.weekday(),
+                        &self.start_of_week,
+                    ) as u64))?
+                    .checked_add_days(Days::new(7 * offset as u64))?;
+
+                let mut days: Vec<u32> = if self.recurrence.weekdays.is_empty() {
+                    vec![days_from_start_of_week(
+                        &self.from.weekday(),
+                        &self.start_of_week,
+                    )]
+                } else {
+                    self.recurrence
+                        .weekdays
+                        .iter()
+                        .map(|weekday| days_from_start_of_week(weekday, &self.start_of_week))
+                        .collect()
+                };
+                days.sort();
+                days.dedup();
+
+                days.into_iter()
+                    .map(|day| week_start.checked_add_days(Days::new(day as u64)))
+                    .collect()
+            }
+
+            Frequency::Monthly => {
+                let month_start = self
+                    .from
+                    .with_day(1)?
+                    .checked_add_months(Months::new(offset))?;
+                let day = self.recurrence.month_day.unwrap_or(self.from.day());
+
+                Some(
+                    NaiveDate::from_ymd_opt(month_start.year(), month_start.month(), day)
+                        .into_iter()
+                        .collect(),
+                )
+            }
+
+            Frequency::Yearly => {
+                let year = self.from.year().checked_add(i32::try_from(offset).ok()?)?;
+
+                Some(
+                    NaiveDate::from_ymd_opt(year, self.from.month(), self.from.day())
+                        .into_iter()
+                        .collect(),
+                )
+            }
+        }
+    }
+}
+
+impl Iterator for Occurrences {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<NaiveDate> {
+        let mut empty_periods = 0;
+
+        while self.pending.is_empty() {
+            if empty_periods >= MAX_EMPTY_PERIODS {
+                return None;
+            }
+
+            let dates = self.dates_in_period(self.period)?;
+            self.period = self.period.checked_add(1)?;
+
+            let from = self.from;
+            self.pending
+                .extend(dates.into_iter().filter(|date| date.ge(&from)));
+            empty_periods += 1;
+        }
+
+        self.pending.pop_front()
+    }
+}
+
+/// Returns the number of days from the start of the week to `weekday`.
+pub fn days_from_start_of_week(weekday: &Weekday, start_of_week: &StartDayOfWeek) -> u32 {
+    match start_of_week {
+        StartDayOfWeek::Sunday => weekday.num_days_from_sunday(),
+        StartDayOfWeek::Monday => weekday.num_days_from_monday(),
+    }
 }
 
 #[cfg(test)]
 mod shared_components_work_when {
+    use regex::Regex;
+
     use crate::language::shared::*;
 
+    fn recognize_digits(
+        input: &str,
+        _date_format: &DateFormat,
+    ) -> Option<RecognizedDateExpression> {
+        let re = Regex::new(r"\d+").unwrap();
+        let found = re.find(input)?;
+
+        Some(RecognizedDateExpression {
+            expression: DateExpression::InXDays(found.as_str().parse().unwrap()),
+            range: found.range(),
+        })
+    }
+
+    #[test]
+    fn all_date_expressions_are_recognized_in_reading_order() {
+        let recognized = recognize_all_date_expressions(
+            "1 and 22 or 333",
+            &DateFormat::DayMonthYear,
+            recognize_digits,
+        );
+
+        assert_eq!(
+            recognized,
+            vec![
+                RecognizedDateExpression {
+                    expression: DateExpression::InXDays(1),
+                    range: 0..1
+                },
+                RecognizedDateExpression {
+                    expression: DateExpression::InXDays(22),
+                    range: 6..8
+                },
+                RecognizedDateExpression {
+                    expression: DateExpression::InXDays(333),
+                    range: 12..15
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn no_date_expression_is_recognized() {
+        assert_eq!(
+            recognize_all_date_expressions("nothing", &DateFormat::DayMonthYear, recognize_digits),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn days_which_do_not_exist_are_invalid() {
+        let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+
+        assert_eq!(
+            resolve_date_expression(
+                DateExpression::DayInMonth(Month::February, 30),
+                &now,
+                &StartDayOfWeek::Monday
+            ),
+            Err(ResolveError::InvalidDate)
+        );
+        assert_eq!(
+            resolve_date_expression(
+                DateExpression::DayInMonthInYear(Month::February, 29, 2026),
+                &now,
+                &StartDayOfWeek::Monday
+            ),
+            Err(ResolveError::InvalidDate)
+        );
+    }
+
+    #[test]
+    fn far_away_dates_overflow_instead_of_panicking() {
+        let now = NaiveDate::MAX;
+
+        assert_eq!(
+            resolve_date_expression(DateExpression::InXDays(1), &now, &StartDayOfWeek::Monday),
+            Err(ResolveError::Overflow)
+        );
+        assert_eq!(
+            resolve_date_expression(
+                DateExpression::InXWeeks(i32::MAX),
+                &now,
+                &StartDayOfWeek::Monday
+            ),
+            Err(ResolveError::Overflow)
+        );
+        assert_eq!(
+            resolve_date_expression(
+                DateExpression::DayInXWeeks(2, Weekday::Mon),
+                &now,
+                &StartDayOfWeek::Sunday
+            ),
+            Err(ResolveError::Overflow)
+        );
+    }
+
+    #[test]
+    fn negative_days_are_resolved() {
+        let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+
+        assert_eq!(
+            resolve_date_expression(DateExpression::InXDays(-3), &now, &StartDayOfWeek::Monday),
+            Ok(NaiveDate::from_ymd_opt(2026, 10, 15).unwrap())
+        );
+    }
+
+    /// Moves the `day` of every month in `year` by `months` and compares it with the `expected` dates.
+    fn assert_months(
+        (year, day): (i32, u32),
+        months: i32,
+        overflow: MonthOverflow,
+        expected: [(i32, u32, u32); 12],
+    ) {
+        for (month, expected) in (1..=12).zip(expected) {
+            // start on the last day of the month, when the day doesn't exist in it
+            let now = (0..day)
+                .find_map(|offset| NaiveDate::from_ymd_opt(year, month, day - offset))
+                .unwrap();
+            let expected = NaiveDate::from_ymd_opt(expected.0, expected.1, expected.2).unwrap();
+
+            assert_eq!(
+                resolve_in_x_months(&now, months, &overflow),
+                Ok(expected),
+                "failed to move {} by {} months",
+                now,
+                months
+            );
+        }
+    }
+
+    #[test]
+    fn months_are_added_in_every_month() {
+        assert_months(
+            (2026, 15),
+            1,
+            MonthOverflow::Clamp,
+            [
+                (2026, 2, 15),
+                (2026, 3, 15),
+                (2026, 4, 15),
+                (2026, 5, 15),
+                (2026, 6, 15),
+                (2026, 7, 15),
+                (2026, 8, 15),
+                (2026, 9, 15),
+                (2026, 10, 15),
+                (2026, 11, 15),
+                (2026, 12, 15),
+                (2027, 1, 15),
+            ],
+        );
+        assert_months(
+            (2026, 15),
+            3,
+            MonthOverflow::Clamp,
+            [
+                (2026, 4, 15),
+                (2026, 5, 15),
+                (2026, 6, 15),
+                (2026, 7, 15),
+                (2026, 8, 15),
+                (2026, 9, 15),
+                (2026, 10, 15),
+                (2026, 11, 15),
+                (2026, 12, 15),
+                (2027, 1, 15),
+                (2027, 2, 15),
+                (2027, 3, 15),
+            ],
+        );
+    }
+
+    #[test]
+    fn months_are_subtracted_in_every_month() {
+        assert_months(
+            (2026, 15),
+            -1,
+            MonthOverflow::Clamp,
+            [
+                (2025, 12, 15),
+                (2026, 1, 15),
+                (2026, 2, 15),
+                (2026, 3, 15),
+                (2026, 4, 15),
+                (2026, 5, 15),
+                (2026, 6, 15),
+                (2026, 7, 15),
+                (2026, 8, 15),
+                (2026, 9, 15),
+                (2026, 10, 15),
+                (2026, 11, 15),
+            ],
+        );
+        assert_months(
+            (2026, 31),
+            -1,
+            MonthOverflow::Clamp,
+            [
+                (2025, 12, 31),
+                (2026, 1, 28),
+                (2026, 2, 28),
+                (2026, 3, 30),
+                (2026, 4, 30),
+                (2026, 5, 30),
+                (2026, 6, 30),
+                (2026, 7, 31),
+                (2026, 8, 30),
+                (2026, 9, 30),
+                (2026, 10, 30),
+                (2026, 11, 30),
+            ],
+        );
+    }
+
+    #[test]
+    fn months_are_clamped_to_the_last_day() {
+        assert_months(
+            (2026, 31),
+            1,
+            MonthOverflow::Clamp,
+            [
+                (2026, 2, 28),
+                (2026, 3, 28),
+                (2026, 4, 30),
+                (2026, 5, 30),
+                (2026, 6, 30),
+                (2026, 7, 30),
+                (2026, 8, 31),
+                (2026, 9, 30),
+                (2026, 10, 30),
+                (2026, 11, 30),
+                (2026, 12, 30),
+                (2027, 1, 31),
+            ],
+        );
+        assert_months(
+            (2027, 31),
+            1,
+            MonthOverflow::Clamp,
+            [
+                (2027, 2, 28),
+                (2027, 3, 28),
+                (2027, 4, 30),
+                (2027, 5, 30),
+                (2027, 6, 30),
+                (2027, 7, 30),
+                (2027, 8, 31),
+                (2027, 9, 30),
+                (2027, 10, 30),
+                (2027, 11, 30),
+                (2027, 12, 30),
+                (2028, 1, 31),
+            ],
+        );
+        // 2028 is a leap year
+        assert_eq!(
+            resolve_in_x_months(
+                &NaiveDate::from_ymd_opt(2028, 1, 31).unwrap(),
+                1,
+                &MonthOverflow::Clamp
+            ),
+            Ok(NaiveDate::from_ymd_opt(2028, 2, 29).unwrap())
+        );
+    }
+
+    #[test]
+    fn months_overflow_into_the_next_month() {
+        assert_months(
+            (2026, 31),
+            1,
+            MonthOverflow::NextMonth,
+            [
+                (2026, 3, 3),
+                (2026, 3, 28),
+                (2026, 5, 1),
+                (2026, 5, 30),
+                (2026, 7, 1),
+                (2026, 7, 30),
+                (2026, 8, 31),
+                (2026, 10, 1),
+                (2026, 10, 30),
+                (2026, 12, 1),
+                (2026, 12, 30),
+                (2027, 1, 31),
+            ],
+        );
+        assert_months(
+            (2026, 15),
+            1,
+            MonthOverflow::NextMonth,
+            [
+                (2026, 2, 15),
+                (2026, 3, 15),
+                (2026, 4, 15),
+                (2026, 5, 15),
+                (2026, 6, 15),
+                (2026, 7, 15),
+                (2026, 8, 15),
+                (2026, 9, 15),
+                (2026, 10, 15),
+                (2026, 11, 15),
+                (2026, 12, 15),
+                (2027, 1, 15),
+            ],
+        );
+    }
+
+    #[test]
+    fn months_are_resolved_across_years() {
+        let now = NaiveDate::from_ymd_opt(2026, 11, 30).unwrap();
+
+        assert_eq!(
+            resolve_date_expression(DateExpression::InXMonths(3), &now, &StartDayOfWeek::Monday),
+            Ok(NaiveDate::from_ymd_opt(2027, 2, 28).unwrap())
+        );
+        assert_eq!(
+            resolve_date_expression(
+                DateExpression::InXMonths(-23),
+                &now,
+                &StartDayOfWeek::Monday
+            ),
+            Ok(NaiveDate::from_ymd_opt(2024, 12, 30).unwrap())
+        );
+        assert_eq!(
+            resolve_in_x_months(&NaiveDate::MAX, 1, &MonthOverflow::NextMonth),
+            Err(ResolveError::Overflow)
+        );
+    }
+
+    #[test]
+    fn years_are_resolved() {
+        let now = NaiveDate::from_ymd_opt(2028, 2, 29).unwrap();
+        let resolve =
+            |expression| resolve_date_expression(expression, &now, &StartDayOfWeek::Monday);
+
+        assert_eq!(
+            resolve(DateExpression::InXYears(1)),
+            Ok(NaiveDate::from_ymd_opt(2029, 2, 28).unwrap())
+        );
+        assert_eq!(
+            resolve(DateExpression::InXYears(-4)),
+            Ok(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())
+        );
+        assert_eq!(
+            resolve(DateExpression::MonthInXYears(Month::December, 1)),
+            Ok(NaiveDate::from_ymd_opt(2029, 12, 1).unwrap())
+        );
+        assert_eq!(
+            resolve(DateExpression::DayInMonthInXYears(Month::June, 8, -1)),
+            Ok(NaiveDate::from_ymd_opt(2027, 6, 8).unwrap())
+        );
+        assert_eq!(
+            resolve(DateExpression::DayInMonthInXYears(Month::February, 29, 1)),
+            Err(ResolveError::InvalidDate)
+        );
+        assert_eq!(
+            resolve(DateExpression::InXYears(i32::MAX)),
+            Err(ResolveError::Overflow)
+        );
+    }
+
+    #[test]
+    fn the_next_day_of_a_month_is_resolved() {
+        let resolve = |day, now: NaiveDate| {
+            resolve_date_expression(
+                DateExpression::NextDayOfMonth(day),
+                &now,
+                &StartDayOfWeek::Monday,
+            )
+        };
+        let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+
+        assert_eq!(
+            resolve(22, now),
+            Ok(NaiveDate::from_ymd_opt(2026, 10, 22).unwrap())
+        );
+        assert_eq!(resolve(18, now), Ok(now));
+        assert_eq!(
+            resolve(5, now),
+            Ok(NaiveDate::from_ymd_opt(2026, 11, 5).unwrap())
+        );
+        assert_eq!(
+            resolve(31, now),
+            Ok(NaiveDate::from_ymd_opt(2026, 10, 31).unwrap())
+        );
+        assert_eq!(
+            resolve(31, NaiveDate::from_ymd_opt(2026, 11, 1).unwrap()),
+            Ok(NaiveDate::from_ymd_opt(2026, 12, 31).unwrap())
+        );
+        assert_eq!(
+            resolve(30, NaiveDate::from_ymd_opt(2026, 12, 31).unwrap()),
+            Ok(NaiveDate::from_ymd_opt(2027, 1, 30).unwrap())
+        );
+        assert_eq!(
+            resolve(29, NaiveDate::from_ymd_opt(2027, 1, 30).unwrap()),
+            Ok(NaiveDate::from_ymd_opt(2027, 3, 29).unwrap())
+        );
+        assert_eq!(resolve(32, now), Err(ResolveError::InvalidDate));
+    }
+
+    #[test]
+    fn the_next_month_of_a_year_is_resolved() {
+        let resolve = |month| {
+            resolve_date_expression(
+                DateExpression::NextMonthOfYear(month),
+                &NaiveDate::from_ymd_opt(2026, 10, 18).unwrap(),
+                &StartDayOfWeek::Monday,
+            )
+        };
+
+        assert_eq!(
+            resolve(Month::December),
+            Ok(NaiveDate::from_ymd_opt(2026, 12, 1).unwrap())
+        );
+        assert_eq!(
+            resolve(Month::October),
+            Ok(NaiveDate::from_ymd_opt(2026, 10, 1).unwrap())
+        );
+        assert_eq!(
+            resolve(Month::March),
+            Ok(NaiveDate::from_ymd_opt(2027, 3, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn two_digit_years_are_resolved_inside_the_window() {
+        let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let resolve =
+            |year, years_ahead| resolve_two_digit_year(&now, year, &PivotWindow { years_ahead });
+
+        assert_eq!(resolve(26, 50), Ok(2026));
+        assert_eq!(resolve(0, 50), Ok(2000));
+        assert_eq!(resolve(76, 50), Ok(2076));
+        assert_eq!(resolve(77, 50), Ok(1977));
+        assert_eq!(resolve(99, 50), Ok(1999));
+        assert_eq!(resolve(26, 0), Ok(2026));
+        assert_eq!(resolve(27, 0), Ok(1927));
+        assert_eq!(resolve(25, 99), Ok(2125));
+        assert_eq!(resolve(25, 500), Ok(2125));
+
+        assert_eq!(
+            resolve_date_expression(
+                DateExpression::DayInMonthInTwoDigitYear(Month::December, 5, 26),
+                &now,
+                &StartDayOfWeek::Monday
+            ),
+            Ok(NaiveDate::from_ymd_opt(2026, 12, 5).unwrap())
+        );
+        assert_eq!(
+            resolve_date_expression(
+                DateExpression::InMonthInTwoDigitYear(Month::March, 98),
+                &now,
+                &StartDayOfWeek::Monday
+            ),
+            Ok(NaiveDate::from_ymd_opt(1998, 3, 1).unwrap())
+        );
+    }
+
+    #[test]
+    fn iso_week_and_ordinal_dates_are_resolved() {
+        let resolve = |expression| {
+            resolve_date_expression(
+                expression,
+                &NaiveDate::from_ymd_opt(2026, 10, 18).unwrap(),
+                &StartDayOfWeek::Sunday,
+            )
+        };
+
+        assert_eq!(
+            resolve(DateExpression::DayInIsoWeek(2026, 49, Weekday::Wed)),
+            Ok(NaiveDate::from_ymd_opt(2026, 12, 2).unwrap())
+        );
+        assert_eq!(
+            resolve(DateExpression::DayInIsoWeek(2026, 1, Weekday::Mon)),
+            Ok(NaiveDate::from_ymd_opt(2025, 12, 29).unwrap())
+        );
+        assert_eq!(
+            resolve(DateExpression::DayInIsoWeek(2025, 53, Weekday::Mon)),
+            Err(ResolveError::InvalidDate)
+        );
+        assert_eq!(
+            resolve(DateExpression::DayInYear(2026, 339)),
+            Ok(NaiveDate::from_ymd_opt(2026, 12, 5).unwrap())
+        );
+        assert_eq!(
+            resolve(DateExpression::DayInYear(2028, 366)),
+            Ok(NaiveDate::from_ymd_opt(2028, 12, 31).unwrap())
+        );
+        assert_eq!(
+            resolve(DateExpression::DayInYear(2026, 366)),
+            Err(ResolveError::InvalidDate)
+        );
+    }
+
     #[test]
     fn some_number_is_parsed_to_month() {
         assert_eq!(num_to_month(1), Some(Month::January));
@@ -81,4 +1228,157 @@ mod shared_components_work_when {
         assert_eq!(num_to_month(12), Some(Month::December));
         assert_eq!(num_to_month(123), None);
     }
+
+    #[test]
+    fn daily_recurrences_occur() {
+        let recurrence = Recurrence {
+            frequency: Frequency::Daily,
+            interval: 3,
+            weekdays: vec![],
+            month_day: None,
+        };
+
+        assert_occurrences(
+            &recurrence,
+            (2024, 12, 30),
+            &StartDayOfWeek::Monday,
+            vec![(2024, 12, 30), (2025, 1, 2), (2025, 1, 5)],
+        );
+    }
+
+    #[test]
+    fn weekly_recurrences_occur() {
+        // the fourth of december 2024 was a wednesday
+        let recurrence = Recurrence {
+            frequency: Frequency::Weekly,
+            interval: 2,
+            weekdays: vec![Weekday::Thu, Weekday::Mon],
+            month_day: None,
+        };
+
+        assert_occurrences(
+            &recurrence,
+            (2024, 12, 4),
+            &StartDayOfWeek::Monday,
+            vec![
+                (2024, 12, 5),
+                (2024, 12, 16),
+                (2024, 12, 19),
+                (2024, 12, 30),
+            ],
+        );
+
+        let recurrence = Recurrence {
+            frequency: Frequency::Weekly,
+            interval: 2,
+            weekdays: vec![Weekday::Sun],
+            month_day: None,
+        };
+
+        // the week of the first day decides, which weeks are the other weeks
+        assert_occurrences(
+            &recurrence,
+            (2024, 12, 4),
+            &StartDayOfWeek::Monday,
+            vec![(2024, 12, 8), (2024, 12, 22)],
+        );
+        assert_occurrences(
+            &recurrence,
+            (2024, 12, 4),
+            &StartDayOfWeek::Sunday,
+            vec![(2024, 12, 15), (2024, 12, 29)],
+        );
+    }
+
+    #[test]
+    fn weekly_recurrences_without_weekdays_use_the_first_day() {
+        let recurrence = Recurrence {
+            frequency: Frequency::Weekly,
+            interval: 1,
+            weekdays: vec![],
+            month_day: None,
+        };
+
+        assert_occurrences(
+            &recurrence,
+            (2024, 12, 4),
+            &StartDayOfWeek::Monday,
+            vec![(2024, 12, 4), (2024, 12, 11)],
+        );
+    }
+
+    #[test]
+    fn monthly_recurrences_skip_months_without_the_day() {
+        let recurrence = Recurrence {
+            frequency: Frequency::Monthly,
+            interval: 1,
+            weekdays: vec![],
+            month_day: Some(31),
+        };
+
+        assert_occurrences(
+            &recurrence,
+            (2025, 1, 15),
+            &StartDayOfWeek::Monday,
+            vec![(2025, 1, 31), (2025, 3, 31), (2025, 5, 31), (2025, 7, 31)],
+        );
+    }
+
+    #[test]
+    fn yearly_recurrences_skip_years_without_the_day() {
+        let recurrence = Recurrence {
+            frequency: Frequency::Yearly,
+            interval: 1,
+            weekdays: vec![],
+            month_day: None,
+        };
+
+        assert_occurrences(
+            &recurrence,
+            (2024, 2, 29),
+            &StartDayOfWeek::Monday,
+            vec![(2024, 2, 29), (2028, 2, 29)],
+        );
+    }
+
+    #[test]
+    fn recurrences_without_occurrences_end() {
+        let recurrence = Recurrence {
+            frequency: Frequency::Monthly,
+            interval: 12,
+            weekdays: vec![],
+            month_day: Some(30),
+        };
+        let from = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+
+        assert_eq!(
+            recurrence
+                .occurrences(&from, &StartDayOfWeek::Monday)
+                .next(),
+            None
+        );
+    }
+
+    fn assert_occurrences(
+        recurrence: &Recurrence,
+        from: (i32, u32, u32),
+        start_of_week: &StartDayOfWeek,
+        expected: Vec<(i32, u32, u32)>,
+    ) {
+        let from = NaiveDate::from_ymd_opt(from.0, from.1, from.2).unwrap();
+        let expected: Vec<NaiveDate> = expected
+            .into_iter()
+            .map(|(year, month, day)| NaiveDate::from_ymd_opt(year, month, day).unwrap())
+            .collect();
+
+        assert_eq!(
+            recurrence
+                .occurrences(&from, start_of_week)
+                .take(expected.len())
+                .collect::<Vec<NaiveDate>>(),
+            expected,
+            "Failed for {:?}",
+            recurrence
+        );
+    }
 }
@@ -0,0 +1,89 @@
+/// A number written with arabic digits, full-width digits or kanji numerals for regular expressions,
+/// like `3`, `３`, `三`, `两` or `二十一`.
+pub const CJK_NUMBER: &str = "[0-9０-９〇零一二两兩三四五六七八九十百千]+";
+
+/// Parses a number written with arabic digits, full-width digits or kanji numerals.
+///
+/// Kanji numerals are read with the multipliers `十`, `百` and `千` like `二十一`, or digit by digit like `二〇二六`.
+pub fn parse_cjk_number(input: &str) -> Option<i32> {
+    if input.is_empty() {
+        return None;
+    }
+
+    let mut total: i32 = 0;
+    let mut current: Option<i32> = None;
+
+    for c in input.chars() {
+        if let Some(digit) = cjk_digit(c) {
+            current = Some(match current {
+                Some(current) => current.checked_mul(10)?.checked_add(digit)?,
+                None => digit,
+            });
+        } else {
+            let multiplier = match c {
+                '十' => 10,
+                '百' => 100,
+                '千' => 1000,
+                _ => return None,
+            };
+
+            total = total.checked_add(current.unwrap_or(1).checked_mul(multiplier)?)?;
+            current = None;
+        }
+    }
+
+    total.checked_add(current.unwrap_or(0))
+}
+
+/// Returns the value of a single arabic, full-width or kanji digit.
+fn cjk_digit(c: char) -> Option<i32> {
+    let digit = match c {
+        '0'..='9' => c as u32 - '0' as u32,
+        '０'..='９' => c as u32 - '０' as u32,
+        '〇' | '零' => 0,
+        '一' => 1,
+        '二' | '两' | '兩' => 2,
+        '三' => 3,
+        '四' => 4,
+        '五' => 5,
+        '六' => 6,
+        '七' => 7,
+        '八' => 8,
+        '九' => 9,
+        _ => return None,
+    };
+
+    Some(digit as i32)
+}
+
+#[cfg(test)]
+mod parse_cjk_number_works_when {
+    use super::parse_cjk_number;
+
+    #[test]
+    fn digits_are_parsed() {
+        assert_eq!(parse_cjk_number("3"), Some(3));
+        assert_eq!(parse_cjk_number("2026"), Some(2026));
+        assert_eq!(parse_cjk_number("１２"), Some(12));
+    }
+
+    #[test]
+    fn kanji_numerals_are_parsed() {
+        assert_eq!(parse_cjk_number("三"), Some(3));
+        assert_eq!(parse_cjk_number("十"), Some(10));
+        assert_eq!(parse_cjk_number("十二"), Some(12));
+        assert_eq!(parse_cjk_number("二十一"), Some(21));
+        assert_eq!(parse_cjk_number("百二十"), Some(120));
+        assert_eq!(parse_cjk_number("千九百九十九"), Some(1999));
+        assert_eq!(parse_cjk_number("二〇二六"), Some(2026));
+        assert_eq!(parse_cjk_number("两"), Some(2));
+        assert_eq!(parse_cjk_number("兩百"), Some(200));
+    }
+
+    #[test]
+    fn no_number_is_found() {
+        assert_eq!(parse_cjk_number(""), None);
+        assert_eq!(parse_cjk_number("三つ"), None);
+        assert_eq!(parse_cjk_number("99999999999"), None);
+    }
+}
@@ -1,46 +1,98 @@
+use std::sync::LazyLock;
+
+use chrono::Weekday;
 use regex::{Captures, Regex};
 
-use super::shared::{num_to_month, DateExpression};
+use super::shared::{date_in_written_year, num_to_month, DateExpression, RecognizedDateExpression};
+
+// The numeric dates use ASCII word boundaries, so they are found next to scripts which don't
+// separate words by spaces, like the `に` in `2026/12/05に`.
+pub const DATE_MONTH_YEAR_PATTERN: &str = r"(?-u:\b)(?:(?P<day>0?[1-9]|[12][0-9]|3[01])[-./](?P<month>0?[1-9]|1[0-2])(?:[-./](?P<year>\d{4}|\d{2}))?|(?P<month2>0?[1-9]|1[0-2])[-./](?P<year2>\d{4}))(?-u:\b)";
+
+static DATE_MONTH_YEAR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(DATE_MONTH_YEAR_PATTERN).unwrap());
+
+pub const MONTH_DATE_YEAR_PATTERN: &str = r"(?-u:\b)(?:(?P<month>0?[1-9]|1[0-2])[-./](?P<day>0?[1-9]|[12][0-9]|3[01])(?:[-./](?P<year>\d{4}|\d{2}))?|(?P<month2>0?[1-9]|1[0-2])[-./](?P<year2>\d{4}))(?-u:\b)";
+
+static MONTH_DATE_YEAR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(MONTH_DATE_YEAR_PATTERN).unwrap());
+
+pub const YEAR_MONTH_DAY_PATTERN: &str = r"(?-u:\b)(?:(?P<year>\d{4})[-./](?P<month>0?[1-9]|1[0-2])(?:[-./](?P<day>0?[1-9]|[12][0-9]|3[01]))?|(?P<month2>0?[1-9]|1[0-2])[-./](?P<day2>0?[1-9]|[12][0-9]|3[01]))(?-u:\b)";
+
+static YEAR_MONTH_DAY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(YEAR_MONTH_DAY_PATTERN).unwrap());
+
+/// The unambiguous ISO 8601 calendar, week and ordinal dates, which may be followed by a time.
+pub const ISO_DATE_PATTERN: &str = r"(?-u:\b)(?P<date>(?P<year>\d{4})-(?:(?P<month>0[1-9]|1[0-2])-(?P<day>0[1-9]|[12][0-9]|3[01])|[Ww](?P<week>0[1-9]|[1-4][0-9]|5[0-3])(?:-(?P<weekday>[1-7]))?|(?P<ordinal>00[1-9]|0[1-9][0-9]|[12][0-9]{2}|3[0-5][0-9]|36[0-6])))(?:(?-u:\b)|T)";
 
-/// Parse date month year (dd.mm.yyyy) combinations out of strings.
+static ISO_DATE: LazyLock<Regex> = LazyLock::new(|| Regex::new(ISO_DATE_PATTERN).unwrap());
+
+/// Parse date month year (dd.mm.yyyy or dd.mm.yy) combinations out of strings.
 ///
 /// Furthermore, the function can parse dd.mm combinations from strings.
 /// To do this correclty, a current date is requred.
 /// Dates in the past, will be calcualted for the next year.
-pub fn parse_date_month_year(input: &str) -> Option<DateExpression> {
-    let regex = Regex::new(r"\b(?:(?P<day>0?[1-9]|[12][0-9]|3[01])[-./](?P<month>0?[1-9]|1[0-2])(?:[-./](?P<year>\d{4}))?|(?P<month2>0?[1-9]|1[0-2])[-./](?P<year2>\d{4}))\b").unwrap();
-
-    let Some(parts) = regex.captures(input) else {
-        return None;
-    };
+pub fn parse_date_month_year(input: &str) -> Option<RecognizedDateExpression> {
+    let parts = DATE_MONTH_YEAR.captures(input)?;
 
-    internal_parse_date(parts)
+    internal_parse_date(&parts)
 }
 
-/// Parse month date year (mm.dd.yyyy) combinations out of strings.
+/// Parse month date year (mm.dd.yyyy or mm.dd.yy) combinations out of strings.
 ///
 /// Furthermore, the function can parse dd.mm combinations from strings.
 /// To do this correclty, a current date is requred.
 /// Dates in the past, will be calcualted for the next year.
-pub fn parse_month_date_year(input: &str) -> Option<DateExpression> {
-    let regex = Regex::new(r"\b(?:(?P<month>0?[1-9]|1[0-2])[-./](?P<day>0?[1-9]|[12][0-9]|3[01])(?:[-./](?P<year>\d{4}))?|(?P<month2>0?[1-9]|1[0-2])[-./](?P<year2>\d{4}))\b").unwrap();
+pub fn parse_month_date_year(input: &str) -> Option<RecognizedDateExpression> {
+    let parts = MONTH_DATE_YEAR.captures(input)?;
 
-    let Some(parts) = regex.captures(input) else {
-        return None;
-    };
+    internal_parse_date(&parts)
+}
+
+/// Parse year month day (yyyy.mm.dd) combinations out of strings.
+///
+/// Furthermore, the function can parse yyyy.mm and mm.dd combinations from strings.
+pub fn parse_year_month_day(input: &str) -> Option<RecognizedDateExpression> {
+    let parts = YEAR_MONTH_DAY.captures(input)?;
 
-    internal_parse_date(parts)
+    internal_parse_date(&parts)
 }
 
-fn internal_parse_date(input: Captures) -> Option<DateExpression> {
-    let year = if input.name("year").is_some() {
-        Some(input["year"].parse::<i32>().unwrap())
-    } else if input.name("year2").is_some() {
-        Some(input["year2"].parse::<i32>().unwrap())
+/// Parse ISO 8601 dates like `2026-12-05`, `2026-W49-3` or `2026-339` out of strings.
+///
+/// These are recognized regardless of the date format, because they can't be mistaken.
+pub fn parse_iso_date(input: &str) -> Option<RecognizedDateExpression> {
+    let parts = ISO_DATE.captures(input)?;
+
+    let year = parts["year"].parse::<i32>().ok()?;
+
+    let expression = if let Some(week) = parts.name("week") {
+        let weekday = match parts.name("weekday") {
+            Some(weekday) => Weekday::try_from(weekday.as_str().parse::<u8>().ok()? - 1).ok()?,
+            None => Weekday::Mon,
+        };
+
+        DateExpression::DayInIsoWeek(year, week.as_str().parse().ok()?, weekday)
+    } else if let Some(ordinal) = parts.name("ordinal") {
+        DateExpression::DayInYear(year, ordinal.as_str().parse().ok()?)
     } else {
-        None
+        DateExpression::DayInMonthInYear(
+            num_to_month(parts["month"].parse().ok()?)?,
+            parts["day"].parse().ok()?,
+            year,
+        )
     };
 
+    // a following time like `T10:00` isn't part of the date
+    Some(RecognizedDateExpression {
+        expression,
+        range: parts.name("date")?.range(),
+    })
+}
+
+fn internal_parse_date(input: &Captures) -> Option<RecognizedDateExpression> {
+    let year = input.name("year").or(input.name("year2"));
+
     let month = if input.name("month").is_some() {
         Some(num_to_month(input["month"].parse::<u32>().unwrap()).unwrap())
     } else if input.name("month2").is_some() {
@@ -51,31 +103,30 @@ fn internal_parse_date(input: Captures) -> Option<DateExpression> {
 
     let day = if input.name("day").is_some() {
         Some(input["day"].parse::<u32>().unwrap())
+    } else if input.name("day2").is_some() {
+        Some(input["day2"].parse::<u32>().unwrap())
     } else {
         None
     };
 
-    if year.is_some() && month.is_some() && day.is_some() {
-        return Some(DateExpression::DayInMonthInYear(
-            month.unwrap(),
-            day.unwrap(),
-            year.unwrap(),
-        ));
-    } else if year.is_some() && month.is_some() {
-        return Some(DateExpression::InMonthInYear(month.unwrap(), year.unwrap()));
-    } else if month.is_some() && day.is_some() {
-        return Some(DateExpression::DayInMonth(month.unwrap(), day.unwrap()));
-    }
+    let expression = match (month, day, year) {
+        (Some(month), day, Some(year)) => date_in_written_year(month, day, year.as_str()),
+        (Some(month), Some(day), None) => Some(DateExpression::DayInMonth(month, day)),
+        _ => None,
+    }?;
 
-    None
+    Some(RecognizedDateExpression::from_captures(expression, input))
 }
 
 #[cfg(test)]
 mod shared_data_parser_works_when {
     use crate::language::{
         shared::{DateExpression, Month},
-        shared_date_parser::{parse_date_month_year, parse_month_date_year},
+        shared_date_parser::{
+            parse_date_month_year, parse_iso_date, parse_month_date_year, parse_year_month_day,
+        },
     };
+    use chrono::Weekday;
 
     #[test]
     fn none_is_returned_when_no_date_was_found() {
@@ -103,6 +154,20 @@ mod shared_data_parser_works_when {
         assert_month_date_year("Remind me on 12.05.2026", Some(test_date.clone()));
     }
 
+    #[test]
+    fn two_digit_years_are_detected_correctly() {
+        let test_date = DateExpression::DayInMonthInTwoDigitYear(Month::December, 5, 26);
+
+        assert_date_month_year("5.12.26", Some(test_date.clone()));
+        assert_date_month_year("Invoice from 05/12/26", Some(test_date.clone()));
+        assert_month_date_year("12/5/26", Some(test_date.clone()));
+        assert_month_date_year("Invoice from 12-05-26", Some(test_date.clone()));
+        assert_date_month_year(
+            "5.12.202",
+            Some(DateExpression::DayInMonth(Month::December, 5)),
+        );
+    }
+
     #[test]
     fn date_month_combinations_are_detected_correctly() {
         let test_date = DateExpression::DayInMonth(Month::December, 5);
@@ -123,9 +188,59 @@ mod shared_data_parser_works_when {
         assert_month_date_year("Remind me on 12.05", Some(test_date.clone()));
     }
 
+    #[test]
+    fn year_month_day_combinations_are_detected_correctly() {
+        let test_date = DateExpression::DayInMonthInYear(Month::December, 5, 2026);
+
+        assert_year_month_day("2026.12.5", Some(test_date.clone()));
+        assert_year_month_day("2026/12/05", Some(test_date.clone()));
+        assert_year_month_day("Remind me on 2026.12.05", Some(test_date.clone()));
+        assert_year_month_day(
+            "Remind me on 2026.12",
+            Some(DateExpression::InMonthInYear(Month::December, 2026)),
+        );
+        assert_year_month_day(
+            "Remind me on 12.5",
+            Some(DateExpression::DayInMonth(Month::December, 5)),
+        );
+        assert_year_month_day("2026/12/05に", Some(test_date.clone()));
+        assert_year_month_day("2026.13.05", None);
+    }
+
+    #[test]
+    fn iso_dates_are_detected_correctly() {
+        let test_date = DateExpression::DayInMonthInYear(Month::December, 5, 2026);
+
+        assert_iso_date("2026-12-05", Some(test_date.clone()));
+        assert_iso_date("Remind me on 2026-12-05", Some(test_date.clone()));
+        assert_iso_date("Remind me on 2026-12-05T10:00", Some(test_date.clone()));
+        assert_iso_date(
+            "2026-W49-3",
+            Some(DateExpression::DayInIsoWeek(2026, 49, Weekday::Wed)),
+        );
+        assert_iso_date(
+            "Remind me in 2026-W49",
+            Some(DateExpression::DayInIsoWeek(2026, 49, Weekday::Mon)),
+        );
+        assert_iso_date("2026-339", Some(DateExpression::DayInYear(2026, 339)));
+        assert_iso_date("会議は2026-12-05です", Some(test_date.clone()));
+        assert_iso_date("2026.12.05", None);
+        assert_iso_date("2026-12-5", None);
+        assert_iso_date("2026-W54", None);
+        assert_iso_date("2026-367", None);
+    }
+
+    #[test]
+    fn the_range_of_an_iso_date_excludes_the_time() {
+        assert_eq!(
+            parse_iso_date("on 2026-12-05T10:00").map(|recognized| recognized.range),
+            Some(3..13)
+        );
+    }
+
     fn assert_date_month_year(input: &str, expects: Option<DateExpression>) {
         assert_eq!(
-            parse_date_month_year(input),
+            parse_date_month_year(input).map(|recognized| recognized.expression),
             expects,
             "Failed for input: {:?} - {:?}",
             input,
@@ -135,7 +250,27 @@ mod shared_data_parser_works_when {
 
     fn assert_month_date_year(input: &str, expects: Option<DateExpression>) {
         assert_eq!(
-            parse_month_date_year(input),
+            parse_month_date_year(input).map(|recognized| recognized.expression),
+            expects,
+            "Failed for input: {:?} - {:?}",
+            input,
+            expects
+        )
+    }
+
+    fn assert_year_month_day(input: &str, expects: Option<DateExpression>) {
+        assert_eq!(
+            parse_year_month_day(input).map(|recognized| recognized.expression),
+            expects,
+            "Failed for input: {:?} - {:?}",
+            input,
+            expects
+        )
+    }
+
+    fn assert_iso_date(input: &str, expects: Option<DateExpression>) {
+        assert_eq!(
+            parse_iso_date(input).map(|recognized| recognized.expression),
             expects,
             "Failed for input: {:?} - {:?}",
             input,
@@ -0,0 +1,205 @@
+use std::ops::Range;
+
+/// Removes the text in `range` from `input` to get a clean title.
+///
+/// A dangling preposition of `prepositions` in front of the removed text and `leading_phrases` like
+/// `remind me to` are removed too. Words are compared case insensitive. Afterwards the whitespace is normalized.
+pub fn strip_date_phrase(
+    input: &str,
+    range: Range<usize>,
+    prepositions: &[&str],
+    leading_phrases: &[&str],
+) -> String {
+    let mut before = input[..range.start].trim_end();
+
+    if let Some(rest) = strip_last_word(before, prepositions) {
+        before = rest;
+    }
+
+    let title = format!("{} {}", before, &input[range.end..]);
+
+    normalize_whitespace(strip_leading_phrase(&title, leading_phrases))
+}
+
+/// Removes the text in `range` from `input` for scripts which don't separate words by spaces, like japanese.
+///
+/// A preposition of `prepositions` directly in front of the removed text, like `在` in `在明天`, a particle of
+/// `particles` directly behind it, like `に` in `明日に`, and a following comma are removed too.
+/// The rest is joined without a space, unless the removed text was surrounded by whitespace.
+pub fn strip_date_phrase_unspaced(
+    input: &str,
+    range: Range<usize>,
+    prepositions: &[&str],
+    particles: &[&str],
+) -> String {
+    let mut raw_before = &input[..range.start];
+    let mut after = &input[range.end..];
+
+    if let Some(preposition) = prepositions
+        .iter()
+        .find(|preposition| raw_before.ends_with(**preposition))
+    {
+        raw_before = &raw_before[..raw_before.len() - preposition.len()];
+    }
+
+    if let Some(particle) = particles
+        .iter()
+        .find(|particle| after.starts_with(**particle))
+    {
+        after = &after[particle.len()..];
+    }
+    after = after.trim_start_matches(['、', '，', ',']);
+
+    let spaced =
+        raw_before.ends_with(char::is_whitespace) && after.starts_with(char::is_whitespace);
+    let mut before = raw_before.trim_end();
+    let after = after.trim_start();
+
+    if after.is_empty() || after.starts_with(['。', '.']) {
+        before = before.trim_end_matches(['、', '，', ',']);
+    }
+
+    let separator = if spaced && !before.is_empty() && !after.is_empty() {
+        " "
+    } else {
+        ""
+    };
+
+    format!("{}{}{}", before, separator, after)
+        .trim()
+        .to_string()
+}
+
+/// Returns `input` without its last word, if the last word is one of `words`.
+fn strip_last_word<'a>(input: &'a str, words: &[&str]) -> Option<&'a str> {
+    let start = input
+        .rfind(|c: char| c.is_whitespace())
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    let last_word = &input[start..];
+
+    if words.iter().any(|word| eq_ignore_case(word, last_word)) {
+        return Some(input[..start].trim_end());
+    }
+
+    None
+}
+
+/// Returns `input` without the first matching phrase of `phrases` at its beginning.
+fn strip_leading_phrase<'a>(input: &'a str, phrases: &[&str]) -> &'a str {
+    let input = input.trim_start();
+
+    for phrase in phrases {
+        let Some(start) = input.get(..phrase.len()) else {
+            continue;
+        };
+        let rest = &input[phrase.len()..];
+
+        if eq_ignore_case(start, phrase)
+            && (rest.is_empty() || rest.starts_with(char::is_whitespace))
+        {
+            return rest;
+        }
+    }
+
+    input
+}
+
+/// Compares two words case insensitive, also outside of ASCII like `Напомни` and `напомни`.
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.to_lowercase() == b.to_lowercase()
+}
+
+/// Collapses all whitespace into single spaces and removes spaces in front of punctuation.
+fn normalize_whitespace(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+
+    for word in input.split_whitespace() {
+        let is_punctuation = word.chars().all(|c| ".,;:!?".contains(c));
+
+        if !output.is_empty() && !is_punctuation {
+            output.push(' ');
+        }
+        output.push_str(word);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod strip_date_phrase_works_when {
+    use super::{strip_date_phrase, strip_date_phrase_unspaced};
+
+    const PREPOSITIONS: [&str; 3] = ["on", "by", "in"];
+    const LEADING_PHRASES: [&str; 2] = ["remind me to", "remind me"];
+
+    #[test]
+    fn date_phrase_is_removed() {
+        assert_stripped("pay rent next friday", 9..20, "pay rent");
+        assert_stripped("next friday pay rent", 0..11, "pay rent");
+        assert_stripped("pay   next friday   rent", 6..17, "pay rent");
+    }
+
+    #[test]
+    fn dangling_prepositions_are_removed() {
+        assert_stripped("pay rent on 5.12", 12..16, "pay rent");
+        assert_stripped("pay rent By friday", 12..18, "pay rent");
+        assert_stripped("hand the code in on monday", 20..26, "hand the code in");
+    }
+
+    #[test]
+    fn leading_phrases_are_removed() {
+        assert_stripped("Remind me to pay rent next friday", 22..33, "pay rent");
+        assert_stripped("remind me tomorrow", 10..18, "");
+        assert_stripped("reminder tomorrow", 9..17, "reminder");
+    }
+
+    #[test]
+    fn words_are_compared_case_insensitive_outside_of_ascii() {
+        assert_eq!(
+            strip_date_phrase("Напомни заплатить В пятницу", 37..51, &["в"], &["напомни"]),
+            "заплатить"
+        );
+    }
+
+    #[test]
+    fn punctuation_is_kept_next_to_the_words() {
+        assert_stripped("pay rent tomorrow.", 9..17, "pay rent.");
+        assert_stripped("pay rent tomorrow, please", 9..17, "pay rent, please");
+    }
+
+    #[test]
+    fn unspaced_date_phrase_is_removed() {
+        const PARTICLES: [&str; 3] = ["までに", "に", "の"];
+
+        assert_eq!(
+            strip_date_phrase_unspaced("明日までにレポートを出す", 0..6, &[], &PARTICLES),
+            "レポートを出す"
+        );
+        assert_eq!(
+            strip_date_phrase_unspaced("牛乳を買う、明日。", 18..24, &[], &PARTICLES),
+            "牛乳を買う。"
+        );
+        assert_eq!(
+            strip_date_phrase_unspaced("レポートを明日出す", 15..21, &[], &PARTICLES),
+            "レポートを出す"
+        );
+        assert_eq!(
+            strip_date_phrase_unspaced("Call Bob 明日 please", 9..15, &[], &PARTICLES),
+            "Call Bob please"
+        );
+        assert_eq!(
+            strip_date_phrase_unspaced("我们在明天开会", 9..15, &["在"], &[]),
+            "我们开会"
+        );
+    }
+
+    fn assert_stripped(input: &str, range: std::ops::Range<usize>, expected: &str) {
+        assert_eq!(
+            strip_date_phrase(input, range, &PREPOSITIONS, &LEADING_PHRASES),
+            expected,
+            "Failed for input: {}",
+            input
+        )
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    DateFormat, DeDateParser, EnDateParser, EsDateParser, FrDateParser, JaDateParser, RuDateParser,
+    StartDayOfWeek, ZhDateParser,
+};
+
+/// The languages a `Parser` can parse.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Language {
+    English,
+    German,
+    French,
+    Spanish,
+    Japanese,
+    Chinese,
+    Russian,
+}
+
+impl Language {
+    /// The date format commonly used with the language.
+    pub fn date_format(&self) -> DateFormat {
+        match self {
+            Language::English => EnDateParser::DATE_FORMAT,
+            Language::German => DeDateParser::DATE_FORMAT,
+            Language::French => FrDateParser::DATE_FORMAT,
+            Language::Spanish => EsDateParser::DATE_FORMAT,
+            Language::Japanese => JaDateParser::DATE_FORMAT,
+            Language::Chinese => ZhDateParser::DATE_FORMAT,
+            Language::Russian => RuDateParser::DATE_FORMAT,
+        }
+    }
+
+    /// The start of the week commonly used with the language.
+    pub fn start_of_week(&self) -> StartDayOfWeek {
+        match self {
+            Language::English => EnDateParser::START_DAY_OF_WEEK,
+            Language::German => DeDateParser::START_DAY_OF_WEEK,
+            Language::French => FrDateParser::START_DAY_OF_WEEK,
+            Language::Spanish => EsDateParser::START_DAY_OF_WEEK,
+            Language::Japanese => JaDateParser::START_DAY_OF_WEEK,
+            Language::Chinese => ZhDateParser::START_DAY_OF_WEEK,
+            Language::Russian => RuDateParser::START_DAY_OF_WEEK,
+        }
+    }
+}
+
+/// Supported values are the language codes `en`, `de`, `fr`, `es`, `ja`, `zh` and `ru`.
+///
+/// ```
+/// use date_time_parser_multi_language::Language;
+//
+/// fn main() {
+///     assert_eq!(Language::from("en"), Language::English);
+///     assert_eq!(Language::from("de"), Language::German);
+///     assert_eq!(Language::from("fr"), Language::French);
+///     assert_eq!(Language::from("es"), Language::Spanish);
+///     assert_eq!(Language::from("ja"), Language::Japanese);
+///     assert_eq!(Language::from("zh"), Language::Chinese);
+///     assert_eq!(Language::from("ru"), Language::Russian);
+/// }
+/// ```
+impl From<&str> for Language {
+    fn from(input: &str) -> Language {
+        if input == "de" {
+            return Language::German;
+        }
+
+        if input == "fr" {
+            return Language::French;
+        }
+
+        if input == "es" {
+            return Language::Spanish;
+        }
+
+        if input == "ja" {
+            return Language::Japanese;
+        }
+
+        if input == "zh" {
+            return Language::Chinese;
+        }
+
+        if input == "ru" {
+            return Language::Russian;
+        }
+
+        Language::English
+    }
+}
+
+#[cfg(test)]
+mod from_str_works_for_language_when {
+    use super::Language;
+
+    #[test]
+    fn english_is_parsed_correctly() {
+        assert_eq!(Language::from("en"), Language::English)
+    }
+
+    #[test]
+    fn german_is_parsed_correctly() {
+        assert_eq!(Language::from("de"), Language::German)
+    }
+
+    #[test]
+    fn french_is_parsed_correctly() {
+        assert_eq!(Language::from("fr"), Language::French)
+    }
+
+    #[test]
+    fn spanish_is_parsed_correctly() {
+        assert_eq!(Language::from("es"), Language::Spanish)
+    }
+
+    #[test]
+    fn japanese_is_parsed_correctly() {
+        assert_eq!(Language::from("ja"), Language::Japanese)
+    }
+
+    #[test]
+    fn chinese_is_parsed_correctly() {
+        assert_eq!(Language::from("zh"), Language::Chinese)
+    }
+
+    #[test]
+    fn russian_is_parsed_correctly() {
+        assert_eq!(Language::from("ru"), Language::Russian)
+    }
+
+    #[test]
+    fn unknown_codes_fall_back_to_english() {
+        assert_eq!(Language::from("xx"), Language::English)
+    }
+}
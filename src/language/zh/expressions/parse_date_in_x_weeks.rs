@@ -0,0 +1,62 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    shared::{DateExpression, RecognizedDateExpression},
+    shared_cjk::{parse_cjk_number, CJK_NUMBER},
+    zh::zh_date_parser::{direction_to_sign, DIRECTIONS, WEEK_WORDS},
+};
+
+static IN_X_WEEKS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?P<num>{})(?:{}){}",
+        CJK_NUMBER, WEEK_WORDS, DIRECTIONS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::InXWeeks(i32)`,
+/// or a `DateExpression::InXDays(i32)` for weeks ago, which count back from today and not from the start of a week
+pub fn parse_in_x_weeks(text: &str) -> Option<RecognizedDateExpression> {
+    // 两周后, 兩週後, 三个星期以前
+
+    let caps = IN_X_WEEKS.captures(text)?;
+    let weeks = parse_cjk_number(&caps["num"])?;
+    let expression = match direction_to_sign(&caps["direction"])? {
+        1 => DateExpression::InXWeeks(weeks),
+        _ => DateExpression::InXDays(-7 * weeks),
+    };
+
+    Some(RecognizedDateExpression::from_captures(expression, &caps))
+}
+
+#[cfg(test)]
+mod parse_in_x_weeks_works_when {
+    use super::parse_in_x_weeks;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn weeks_are_parsed() {
+        assert_in_x_weeks("两周后", DateExpression::InXWeeks(2));
+        assert_in_x_weeks("兩週後", DateExpression::InXWeeks(2));
+        assert_in_x_weeks("3个星期之后", DateExpression::InXWeeks(3));
+        assert_in_x_weeks("一个礼拜以前", DateExpression::InXDays(-7));
+        assert_in_x_weeks("两周前", DateExpression::InXDays(-14));
+    }
+
+    #[test]
+    fn no_weeks_are_found() {
+        assert_eq!(parse_in_x_weeks("两周"), None);
+        assert_eq!(parse_in_x_weeks("几周后"), None);
+    }
+
+    fn assert_in_x_weeks(text: &str, expression: DateExpression) {
+        assert_eq!(
+            parse_in_x_weeks(text).map(|recognized| recognized.expression),
+            Some(expression),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
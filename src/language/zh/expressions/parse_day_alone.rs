@@ -0,0 +1,57 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    shared::{DateExpression, RecognizedDateExpression},
+    zh::zh_date_parser::{parse_weekday_chinese, WEEKDAYS, WEEKDAY_WORDS},
+};
+
+static DAY_ALONE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?:{})(?P<day>{})", WEEKDAY_WORDS, WEEKDAYS)).unwrap());
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(0, Weekday)`
+pub fn parse_day_alone(text: &str) -> Option<RecognizedDateExpression> {
+    // 星期五, 周六, 礼拜天
+
+    let caps = DAY_ALONE.captures(text)?;
+    let day = parse_weekday_chinese(&caps["day"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(0, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_alone_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_alone;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn weekdays_are_parsed() {
+        assert_day_alone("星期一", Weekday::Mon);
+        assert_day_alone("周五", Weekday::Fri);
+        assert_day_alone("週五", Weekday::Fri);
+        assert_day_alone("礼拜天", Weekday::Sun);
+        assert_day_alone("禮拜六", Weekday::Sat);
+        assert_day_alone("星期日见", Weekday::Sun);
+    }
+
+    #[test]
+    fn no_weekday_is_found() {
+        assert_eq!(parse_day_alone("星期"), None);
+        assert_eq!(parse_day_alone("周末"), None);
+    }
+
+    fn assert_day_alone(text: &str, day: Weekday) {
+        assert_eq!(
+            parse_day_alone(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(0, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
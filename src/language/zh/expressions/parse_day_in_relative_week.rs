@@ -0,0 +1,90 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    shared::{DateExpression, RecognizedDateExpression},
+    shared_cjk::{parse_cjk_number, CJK_NUMBER},
+    zh::zh_date_parser::{
+        direction_to_sign, parse_weekday_chinese, relative_prefix_to_num, DIRECTIONS,
+        RELATIVE_PREFIXES, WEEKDAYS, WEEKDAY_WORDS, WEEK_WORDS,
+    },
+};
+
+static DAY_IN_RELATIVE_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?:(?P<prefix>{prefixes})(?:{week})(?P<day>{weekdays})|(?P<num>{number})(?:{week}){directions}的?(?:{weekday_words})(?P<day2>{weekdays}))",
+        prefixes = RELATIVE_PREFIXES,
+        week = WEEK_WORDS,
+        weekdays = WEEKDAYS,
+        number = CJK_NUMBER,
+        directions = DIRECTIONS,
+        weekday_words = WEEKDAY_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::DayInXWeeks(i32, Weekday)`
+pub fn parse_day_in_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    // 下周一, 下個星期三, 上礼拜五, 两周后的周一
+
+    let caps = DAY_IN_RELATIVE_WEEK.captures(text)?;
+
+    let weeks = match caps.name("prefix") {
+        Some(prefix) => relative_prefix_to_num(prefix.as_str())?,
+        None => parse_cjk_number(&caps["num"])? * direction_to_sign(&caps["direction"])?,
+    };
+    let day = parse_weekday_chinese(caps.name("day").or(caps.name("day2"))?.as_str())?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::DayInXWeeks(weeks, day),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_day_in_relative_week_works_when {
+    use chrono::Weekday;
+
+    use super::parse_day_in_relative_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn days_in_relative_weeks_are_parsed() {
+        assert_day_in_x_weeks("下周一", 1, Weekday::Mon);
+        assert_day_in_x_weeks("下星期三", 1, Weekday::Wed);
+        assert_day_in_x_weeks("下个礼拜五", 1, Weekday::Fri);
+        assert_day_in_x_weeks("这周日", 0, Weekday::Sun);
+        assert_day_in_x_weeks("上星期天", -1, Weekday::Sun);
+        assert_day_in_x_weeks("下下周二", 2, Weekday::Tue);
+    }
+
+    #[test]
+    fn traditional_characters_are_parsed() {
+        assert_day_in_x_weeks("下週一", 1, Weekday::Mon);
+        assert_day_in_x_weeks("下個禮拜五", 1, Weekday::Fri);
+        assert_day_in_x_weeks("這週六", 0, Weekday::Sat);
+    }
+
+    #[test]
+    fn days_in_explicit_weeks_are_parsed() {
+        assert_day_in_x_weeks("两周后的周一", 2, Weekday::Mon);
+        assert_day_in_x_weeks("兩週後的星期三", 2, Weekday::Wed);
+        assert_day_in_x_weeks("一个星期以前的周五", -1, Weekday::Fri);
+    }
+
+    #[test]
+    fn no_day_is_found() {
+        assert_eq!(parse_day_in_relative_week("下周"), None);
+        assert_eq!(parse_day_in_relative_week("周一"), None);
+    }
+
+    fn assert_day_in_x_weeks(text: &str, weeks: i32, day: Weekday) {
+        assert_eq!(
+            parse_day_in_relative_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::DayInXWeeks(weeks, day)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    shared::{DateExpression, RecognizedDateExpression},
+    shared_cjk::{parse_cjk_number, CJK_NUMBER},
+    zh::zh_date_parser::{direction_to_sign, DIRECTIONS},
+};
+
+static IN_N_MONTHS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(?P<num>{})[个個]月{}", CJK_NUMBER, DIRECTIONS)).unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+pub fn parse_in_n_months(text: &str) -> Option<RecognizedDateExpression> {
+    // 两个月后, 兩個月後, 三个月前
+
+    let caps = IN_N_MONTHS.captures(text)?;
+    let months = parse_cjk_number(&caps["num"])? * direction_to_sign(&caps["direction"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXMonths(months),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_in_n_months_works_when {
+    use super::parse_in_n_months;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn months_are_parsed() {
+        assert_in_n_months("两个月后", 2);
+        assert_in_n_months("兩個月後", 2);
+        assert_in_n_months("6个月以后", 6);
+        assert_in_n_months("三个月前", -3);
+    }
+
+    #[test]
+    fn no_months_are_found() {
+        assert_eq!(parse_in_n_months("三月后"), None);
+        assert_eq!(parse_in_n_months("两个月"), None);
+    }
+
+    fn assert_in_n_months(text: &str, months: i32) {
+        assert_eq!(
+            parse_in_n_months(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXMonths(months)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    shared::{DateExpression, RecognizedDateExpression},
+    zh::zh_date_parser::normalize_chinese,
+};
+
+static KEYWORDS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"大[后後]天|[后後]天|明天|明日|今天|今日|大前天|前天|昨天|昨日").unwrap()
+});
+
+/// Parses common keywords into an `Option` containing a `DateExpr::InNDays(i32)`.
+pub fn parse_keywords(text: &str) -> Option<RecognizedDateExpression> {
+    // 今天, 明天, 后天, 後天, 昨天, 前天
+
+    let keyword = KEYWORDS.find(text)?;
+
+    let n = match normalize_chinese(keyword.as_str()).as_ref() {
+        "今天" | "今日" => 0,
+        "明天" | "明日" => 1,
+        "后天" => 2,
+        "大后天" => 3,
+        "昨天" | "昨日" => -1,
+        "前天" => -2,
+        "大前天" => -3,
+        _ => return None,
+    };
+
+    Some(RecognizedDateExpression {
+        expression: DateExpression::InXDays(n),
+        range: keyword.range(),
+    })
+}
+
+#[cfg(test)]
+mod parse_keywords_works_when {
+    use crate::language::{
+        shared::DateExpression, zh::expressions::parse_keywords::parse_keywords,
+    };
+
+    #[test]
+    fn day_keywords() {
+        assert_in_n_days("今天", 0);
+        assert_in_n_days("明天", 1);
+        assert_in_n_days("后天", 2);
+        assert_in_n_days("大后天", 3);
+        assert_in_n_days("昨天", -1);
+        assert_in_n_days("前天", -2);
+        assert_in_n_days("大前天", -3);
+    }
+
+    #[test]
+    fn traditional_keywords() {
+        assert_in_n_days("後天", 2);
+        assert_in_n_days("大後天", 3);
+        assert_in_n_days("明日", 1);
+    }
+
+    #[test]
+    fn keywords_are_found_without_spaces() {
+        assert_in_n_days("我们明天见", 1);
+        assert_in_n_days("他前天来了", -2);
+    }
+
+    #[test]
+    fn no_keyword_is_found() {
+        assert_eq!(parse_keywords("星期天"), None);
+        assert_eq!(parse_keywords("三天前"), None);
+    }
+
+    fn assert_in_n_days(text: &str, days: i32) {
+        assert_eq!(
+            parse_keywords(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXDays(days)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    shared::{DateExpression, RecognizedDateExpression},
+    zh::zh_date_parser::{relative_prefix_to_num, RELATIVE_PREFIXES, WEEK_WORDS},
+};
+
+static KEYWORD_RELATIVE_WEEK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?P<prefix>{})(?:{})",
+        RELATIVE_PREFIXES, WEEK_WORDS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpression::InXWeeks(i32)`
+pub fn parse_keyword_relative_week(text: &str) -> Option<RecognizedDateExpression> {
+    // 下周, 这个星期, 上礼拜, 下下週
+
+    let caps = KEYWORD_RELATIVE_WEEK.captures(text)?;
+    let weeks = relative_prefix_to_num(&caps["prefix"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXWeeks(weeks),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_keyword_relative_week_works_when {
+    use super::parse_keyword_relative_week;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn relative_weeks_are_parsed() {
+        assert_in_x_weeks("下周", 1);
+        assert_in_x_weeks("下週", 1);
+        assert_in_x_weeks("下个星期", 1);
+        assert_in_x_weeks("这个礼拜", 0);
+        assert_in_x_weeks("本周", 0);
+        assert_in_x_weeks("上星期", -1);
+        assert_in_x_weeks("上上周", -2);
+    }
+
+    #[test]
+    fn no_relative_week_is_found() {
+        assert_eq!(parse_keyword_relative_week("每周"), None);
+        assert_eq!(parse_keyword_relative_week("两周后"), None);
+    }
+
+    fn assert_in_x_weeks(text: &str, weeks: i32) {
+        assert_eq!(
+            parse_keyword_relative_week(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXWeeks(weeks)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
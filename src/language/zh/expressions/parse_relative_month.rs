@@ -0,0 +1,55 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::language::{
+    shared::{DateExpression, RecognizedDateExpression},
+    zh::zh_date_parser::{relative_prefix_to_num, RELATIVE_PREFIXES},
+};
+
+static RELATIVE_MONTH: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"(?P<prefix>{})[个個]?月", RELATIVE_PREFIXES)).unwrap());
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InNMonths(i32)`
+pub fn parse_relative_month(text: &str) -> Option<RecognizedDateExpression> {
+    // 下个月, 下個月, 本月, 这个月, 上个月
+
+    let caps = RELATIVE_MONTH.captures(text)?;
+    let months = relative_prefix_to_num(&caps["prefix"])?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXMonths(months),
+        &caps,
+    ))
+}
+
+#[cfg(test)]
+mod parse_relative_month_works_when {
+    use super::parse_relative_month;
+    use crate::language::shared::DateExpression;
+
+    #[test]
+    fn relative_months_are_parsed() {
+        assert_in_x_months("下个月", 1);
+        assert_in_x_months("下個月", 1);
+        assert_in_x_months("本月", 0);
+        assert_in_x_months("这个月", 0);
+        assert_in_x_months("上个月", -1);
+        assert_in_x_months("上上个月", -2);
+    }
+
+    #[test]
+    fn no_relative_month_is_found() {
+        assert_eq!(parse_relative_month("每个月"), None);
+        assert_eq!(parse_relative_month("12月"), None);
+    }
+
+    fn assert_in_x_months(text: &str, months: i32) {
+        assert_eq!(
+            parse_relative_month(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXMonths(months)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
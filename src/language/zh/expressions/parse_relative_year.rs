@@ -0,0 +1,160 @@
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+
+use crate::language::{
+    shared::{num_to_month, DateExpression, RecognizedDateExpression},
+    shared_cjk::{parse_cjk_number, CJK_NUMBER},
+    zh::zh_date_parser::{direction_to_sign, normalize_chinese, DIRECTIONS},
+};
+
+static RELATIVE_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?P<key>今年|明年|大[后後]年|[后後]年|去年|大前年|前年)|(?P<num>{})年{}",
+        CJK_NUMBER, DIRECTIONS
+    ))
+    .unwrap()
+});
+
+static MONTH_IN_RELATIVE_YEAR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?:(?P<key>今年|明年|大[后後]年|[后後]年|去年|大前年|前年)|(?P<num>{number})年{directions})的?(?P<month>{number})月(?:(?P<day>{number})[日号號])?",
+        number = CJK_NUMBER,
+        directions = DIRECTIONS
+    ))
+    .unwrap()
+});
+
+/// Parses a `str` into an `Option` containing a `DateExpr::InXYears(i32)`
+pub fn parse_relative_year(text: &str) -> Option<RecognizedDateExpression> {
+    // 明年, 今年, 去年, 後年, 三年后
+
+    let caps = RELATIVE_YEAR.captures(text)?;
+
+    Some(RecognizedDateExpression::from_captures(
+        DateExpression::InXYears(years(&caps)?),
+        &caps,
+    ))
+}
+
+/// Parses a `str` into an `Option` containing a `DateExpr::MonthInXYears(Month, i32)`
+/// or a `DateExpr::DayInMonthInXYears(Month, u32, i32)`.
+pub fn parse_month_in_relative_year(text: &str) -> Option<RecognizedDateExpression> {
+    // 明年12月5日, 明年12月, 後年三月一號, 两年后的4月
+
+    MONTH_IN_RELATIVE_YEAR.captures_iter(text).find_map(|caps| {
+        let month = num_to_month(parse_cjk_number(&caps["month"])?.try_into().ok()?)?;
+        let years = years(&caps)?;
+
+        let expression = match caps.name("day") {
+            Some(day) => DateExpression::DayInMonthInXYears(
+                month,
+                parse_cjk_number(day.as_str())?.try_into().ok()?,
+                years,
+            ),
+            None => DateExpression::MonthInXYears(month, years),
+        };
+
+        Some(RecognizedDateExpression::from_captures(expression, &caps))
+    })
+}
+
+/// Returns the years of a keyword like `明年` or of a time span like `三年后`.
+fn years(caps: &Captures) -> Option<i32> {
+    let Some(key) = caps.name("key") else {
+        return parse_cjk_number(&caps["num"])?.checked_mul(direction_to_sign(&caps["direction"])?);
+    };
+
+    match normalize_chinese(key.as_str()).as_ref() {
+        "今年" => Some(0),
+        "明年" => Some(1),
+        "后年" => Some(2),
+        "大后年" => Some(3),
+        "去年" => Some(-1),
+        "前年" => Some(-2),
+        "大前年" => Some(-3),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod parse_relative_year_works_when {
+    use super::{parse_month_in_relative_year, parse_relative_year};
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn relative_years_are_parsed() {
+        assert_in_x_years("明年", 1);
+        assert_in_x_years("今年", 0);
+        assert_in_x_years("去年", -1);
+        assert_in_x_years("前年", -2);
+        assert_in_x_years("后年", 2);
+        assert_in_x_years("後年", 2);
+        assert_in_x_years("三年后", 3);
+        assert_in_x_years("两年以前", -2);
+    }
+
+    #[test]
+    fn no_relative_year_is_found() {
+        assert_eq!(parse_relative_year("每年"), None);
+        assert_eq!(parse_relative_year("2026年"), None);
+    }
+
+    #[test]
+    fn months_in_relative_years_are_parsed() {
+        for (text, expected) in [
+            (
+                "明年12月5日",
+                DateExpression::DayInMonthInXYears(Month::December, 5, 1),
+            ),
+            (
+                "明年12月",
+                DateExpression::MonthInXYears(Month::December, 1),
+            ),
+            (
+                "明年十二月五号",
+                DateExpression::DayInMonthInXYears(Month::December, 5, 1),
+            ),
+            (
+                "後年三月一號",
+                DateExpression::DayInMonthInXYears(Month::March, 1, 2),
+            ),
+            ("去年的五月", DateExpression::MonthInXYears(Month::May, -1)),
+            (
+                "兩年後的4月",
+                DateExpression::MonthInXYears(Month::April, 2),
+            ),
+        ] {
+            assert_eq!(
+                parse_month_in_relative_year(text).map(|recognized| recognized.expression),
+                Some(expected),
+                "Failed for input: {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn range_of_month_in_relative_year_covers_the_year() {
+        let text = "我们明年12月去旅行";
+        let recognized = parse_month_in_relative_year(text).unwrap();
+
+        assert_eq!(&text[recognized.range], "明年12月");
+    }
+
+    #[test]
+    fn no_month_in_relative_year_is_found() {
+        assert_eq!(parse_month_in_relative_year("明年"), None);
+        assert_eq!(parse_month_in_relative_year("12月5日"), None);
+        assert_eq!(parse_month_in_relative_year("明年13月"), None);
+    }
+
+    fn assert_in_x_years(text: &str, years: i32) {
+        assert_eq!(
+            parse_relative_year(text).map(|recognized| recognized.expression),
+            Some(DateExpression::InXYears(years)),
+            "Failed for input: {}",
+            text
+        )
+    }
+}
@@ -0,0 +1,2 @@
+mod expressions;
+pub mod zh_date_parser;
@@ -0,0 +1,320 @@
+use std::ops::Range;
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::{
+    date_match::{DateMatch, StrippedDateExpression},
+    date_parser::DateParser,
+    language::{
+        shared::{recognize_all_date_expressions, RecognizedDateExpression},
+        shared_date_parser::{
+            parse_date_month_year, parse_iso_date, parse_month_date_year, parse_year_month_day,
+        },
+        shared_title::strip_date_phrase_unspaced,
+    },
+    parse_error::ParseError,
+    DateFormat, StartDayOfWeek,
+};
+
+use super::expressions::{
+    parse_date_in_x_weeks::parse_in_x_weeks,
+    parse_day_alone::parse_day_alone,
+    parse_day_in_relative_week::parse_day_in_relative_week,
+    parse_in_n_months::parse_in_n_months,
+    parse_keywords::parse_keywords,
+    parse_month_date::parse_month_date,
+    parse_relative_date::parse_relative_day,
+    parse_relative_keyword_week::parse_keyword_relative_week,
+    parse_relative_month::parse_relative_month,
+    parse_relative_year::{parse_month_in_relative_year, parse_relative_year},
+};
+
+/// The words in front of a weekday for regular expressions, like `星期` in `星期一` or `周` in `周五`.
+pub const WEEKDAY_WORDS: &str = "星期|礼拜|禮拜|周|週";
+
+/// The weekdays behind a `WEEKDAY_WORDS` for regular expressions, like `一` in `星期一` or `天` in `星期天`.
+pub const WEEKDAYS: &str = "[一二三四五六日天]";
+
+/// The words for a week for regular expressions, like `周` in `下周` or `个星期` in `两个星期后`.
+pub const WEEK_WORDS: &str = "(?:个|個)?(?:星期|礼拜|禮拜)|周|週";
+
+/// The prefixes of a relative week or month, like `下` in `下周` or `上` in `上个月`.
+pub const RELATIVE_PREFIXES: &str = "上上|上|这|這|本|下下|下";
+
+/// The words behind a time span which make it relative to today, like `后` in `三天后` or `以前` in `两周以前`.
+pub const DIRECTIONS: &str = "(?:之|以)?(?P<direction>后|後|前)";
+
+/// Maps the traditional characters used in date expressions to their simplified counterparts,
+/// so `後天` and `后天` or `這週` and `这周` are looked up the same way.
+///
+/// Only the looked up words are normalized, the input stays untouched to keep the ranges valid.
+pub fn normalize_chinese(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '後' => '后',
+            '週' => '周',
+            '個' => '个',
+            '這' => '这',
+            '禮' => '礼',
+            '兩' => '两',
+            '號' => '号',
+            c => c,
+        })
+        .collect()
+}
+
+/// Parses the character of a chinese weekday behind `星期`, `礼拜` or `周`, like `一` or `天`.
+pub fn parse_weekday_chinese(text: &str) -> Option<Weekday> {
+    match text {
+        "一" => Some(Weekday::Mon),
+        "二" => Some(Weekday::Tue),
+        "三" => Some(Weekday::Wed),
+        "四" => Some(Weekday::Thu),
+        "五" => Some(Weekday::Fri),
+        "六" => Some(Weekday::Sat),
+        "日" | "天" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Returns the relative week or month of a prefix.
+///
+/// e.g. `下` => 1, `下下` => 2, `这` => 0, `上` => -1, `上上` => -2
+pub fn relative_prefix_to_num(text: &str) -> Option<i32> {
+    match normalize_chinese(text).as_ref() {
+        "下下" => Some(2),
+        "下" => Some(1),
+        "这" | "本" => Some(0),
+        "上" => Some(-1),
+        "上上" => Some(-2),
+        _ => None,
+    }
+}
+
+/// Returns the sign of a time span, `后` is later and `前` is earlier.
+pub fn direction_to_sign(text: &str) -> Option<i32> {
+    match normalize_chinese(text).as_ref() {
+        "后" => Some(1),
+        "前" => Some(-1),
+        _ => None,
+    }
+}
+
+/// Prepositions in front of a date phrase which are removed together with it, like `在` in `在明天开会`.
+const PREPOSITIONS: [&str; 4] = ["在", "于", "於", "到"];
+
+/// Particles behind a date phrase which are removed together with it, like `之前` in `周五之前交报告`.
+const PARTICLES: [&str; 4] = ["之前", "以前", "前", "的"];
+
+pub struct ZhDateParser {}
+
+impl ZhDateParser {
+    /// Dates are written year first in chinese, e.g. 2026/12/05.
+    pub const DATE_FORMAT: DateFormat = DateFormat::YearMonthDay;
+
+    /// Weeks start on monday in chinese, `星期一` is the first day of the week.
+    pub const START_DAY_OF_WEEK: StartDayOfWeek = StartDayOfWeek::Monday;
+}
+
+/// Recognizes the first chinese date expression in `input`, written with simplified or traditional characters.
+///
+/// Chinese doesn't separate words by spaces, so the expressions are matched without word boundaries.
+/// The parsers are tried from the most specific to the least specific one.
+pub fn recognize_date_expression(
+    input: &str,
+    date_format: &DateFormat,
+) -> Option<RecognizedDateExpression> {
+    // 今天, 明天, 后天, 昨天, 前天
+    if let Some(date) = parse_keywords(input) {
+        return Some(date);
+    }
+
+    // ISO dates like 2026-12-05, 2026-W49-3 or 2026-339 regardless of the date format
+    if let Some(date) = parse_iso_date(input) {
+        return Some(date);
+    }
+
+    // full dates in DD.MM.YYYY, MM.DD.YYYY or YYYY.MM.DD formats
+    match date_format {
+        DateFormat::DayMonthYear => {
+            if let Some(date) = parse_date_month_year(input) {
+                return Some(date);
+            }
+        }
+        DateFormat::MonthDayYear => {
+            if let Some(date) = parse_month_date_year(input) {
+                return Some(date);
+            }
+        }
+        DateFormat::YearMonthDay => {
+            if let Some(date) = parse_year_month_day(input) {
+                return Some(date);
+            }
+        }
+    }
+
+    // 明年12月5日, 明年12月, before the year is dropped from the month and the day
+    if let Some(date) = parse_month_in_relative_year(input) {
+        return Some(date);
+    }
+
+    // 12月5日, 2026年12月5日, 十二月五号, before `5日前` is mistaken for five days ago
+    if let Some(date) = parse_month_date(input) {
+        return Some(date);
+    }
+
+    // 下周一, 下个星期三, 两周后的周五
+    if let Some(date) = parse_day_in_relative_week(input) {
+        return Some(date);
+    }
+
+    // a single day of the week
+    if let Some(date) = parse_day_alone(input) {
+        return Some(date);
+    }
+
+    // 下周, 这个星期, 上礼拜
+    if let Some(date) = parse_keyword_relative_week(input) {
+        return Some(date);
+    }
+
+    // 两周后, 三个星期以前
+    if let Some(date) = parse_in_x_weeks(input) {
+        return Some(date);
+    }
+
+    // 三天后, 3天以前
+    if let Some(date) = parse_relative_day(input) {
+        return Some(date);
+    }
+
+    // 两个月后, 三个月前
+    if let Some(date) = parse_in_n_months(input) {
+        return Some(date);
+    }
+
+    // 下个月, 本月, 上个月
+    if let Some(date) = parse_relative_month(input) {
+        return Some(date);
+    }
+
+    // 明年, 今年, 去年, 三年后
+    if let Some(date) = parse_relative_year(input) {
+        return Some(date);
+    }
+
+    None
+}
+
+/// Removes the date phrase in `range` from `text` together with the words around it which belong to the date.
+pub fn title_without_date_phrase(text: &str, range: Option<Range<usize>>) -> String {
+    match range {
+        Some(range) => strip_date_phrase_unspaced(text, range, &PREPOSITIONS, &PARTICLES),
+        None => strip_date_phrase_unspaced(text, text.len()..text.len(), &[], &[]),
+    }
+}
+
+/// The ZhDateParser can be used to parse chinese date information out of strings.
+///
+/// ```
+/// use date_time_parser_multi_language::{DateParser, ZhDateParser};
+/// use chrono::NaiveDate;
+//
+/// fn main() {
+///     let some_input = "下周一去看牙医";
+///
+///     let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+///
+///     let date_match = ZhDateParser::search_date_expression(some_input, &now, &ZhDateParser::DATE_FORMAT, &ZhDateParser::START_DAY_OF_WEEK).unwrap();
+///
+///     assert_eq!(date_match.date, NaiveDate::from_ymd_opt(2026, 10, 19).unwrap());
+///     assert_eq!(date_match.text, "下周一");
+/// }
+/// ```
+impl DateParser for ZhDateParser {
+    fn try_search_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Result<DateMatch, ParseError> {
+        let recognized =
+            recognize_date_expression(text, date_format).ok_or(ParseError::NoExpressionFound)?;
+
+        DateMatch::resolve(text, recognized, now, start_day_week)
+    }
+
+    fn search_all_date_expressions(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> Vec<DateMatch> {
+        recognize_all_date_expressions(text, date_format, recognize_date_expression)
+            .into_iter()
+            .filter_map(|recognized| DateMatch::resolve(text, recognized, now, start_day_week).ok())
+            .collect()
+    }
+
+    fn strip_date_expression(
+        text: &str,
+        now: &NaiveDate,
+        date_format: &DateFormat,
+        start_day_week: &StartDayOfWeek,
+    ) -> StrippedDateExpression {
+        let date_match = Self::search_date_expression(text, now, date_format, start_day_week);
+        let title = title_without_date_phrase(
+            text,
+            date_match
+                .as_ref()
+                .map(|date_match| date_match.range.clone()),
+        );
+
+        StrippedDateExpression { title, date_match }
+    }
+}
+
+#[cfg(test)]
+mod zh_date_parser_works_when {
+    use chrono::Weekday;
+
+    use super::{
+        direction_to_sign, normalize_chinese, parse_weekday_chinese, relative_prefix_to_num,
+    };
+
+    #[test]
+    fn traditional_characters_are_normalized() {
+        assert_eq!(normalize_chinese("後天"), "后天");
+        assert_eq!(normalize_chinese("下個禮拜"), "下个礼拜");
+        assert_eq!(normalize_chinese("這週"), "这周");
+        assert_eq!(normalize_chinese("明天"), "明天");
+    }
+
+    #[test]
+    fn weekdays_are_parsed() {
+        assert_eq!(parse_weekday_chinese("一"), Some(Weekday::Mon));
+        assert_eq!(parse_weekday_chinese("五"), Some(Weekday::Fri));
+        assert_eq!(parse_weekday_chinese("日"), Some(Weekday::Sun));
+        assert_eq!(parse_weekday_chinese("天"), Some(Weekday::Sun));
+        assert_eq!(parse_weekday_chinese("七"), None);
+    }
+
+    #[test]
+    fn relative_prefixes_are_parsed() {
+        assert_eq!(relative_prefix_to_num("下"), Some(1));
+        assert_eq!(relative_prefix_to_num("下下"), Some(2));
+        assert_eq!(relative_prefix_to_num("這"), Some(0));
+        assert_eq!(relative_prefix_to_num("本"), Some(0));
+        assert_eq!(relative_prefix_to_num("上"), Some(-1));
+        assert_eq!(relative_prefix_to_num("上上"), Some(-2));
+        assert_eq!(relative_prefix_to_num("明"), None);
+    }
+
+    #[test]
+    fn directions_are_parsed() {
+        assert_eq!(direction_to_sign("后"), Some(1));
+        assert_eq!(direction_to_sign("後"), Some(1));
+        assert_eq!(direction_to_sign("前"), Some(-1));
+        assert_eq!(direction_to_sign("中"), None);
+    }
+}
@@ -1,9 +1,66 @@
+mod date_match;
 mod date_parser;
+mod date_range;
+mod date_range_parser;
+mod date_time_parser;
+#[cfg(feature = "icalendar")]
+pub mod icalendar;
 mod language;
+mod parse_error;
+mod parser;
 mod recognizable;
+mod recurrence_parser;
 mod time_parser;
 
+pub use crate::date_match::{DateMatch, StrippedDateExpression};
 pub use crate::date_parser::DateParser;
+pub use crate::date_range::{DateRange, DateRangeMatch};
+pub use crate::date_range_parser::DateRangeParser;
+pub use crate::date_time_parser::DateTimeParser;
 pub use crate::language::date_format::DateFormat;
+pub use crate::language::de::de_date_parser::DeDateParser;
 pub use crate::language::en::en_date_parser::EnDateParser;
+pub use crate::language::en::en_date_time_parser::EnDateTimeParser;
+pub use crate::language::en::en_time_parser::EnTimeParser;
+pub use crate::language::es::es_date_parser::EsDateParser;
+pub use crate::language::fr::fr_date_parser::FrDateParser;
+pub use crate::language::ja::ja_date_parser::JaDateParser;
+pub use crate::language::ru::ru_date_parser::RuDateParser;
+pub use crate::language::shared::{
+    DateExpression, DateRangeExpression, Frequency, Month, MonthOverflow, Occurrences, PivotWindow,
+    Preference, Recurrence,
+};
 pub use crate::language::start_day_of_week::StartDayOfWeek;
+pub use crate::language::supported_language::Language;
+pub use crate::language::zh::zh_date_parser::ZhDateParser;
+pub use crate::parse_error::ParseError;
+pub use crate::parser::{Parser, ParserBuilder, ParserConfig};
+pub use crate::recurrence_parser::{RecurrenceMatch, RecurrenceParser};
+pub use crate::time_parser::TimeParser;
+
+/// The english expression parsers, re-exported for the benchmarks. This is not part of the public API
+/// and may change in any release.
+#[cfg(feature = "unstable-bench")]
+#[doc(hidden)]
+pub mod bench {
+    pub use crate::language::en::expressions::{
+        parse_ago::parse_ago,
+        parse_date_in_week::parse_date_in_week,
+        parse_date_in_x_weeks::parse_in_x_weeks,
+        parse_day_alone::parse_day_alone,
+        parse_day_in_explicit_weeks::parse_day_in_explicit_week,
+        parse_day_in_relative_week::parse_day_in_relative_week,
+        parse_day_of_month::parse_day_of_month,
+        parse_in_n_months::parse_in_n_months,
+        parse_keywords::parse_keywords,
+        parse_month_date::parse_month_date,
+        parse_month_year::parse_month_year,
+        parse_relative_date::parse_relative_day,
+        parse_relative_keywork_week::parse_keyword_relative_week,
+        parse_relative_month::parse_relative_month,
+        parse_relative_year::{parse_month_in_relative_year, parse_relative_year},
+    };
+    pub use crate::language::shared_date_parser::{
+        parse_date_month_year, parse_iso_date, parse_month_date_year, parse_year_month_day,
+    };
+}
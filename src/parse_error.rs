@@ -0,0 +1,170 @@
+use std::{error::Error, fmt, ops::Range};
+
+use crate::language::shared::{DateExpression, ResolveError};
+
+/// The reason why no date could be parsed out of an input.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseError {
+    /// The input doesn't contain any date expression.
+    NoExpressionFound,
+    /// An expression was found, but it names a day which doesn't exist, like `February 30th`.
+    InvalidDate {
+        expression: DateExpression,
+        /// The byte range of the expression in the input.
+        range: Range<usize>,
+    },
+    /// An expression was found, but the date lies outside of the supported dates.
+    Overflow {
+        expression: DateExpression,
+        /// The byte range of the expression in the input.
+        range: Range<usize>,
+    },
+    /// An expression was found, but it leads to more than one possible date,
+    /// like `05/06` in english without a configured date format.
+    Ambiguous {
+        expression: DateExpression,
+        /// The byte range of the expression in the input.
+        range: Range<usize>,
+    },
+}
+
+impl ParseError {
+    pub(crate) fn from_resolve_error(
+        error: ResolveError,
+        expression: DateExpression,
+        range: Range<usize>,
+    ) -> ParseError {
+        match error {
+            ResolveError::InvalidDate => ParseError::InvalidDate { expression, range },
+            ResolveError::Overflow => ParseError::Overflow { expression, range },
+        }
+    }
+
+    /// The byte range of the found expression, if there is one.
+    pub fn range(&self) -> Option<Range<usize>> {
+        match self {
+            ParseError::NoExpressionFound => None,
+            ParseError::InvalidDate { range, .. }
+            | ParseError::Overflow { range, .. }
+            | ParseError::Ambiguous { range, .. } => Some(range.clone()),
+        }
+    }
+}
+
+/// Returns the english ordinal of a day, e.g. `30th` or `22nd`.
+fn ordinal(day: u32) -> String {
+    let suffix = match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+
+    format!("{}{}", day, suffix)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::NoExpressionFound => write!(f, "no date expression found"),
+            ParseError::InvalidDate {
+                expression:
+                    DateExpression::DayInMonth(month, day)
+                    | DateExpression::DayInMonthInYear(month, day, _)
+                    | DateExpression::DayInMonthInTwoDigitYear(month, day, _)
+                    | DateExpression::DayInMonthInXYears(month, day, _),
+                ..
+            } => write!(f, "{:?} has no {}", month, ordinal(*day)),
+            ParseError::InvalidDate {
+                expression: DateExpression::DayInIsoWeek(year, week, _),
+                ..
+            } => write!(f, "{} has no week {}", year, week),
+            ParseError::InvalidDate {
+                expression: DateExpression::DayInYear(year, day),
+                ..
+            } => write!(f, "{} has no day {}", year, day),
+            ParseError::InvalidDate { .. } => write!(f, "the date doesn't exist"),
+            ParseError::Overflow { .. } => write!(f, "the date is out of range"),
+            ParseError::Ambiguous { .. } => write!(f, "the date is ambiguous"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod parse_error_works_when {
+    use chrono::Weekday;
+
+    use super::{ordinal, ParseError};
+    use crate::language::shared::{DateExpression, Month};
+
+    #[test]
+    fn ordinals_are_written() {
+        assert_eq!(ordinal(1), "1st");
+        assert_eq!(ordinal(2), "2nd");
+        assert_eq!(ordinal(3), "3rd");
+        assert_eq!(ordinal(11), "11th");
+        assert_eq!(ordinal(12), "12th");
+        assert_eq!(ordinal(13), "13th");
+        assert_eq!(ordinal(22), "22nd");
+        assert_eq!(ordinal(30), "30th");
+        assert_eq!(ordinal(31), "31st");
+    }
+
+    #[test]
+    fn invalid_days_are_explained() {
+        let error = ParseError::InvalidDate {
+            expression: DateExpression::DayInMonth(Month::February, 30),
+            range: 3..16,
+        };
+
+        assert_eq!(error.to_string(), "February has no 30th");
+        assert_eq!(error.range(), Some(3..16));
+
+        let error = ParseError::InvalidDate {
+            expression: DateExpression::DayInMonthInYear(Month::April, 31, 2026),
+            range: 0..8,
+        };
+
+        assert_eq!(error.to_string(), "April has no 31st");
+    }
+
+    #[test]
+    fn invalid_iso_dates_are_explained() {
+        let error = ParseError::InvalidDate {
+            expression: DateExpression::DayInIsoWeek(2025, 53, Weekday::Mon),
+            range: 0..8,
+        };
+
+        assert_eq!(error.to_string(), "2025 has no week 53");
+
+        let error = ParseError::InvalidDate {
+            expression: DateExpression::DayInYear(2026, 366),
+            range: 0..8,
+        };
+
+        assert_eq!(error.to_string(), "2026 has no day 366");
+    }
+
+    #[test]
+    fn ambiguous_dates_are_explained() {
+        let error = ParseError::Ambiguous {
+            expression: DateExpression::DayInMonth(Month::May, 6),
+            range: 9..14,
+        };
+
+        assert_eq!(error.to_string(), "the date is ambiguous");
+        assert_eq!(error.range(), Some(9..14));
+    }
+
+    #[test]
+    fn no_expression_has_no_range() {
+        assert_eq!(ParseError::NoExpressionFound.range(), None);
+        assert_eq!(
+            ParseError::NoExpressionFound.to_string(),
+            "no date expression found"
+        );
+    }
+}
@@ -0,0 +1,609 @@
+use std::ops::Range;
+
+use chrono::{Local, NaiveDate};
+
+use crate::{
+    date_match::{DateMatch, StrippedDateExpression},
+    language::{
+        de::de_date_parser,
+        en::en_date_parser,
+        es::es_date_parser,
+        fr::fr_date_parser,
+        ja::ja_date_parser,
+        ru::ru_date_parser,
+        shared::{
+            apply_preference, recognize_all_date_expressions, resolve_date_expression_with,
+            DateExpression, MonthOverflow, PivotWindow, Preference, RecognizeDateExpression,
+            RecognizedDateExpression, ResolveError,
+        },
+        supported_language::Language,
+        zh::zh_date_parser,
+    },
+    parse_error::ParseError,
+    DateFormat, StartDayOfWeek,
+};
+
+/// The settings of a `Parser`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ParserConfig {
+    pub language: Language,
+    pub date_format: DateFormat,
+    pub start_of_week: StartDayOfWeek,
+    /// The date relative dates are resolved against. When empty, the current local date is used.
+    pub reference_date: Option<NaiveDate>,
+    /// Moves dates without an explicit week or year, like `friday`, into the future or the past.
+    /// When empty, these dates are resolved as written.
+    pub preference: Option<Preference>,
+    /// Decides what happens with `in 1 month` on the 31st, when the target month is shorter.
+    pub month_overflow: MonthOverflow,
+    /// Decides the century of two-digit years like `5.12.26` or `Dec '26`.
+    pub pivot_window: PivotWindow,
+}
+
+impl ParserConfig {
+    /// Resolves an expression relative to `now` with the month overflow, the pivot window and the preference.
+    fn resolve(
+        &self,
+        expression: DateExpression,
+        now: &NaiveDate,
+    ) -> Result<NaiveDate, ResolveError> {
+        let date = resolve_date_expression_with(
+            expression.clone(),
+            now,
+            &self.start_of_week,
+            &self.month_overflow,
+            &self.pivot_window,
+        )?;
+
+        match &self.preference {
+            Some(preference) => {
+                apply_preference(&expression, date, now, preference).ok_or(ResolveError::Overflow)
+            }
+            None => Ok(date),
+        }
+    }
+}
+
+/// Builds a `Parser`. The date format and the start of the week default to the ones of the language.
+#[derive(Debug, Clone)]
+pub struct ParserBuilder {
+    language: Language,
+    date_format: Option<DateFormat>,
+    start_of_week: Option<StartDayOfWeek>,
+    reference_date: Option<NaiveDate>,
+    preference: Option<Preference>,
+    month_overflow: MonthOverflow,
+    pivot_window: PivotWindow,
+}
+
+impl ParserBuilder {
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    pub fn date_format(mut self, date_format: DateFormat) -> Self {
+        self.date_format = Some(date_format);
+        self
+    }
+
+    pub fn start_of_week(mut self, start_of_week: StartDayOfWeek) -> Self {
+        self.start_of_week = Some(start_of_week);
+        self
+    }
+
+    pub fn reference_date(mut self, reference_date: NaiveDate) -> Self {
+        self.reference_date = Some(reference_date);
+        self
+    }
+
+    pub fn preference(mut self, preference: Preference) -> Self {
+        self.preference = Some(preference);
+        self
+    }
+
+    pub fn month_overflow(mut self, month_overflow: MonthOverflow) -> Self {
+        self.month_overflow = month_overflow;
+        self
+    }
+
+    pub fn pivot_window(mut self, pivot_window: PivotWindow) -> Self {
+        self.pivot_window = pivot_window;
+        self
+    }
+
+    pub fn build(self) -> Parser {
+        // english is written month first in the US and day first elsewhere
+        let alternative_date_format = match (&self.date_format, &self.language) {
+            (None, Language::English) => Some(DateFormat::DayMonthYear),
+            _ => None,
+        };
+
+        let config = ParserConfig {
+            date_format: self
+                .date_format
+                .unwrap_or_else(|| self.language.date_format()),
+            start_of_week: self
+                .start_of_week
+                .unwrap_or_else(|| self.language.start_of_week()),
+            language: self.language,
+            reference_date: self.reference_date,
+            preference: self.preference,
+            month_overflow: self.month_overflow,
+            pivot_window: self.pivot_window,
+        };
+
+        Parser {
+            alternative_date_format,
+            ..Parser::new(config)
+        }
+    }
+}
+
+/// A parser, which holds its settings, so they don't have to be passed on every call.
+///
+/// ```
+/// use date_time_parser_multi_language::{Language, Parser, Preference};
+/// use chrono::NaiveDate;
+//
+/// fn main() {
+///     let parser = Parser::builder()
+///         .language(Language::German)
+///         .reference_date(NaiveDate::from_ymd_opt(2026, 10, 18).unwrap())
+///         .preference(Preference::Future)
+///         .build();
+///
+///     let date_match = parser.parse("Zahnarzt am 5. Dezember").unwrap();
+///
+///     assert_eq!(date_match.date, NaiveDate::from_ymd_opt(2026, 12, 5).unwrap());
+///
+///     let error = parser.parse("Zahnarzt am 30. Februar").unwrap_err();
+///
+///     assert_eq!(error.to_string(), "February has no 30th");
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Parser {
+    config: ParserConfig,
+    grammar: Grammar,
+    /// Another reading of numeric dates, when the date format of the config is only a guess.
+    alternative_date_format: Option<DateFormat>,
+}
+
+impl Parser {
+    pub fn new(config: ParserConfig) -> Parser {
+        Parser {
+            grammar: Grammar::of(&config.language),
+            config,
+            alternative_date_format: None,
+        }
+    }
+
+    /// Returns a builder for an english parser.
+    pub fn builder() -> ParserBuilder {
+        ParserBuilder {
+            language: Language::English,
+            date_format: None,
+            start_of_week: None,
+            reference_date: None,
+            preference: None,
+            month_overflow: MonthOverflow::Clamp,
+            pivot_window: PivotWindow::default(),
+        }
+    }
+
+    pub fn config(&self) -> &ParserConfig {
+        &self.config
+    }
+
+    /// Searches the first date expression in `text`, see `DateParser::try_search_date_expression`.
+    pub fn parse(&self, text: &str) -> Result<DateMatch, ParseError> {
+        let now = self.reference_date();
+        let recognized = (self.grammar.recognize)(text, &self.config.date_format)
+            .ok_or(ParseError::NoExpressionFound)?;
+
+        self.resolve(text, recognized, &now)
+    }
+
+    /// Searches all date expressions in `text`, see `DateParser::search_all_date_expressions`.
+    pub fn parse_all(&self, text: &str) -> Vec<DateMatch> {
+        let now = self.reference_date();
+
+        recognize_all_date_expressions(text, &self.config.date_format, self.grammar.recognize)
+            .into_iter()
+            .filter_map(|recognized| self.resolve(text, recognized, &now).ok())
+            .collect()
+    }
+
+    /// Removes the first date expression from `text`, see `DateParser::strip_date_expression`.
+    pub fn strip(&self, text: &str) -> StrippedDateExpression {
+        let date_match = self.parse(text).ok();
+        let title = (self.grammar.strip)(
+            text,
+            date_match
+                .as_ref()
+                .map(|date_match| date_match.range.clone()),
+        );
+
+        StrippedDateExpression { title, date_match }
+    }
+
+    fn reference_date(&self) -> NaiveDate {
+        self.config
+            .reference_date
+            .unwrap_or_else(|| Local::now().date_naive())
+    }
+
+    /// Resolves a recognized expression, which has to lead to one date in both date formats,
+    /// when the date format of the config is only a guess.
+    fn resolve(
+        &self,
+        text: &str,
+        recognized: RecognizedDateExpression,
+        now: &NaiveDate,
+    ) -> Result<DateMatch, ParseError> {
+        let date_match = DateMatch::resolve_with(text, recognized, |expression| {
+            self.config.resolve(expression, now)
+        })?;
+
+        match self.alternative_date(&date_match, now) {
+            Some(date) if date != date_match.date => Err(ParseError::Ambiguous {
+                expression: date_match.expression,
+                range: date_match.range,
+            }),
+            _ => Ok(date_match),
+        }
+    }
+
+    /// Resolves the matched text in the alternative date format, e.g. `05/06` as the 5th of june.
+    fn alternative_date(&self, date_match: &DateMatch, now: &NaiveDate) -> Option<NaiveDate> {
+        let date_format = self.alternative_date_format.as_ref()?;
+        let recognized = (self.grammar.recognize)(&date_match.text, date_format)?;
+
+        if recognized.range != (0..date_match.text.len())
+            || recognized.expression == date_match.expression
+        {
+            return None;
+        }
+
+        self.config.resolve(recognized.expression, now).ok()
+    }
+}
+
+/// The language specific parts of a search, picked once when the `Parser` is created.
+#[derive(Debug, Clone)]
+struct Grammar {
+    recognize: RecognizeDateExpression,
+    strip: fn(&str, Option<Range<usize>>) -> String,
+}
+
+impl Grammar {
+    fn of(language: &Language) -> Self {
+        match language {
+            Language::English => Grammar {
+                recognize: en_date_parser::recognize_date_expression,
+                strip: en_date_parser::title_without_date_phrase,
+            },
+            Language::German => Grammar {
+                recognize: de_date_parser::recognize_date_expression,
+                strip: de_date_parser::title_without_date_phrase,
+            },
+            Language::French => Grammar {
+                recognize: fr_date_parser::recognize_date_expression,
+                strip: fr_date_parser::title_without_date_phrase,
+            },
+            Language::Spanish => Grammar {
+                recognize: es_date_parser::recognize_date_expression,
+                strip: es_date_parser::title_without_date_phrase,
+            },
+            Language::Japanese => Grammar {
+                recognize: ja_date_parser::recognize_date_expression,
+                strip: ja_date_parser::title_without_date_phrase,
+            },
+            Language::Chinese => Grammar {
+                recognize: zh_date_parser::recognize_date_expression,
+                strip: zh_date_parser::title_without_date_phrase,
+            },
+            Language::Russian => Grammar {
+                recognize: ru_date_parser::recognize_date_expression,
+                strip: ru_date_parser::title_without_date_phrase,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod parser_works_when {
+    use chrono::NaiveDate;
+
+    use super::{Parser, ParserConfig};
+    use crate::{
+        language::shared::{DateExpression, Month, MonthOverflow, PivotWindow, Preference},
+        DateFormat, Language, ParseError, StartDayOfWeek,
+    };
+
+    #[test]
+    fn defaults_of_the_language_are_used() {
+        let parser = Parser::builder().language(Language::German).build();
+
+        assert_eq!(
+            parser.config(),
+            &ParserConfig {
+                language: Language::German,
+                date_format: DateFormat::DayMonthYear,
+                start_of_week: StartDayOfWeek::Monday,
+                reference_date: None,
+                preference: None,
+                month_overflow: MonthOverflow::Clamp,
+                pivot_window: PivotWindow { years_ahead: 50 },
+            }
+        );
+
+        let parser = Parser::builder().build();
+
+        assert_eq!(parser.config().date_format, DateFormat::MonthDayYear);
+        assert_eq!(parser.config().start_of_week, StartDayOfWeek::Sunday);
+    }
+
+    #[test]
+    fn explicit_settings_win_over_the_defaults() {
+        let parser = Parser::builder()
+            .date_format(DateFormat::DayMonthYear)
+            .start_of_week(StartDayOfWeek::Monday)
+            .build();
+
+        assert_eq!(parser.config().date_format, DateFormat::DayMonthYear);
+        assert_eq!(parser.config().start_of_week, StartDayOfWeek::Monday);
+    }
+
+    #[test]
+    fn preference_moves_open_dates() {
+        // the 18th of october 2026 is a sunday
+        let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let parser = |preference: Option<Preference>| {
+            let builder = Parser::builder()
+                .start_of_week(StartDayOfWeek::Monday)
+                .reference_date(now);
+
+            match preference {
+                Some(preference) => builder.preference(preference).build(),
+                None => builder.build(),
+            }
+        };
+
+        let friday = |parser: Parser| parser.parse("on friday").ok().map(|m| m.date);
+
+        assert_eq!(friday(parser(None)), NaiveDate::from_ymd_opt(2026, 10, 16));
+        assert_eq!(
+            friday(parser(Some(Preference::Future))),
+            NaiveDate::from_ymd_opt(2026, 10, 23)
+        );
+        assert_eq!(
+            friday(parser(Some(Preference::Past))),
+            NaiveDate::from_ymd_opt(2026, 10, 16)
+        );
+
+        let june = |parser: Parser| parser.parse("june 5th").ok().map(|m| m.date);
+
+        assert_eq!(june(parser(None)), NaiveDate::from_ymd_opt(2027, 6, 5));
+        assert_eq!(
+            june(parser(Some(Preference::Past))),
+            NaiveDate::from_ymd_opt(2026, 6, 5)
+        );
+    }
+
+    #[test]
+    fn preference_moves_days_of_the_month_and_months() {
+        let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let parser = |language: Language, preference: Preference| {
+            Parser::builder()
+                .language(language)
+                .reference_date(now)
+                .preference(preference)
+                .build()
+        };
+        let date = |parser: Parser, text: &str| parser.parse(text).ok().map(|m| m.date);
+
+        assert_eq!(
+            date(parser(Language::English, Preference::Future), "on the 22nd"),
+            NaiveDate::from_ymd_opt(2026, 10, 22)
+        );
+        assert_eq!(
+            date(parser(Language::English, Preference::Past), "on the 22nd"),
+            NaiveDate::from_ymd_opt(2026, 9, 22)
+        );
+        // september has no 31st
+        assert_eq!(
+            date(parser(Language::English, Preference::Past), "on the 31st"),
+            NaiveDate::from_ymd_opt(2026, 8, 31)
+        );
+        assert_eq!(
+            date(parser(Language::English, Preference::Past), "on the 5th"),
+            NaiveDate::from_ymd_opt(2026, 10, 5)
+        );
+
+        assert_eq!(
+            date(parser(Language::Russian, Preference::Future), "в июне"),
+            NaiveDate::from_ymd_opt(2027, 6, 1)
+        );
+        assert_eq!(
+            date(parser(Language::Russian, Preference::Past), "в июне"),
+            NaiveDate::from_ymd_opt(2026, 6, 1)
+        );
+        assert_eq!(
+            date(parser(Language::Russian, Preference::Past), "в ноябре"),
+            NaiveDate::from_ymd_opt(2025, 11, 1)
+        );
+    }
+
+    #[test]
+    fn explicit_dates_are_not_moved() {
+        let parser = Parser::builder()
+            .start_of_week(StartDayOfWeek::Monday)
+            .reference_date(NaiveDate::from_ymd_opt(2026, 10, 18).unwrap())
+            .preference(Preference::Past)
+            .build();
+
+        assert_eq!(
+            parser.parse("next friday").ok().map(|m| m.date),
+            NaiveDate::from_ymd_opt(2026, 10, 23)
+        );
+    }
+
+    #[test]
+    fn errors_explain_missing_dates() {
+        let parser = Parser::builder()
+            .reference_date(NaiveDate::from_ymd_opt(2026, 10, 18).unwrap())
+            .build();
+
+        assert_eq!(parser.parse("buy milk"), Err(ParseError::NoExpressionFound));
+        assert_eq!(
+            parser.parse("pay rent on february 30th"),
+            Err(ParseError::InvalidDate {
+                expression: DateExpression::DayInMonth(Month::February, 30),
+                range: 12..25,
+            })
+        );
+    }
+
+    #[test]
+    fn numeric_dates_are_ambiguous_without_a_date_format() {
+        let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let parser = Parser::builder().reference_date(now).build();
+
+        assert_eq!(
+            parser.parse("Party on 05/06"),
+            Err(ParseError::Ambiguous {
+                expression: DateExpression::DayInMonth(Month::May, 6),
+                range: 9..14,
+            })
+        );
+        assert_eq!(parser.parse_all("Party on 05/06"), vec![]);
+        assert_eq!(
+            parser.parse("Party on 05/13").map(|m| m.date),
+            Ok(NaiveDate::from_ymd_opt(2027, 5, 13).unwrap())
+        );
+        assert_eq!(
+            parser.parse("Party on 06/06").map(|m| m.date),
+            Ok(NaiveDate::from_ymd_opt(2027, 6, 6).unwrap())
+        );
+
+        let parser = Parser::builder()
+            .date_format(DateFormat::MonthDayYear)
+            .reference_date(now)
+            .build();
+
+        assert_eq!(
+            parser.parse("Party on 05/06").map(|m| m.date),
+            Ok(NaiveDate::from_ymd_opt(2027, 5, 6).unwrap())
+        );
+
+        let parser = Parser::builder()
+            .language(Language::German)
+            .reference_date(now)
+            .build();
+
+        assert_eq!(
+            parser.parse("Party am 05.06.").map(|m| m.date),
+            Ok(NaiveDate::from_ymd_opt(2027, 6, 5).unwrap())
+        );
+    }
+
+    #[test]
+    fn errors_of_the_config_are_reported() {
+        // 2000 was a leap year, 1900 wasn't
+        let parser = Parser::builder()
+            .date_format(DateFormat::DayMonthYear)
+            .reference_date(NaiveDate::from_ymd_opt(1950, 1, 1).unwrap())
+            .pivot_window(PivotWindow { years_ahead: 0 })
+            .build();
+
+        assert_eq!(
+            parser.parse("Party on 29.2.00"),
+            Err(ParseError::InvalidDate {
+                expression: DateExpression::DayInMonthInTwoDigitYear(Month::February, 29, 0),
+                range: 9..16,
+            })
+        );
+    }
+
+    #[test]
+    fn two_digit_years_are_resolved_with_the_pivot_window_only() {
+        // 00 is read as 2000 with a window of 60 years after 1940, but as 1900 with the default window
+        let parser = Parser::builder()
+            .date_format(DateFormat::DayMonthYear)
+            .reference_date(NaiveDate::from_ymd_opt(1940, 1, 1).unwrap())
+            .pivot_window(PivotWindow { years_ahead: 60 })
+            .build();
+        let leap_day = NaiveDate::from_ymd_opt(2000, 2, 29).unwrap();
+
+        assert_eq!(
+            parser.parse("Party on 29.2.00").map(|m| m.date),
+            Ok(leap_day)
+        );
+        assert_eq!(
+            parser
+                .parse_all("Party on 29.2.00")
+                .into_iter()
+                .map(|m| m.date)
+                .collect::<Vec<_>>(),
+            vec![leap_day]
+        );
+        assert_eq!(parser.strip("Party on 29.2.00").title, "Party");
+    }
+
+    #[test]
+    fn months_overflow_as_configured() {
+        let now = NaiveDate::from_ymd_opt(2026, 1, 31).unwrap();
+        let next_month = |month_overflow: MonthOverflow| {
+            Parser::builder()
+                .reference_date(now)
+                .month_overflow(month_overflow)
+                .build()
+                .parse("next month")
+                .ok()
+                .map(|m| m.date)
+        };
+
+        assert_eq!(
+            next_month(MonthOverflow::Clamp),
+            NaiveDate::from_ymd_opt(2026, 2, 28)
+        );
+        assert_eq!(
+            next_month(MonthOverflow::NextMonth),
+            NaiveDate::from_ymd_opt(2026, 3, 3)
+        );
+    }
+
+    #[test]
+    fn two_digit_years_use_the_pivot_window() {
+        let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+        let invoice_date = |years_ahead: u32, text: &str| {
+            Parser::builder()
+                .date_format(DateFormat::DayMonthYear)
+                .reference_date(now)
+                .pivot_window(PivotWindow { years_ahead })
+                .build()
+                .parse(text)
+                .ok()
+                .map(|m| m.date)
+        };
+
+        assert_eq!(
+            invoice_date(50, "Invoice from 5.12.76"),
+            NaiveDate::from_ymd_opt(2076, 12, 5)
+        );
+        assert_eq!(
+            invoice_date(50, "Invoice from 5.12.77"),
+            NaiveDate::from_ymd_opt(1977, 12, 5)
+        );
+        assert_eq!(
+            invoice_date(0, "Invoice from 5.12.27"),
+            NaiveDate::from_ymd_opt(1927, 12, 5)
+        );
+        assert_eq!(
+            invoice_date(0, "Invoice from Dec '26"),
+            NaiveDate::from_ymd_opt(2026, 12, 1)
+        );
+    }
+}
@@ -37,7 +37,4 @@ use crate::language::date_format::DateFormat;
 pub trait Recognizable: Sized {
     /// Takes unstructed text, and returns an instance of the abstract syntax if a match is found.
     fn recognize(text: &str, date_format: &DateFormat) -> Option<Self>;
-
-    /// Returns a string to describe the abstract syntax.
-    fn describe() -> &'static str;
 }
@@ -0,0 +1,19 @@
+use std::ops::Range;
+
+use crate::language::shared::Recurrence;
+
+/// A recurrence found in a string together with the part of the string it was found in.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RecurrenceMatch {
+    /// The recognized recurrence.
+    pub recurrence: Recurrence,
+    /// The byte range of the matched text in the input.
+    pub range: Range<usize>,
+    /// The matched text, e.g. `every monday` in `water the plants every monday`.
+    pub text: String,
+}
+
+pub trait RecurrenceParser {
+    /// Searches the first recurring date expression like `every monday` or `daily` in `input`.
+    fn search_recurrence(input: &str) -> Option<RecurrenceMatch>;
+}
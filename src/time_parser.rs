@@ -1,5 +1,5 @@
 use chrono::NaiveTime;
 
 pub trait TimeParser {
-    fn search_time(input: &str) -> &NaiveTime;
+    fn search_time(input: &str) -> Option<NaiveTime>;
 }
@@ -0,0 +1,271 @@
+#![cfg(feature = "icalendar")]
+
+use chrono::{NaiveDate, NaiveDateTime, Weekday};
+use date_time_parser_multi_language::icalendar::{
+    date_from_ical, date_range_from_ical, date_range_to_ical, date_time_from_ical,
+    date_time_to_ical, date_to_ical, recurrence_from_ical, recurrence_to_ical, Event, EventTime,
+};
+use date_time_parser_multi_language::{
+    DateFormat, DateRange, DateRangeParser, EnDateParser, Frequency, Recurrence, RecurrenceParser,
+    StartDayOfWeek,
+};
+
+#[test]
+fn test_dates() {
+    let date = NaiveDate::from_ymd_opt(2026, 12, 5).unwrap();
+
+    assert_eq!(date_to_ical(&date), "DTSTART;VALUE=DATE:20261205");
+    assert_eq!(date_from_ical(&date_to_ical(&date)), Some(date));
+
+    assert_eq!(date_from_ical("DTSTART:20261205T143000"), None);
+    assert_eq!(date_from_ical("DTSTART;VALUE=DATE:2026125"), None);
+}
+
+#[test]
+fn test_date_times() {
+    let date_time = date_time(2026, 12, 5, 14, 30);
+
+    assert_eq!(
+        date_time_to_ical(&date_time, None),
+        "DTSTART:20261205T143000"
+    );
+    assert_eq!(
+        date_time_from_ical(&date_time_to_ical(&date_time, None)),
+        Some((date_time, None))
+    );
+
+    let line = date_time_to_ical(&date_time, Some("Europe/Berlin"));
+    assert_eq!(line, "DTSTART;TZID=Europe/Berlin:20261205T143000");
+    assert_eq!(
+        date_time_from_ical(&line),
+        Some((date_time, Some(String::from("Europe/Berlin"))))
+    );
+}
+
+#[test]
+fn test_date_ranges() {
+    let now = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+
+    let date_range = EnDateParser::search_date_range(
+        "vacation from june 3rd to june 7th",
+        &now,
+        &DateFormat::DayMonthYear,
+        &StartDayOfWeek::Monday,
+    )
+    .unwrap()
+    .date_range;
+
+    // the end of all-day events is exclusive
+    let text = date_range_to_ical(&date_range).unwrap();
+    assert_eq!(
+        text,
+        "DTSTART;VALUE=DATE:20260603\r\nDTEND;VALUE=DATE:20260608"
+    );
+    assert_eq!(date_range_from_ical(&text), Some(date_range));
+
+    let single_day = DateRange {
+        start: now,
+        end: now,
+    };
+    assert_eq!(
+        date_range_from_ical(&date_range_to_ical(&single_day).unwrap()),
+        Some(single_day)
+    );
+
+    assert_eq!(
+        date_range_from_ical("DTSTART;VALUE=DATE:20260603\r\nDTEND;VALUE=DATE:20260603"),
+        None
+    );
+}
+
+#[test]
+fn test_recurrences() {
+    assert_recurrence(
+        "every other week on monday and thursday",
+        &StartDayOfWeek::Monday,
+        "RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH;WKST=MO",
+    );
+    assert_recurrence(
+        "every other week on monday and thursday",
+        &StartDayOfWeek::Sunday,
+        "RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH;WKST=SU",
+    );
+    assert_recurrence(
+        "each 1st of the month",
+        &StartDayOfWeek::Monday,
+        "RRULE:FREQ=MONTHLY;BYMONTHDAY=1",
+    );
+    assert_recurrence("daily", &StartDayOfWeek::Monday, "RRULE:FREQ=DAILY");
+    assert_recurrence(
+        "every 3 years",
+        &StartDayOfWeek::Monday,
+        "RRULE:FREQ=YEARLY;INTERVAL=3",
+    );
+
+    assert_eq!(recurrence_from_ical("RRULE:FREQ=DAILY;COUNT=5"), None);
+    assert_eq!(recurrence_from_ical("RRULE:FREQ=WEEKLY;BYDAY=1MO"), None);
+    assert_eq!(recurrence_from_ical("RRULE:INTERVAL=2"), None);
+}
+
+#[test]
+fn test_events() {
+    let timestamp = date_time(2026, 10, 18, 8, 0);
+
+    let event = Event {
+        uid: String::from("42@example.com"),
+        timestamp,
+        summary: String::from("Call Bob; bring notes, slides"),
+        time: EventTime::DateTime(date_time(2026, 12, 5, 17, 0)),
+        tzid: None,
+        recurrence: Some(Recurrence {
+            frequency: Frequency::Weekly,
+            interval: 1,
+            weekdays: vec![Weekday::Sat],
+            month_day: None,
+        }),
+        start_of_week: StartDayOfWeek::Sunday,
+    };
+
+    let text = event.to_ical().unwrap();
+    assert_eq!(
+        text,
+        "BEGIN:VEVENT\r\n\
+         UID:42@example.com\r\n\
+         DTSTAMP:20261018T080000Z\r\n\
+         DTSTART:20261205T170000\r\n\
+         RRULE:FREQ=WEEKLY;BYDAY=SA;WKST=SU\r\n\
+         SUMMARY:Call Bob\\; bring notes\\, slides\r\n\
+         END:VEVENT\r\n"
+    );
+    assert_eq!(Event::from_ical(&text), Some(event));
+
+    assert_event_round_trip(Event {
+        uid: String::from("43@example.com"),
+        timestamp,
+        summary: String::from("Vacation"),
+        time: EventTime::DateRange(DateRange {
+            start: NaiveDate::from_ymd_opt(2026, 12, 3).unwrap(),
+            end: NaiveDate::from_ymd_opt(2026, 12, 7).unwrap(),
+        }),
+        tzid: None,
+        recurrence: None,
+        start_of_week: StartDayOfWeek::Monday,
+    });
+
+    assert_event_round_trip(Event {
+        uid: String::from("44@example.com"),
+        timestamp,
+        summary: "A very long title, which doesn't fit into one line of 75 octets and is folded 🙂\nsecond line".to_string(),
+        time: EventTime::Date(NaiveDate::from_ymd_opt(2026, 12, 24).unwrap()),
+        tzid: None,
+        recurrence: None,
+        start_of_week: StartDayOfWeek::Monday,
+    });
+}
+
+#[test]
+fn test_events_with_time_zone() {
+    let event = Event {
+        uid: String::from("46@example.com"),
+        timestamp: date_time(2026, 10, 18, 8, 0),
+        summary: String::from("Dentist"),
+        time: EventTime::DateTime(date_time(2026, 12, 5, 9, 30)),
+        tzid: Some(String::from("Europe/Berlin")),
+        recurrence: None,
+        start_of_week: StartDayOfWeek::Monday,
+    };
+
+    let text = event.to_ical().unwrap();
+    assert!(
+        text.contains("\r\nDTSTART;TZID=Europe/Berlin:20261205T093000\r\n"),
+        "Failed for: {}",
+        text
+    );
+    assert_eq!(Event::from_ical(&text), Some(event));
+
+    assert_event_round_trip(Event {
+        uid: String::from("47@example.com"),
+        timestamp: date_time(2026, 10, 18, 8, 0),
+        summary: String::from("Standup"),
+        time: EventTime::DateTime(date_time(2026, 12, 7, 10, 0)),
+        tzid: Some(String::from("America/New_York")),
+        recurrence: Some(Recurrence {
+            frequency: Frequency::Daily,
+            interval: 1,
+            weekdays: vec![],
+            month_day: None,
+        }),
+        start_of_week: StartDayOfWeek::Monday,
+    });
+
+    // the end of a timed event is ignored
+    let text = [
+        "BEGIN:VEVENT",
+        "UID:48@example.com",
+        "DTSTAMP:20261018T080000Z",
+        "DTSTART;TZID=Europe/Berlin:20261205T093000",
+        "DTEND;TZID=Europe/Berlin:20261205T103000",
+        "SUMMARY:Dentist",
+        "END:VEVENT",
+    ]
+    .join("\r\n");
+
+    assert_eq!(
+        Event::from_ical(&text),
+        Some(Event {
+            uid: String::from("48@example.com"),
+            timestamp: date_time(2026, 10, 18, 8, 0),
+            summary: String::from("Dentist"),
+            time: EventTime::DateTime(date_time(2026, 12, 5, 9, 30)),
+            tzid: Some(String::from("Europe/Berlin")),
+            recurrence: None,
+            start_of_week: StartDayOfWeek::Monday,
+        })
+    );
+}
+
+#[test]
+fn test_long_lines_are_folded() {
+    let event = Event {
+        uid: String::from("45@example.com"),
+        timestamp: date_time(2026, 10, 18, 8, 0),
+        summary: "x".repeat(200),
+        time: EventTime::Date(NaiveDate::from_ymd_opt(2026, 12, 24).unwrap()),
+        tzid: None,
+        recurrence: None,
+        start_of_week: StartDayOfWeek::Monday,
+    };
+
+    let text = event.to_ical().unwrap();
+
+    assert!(text.split("\r\n").all(|line| line.len() <= 75));
+    assert_eq!(Event::from_ical(&text), Some(event));
+}
+
+fn assert_recurrence(input: &str, start_of_week: &StartDayOfWeek, expected: &str) {
+    let recurrence = EnDateParser::search_recurrence(input)
+        .unwrap_or_else(|| panic!("Failed to parse a recurrence from {}", input))
+        .recurrence;
+
+    let line = recurrence_to_ical(&recurrence, start_of_week);
+    assert_eq!(line, expected, "Failed for input: {}", input);
+    assert_eq!(
+        recurrence_from_ical(&line),
+        Some((recurrence, start_of_week.clone())),
+        "Failed for input: {}",
+        input
+    );
+}
+
+fn assert_event_round_trip(event: Event) {
+    let text = event.to_ical().unwrap();
+
+    assert_eq!(Event::from_ical(&text), Some(event), "Failed for: {}", text);
+}
+
+fn date_time(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(year, month, day)
+        .unwrap()
+        .and_hms_opt(hour, minute, 0)
+        .unwrap()
+}
@@ -0,0 +1,213 @@
+use chrono::{NaiveDate, Weekday};
+use date_time_parser_multi_language::{
+    DateExpression, DateFormat, DateMatch, DateParser, DeDateParser, Month, ParseError,
+    StartDayOfWeek, StrippedDateExpression,
+};
+
+#[test]
+fn test_defaults() {
+    assert_eq!(DeDateParser::DATE_FORMAT, DateFormat::DayMonthYear);
+    assert_eq!(DeDateParser::START_DAY_OF_WEEK, StartDayOfWeek::Monday);
+}
+
+#[test]
+fn test_keywords() {
+    // the first of december 2024 was a sunday
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("Was steht heute an?", 2024, 12, 1, &now);
+    assert_date("Bob morgen anrufen", 2024, 12, 2, &now);
+    assert_date("Bob übermorgen anrufen", 2024, 12, 3, &now);
+}
+
+#[test]
+fn test_relative_weeks() {
+    // the first of december 2024 was a sunday
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("nächsten Montag", 2024, 12, 2, &now);
+    assert_date("am nächsten Dienstag", 2024, 12, 3, &now);
+    assert_date("am kommenden Freitag", 2024, 12, 6, &now);
+    assert_date("nächste Woche", 2024, 12, 2, &now);
+    assert_date("in drei Wochen", 2024, 12, 16, &now);
+    assert_date("Montag in zwei Wochen", 2024, 12, 9, &now);
+    assert_date("nächste Woche am Donnerstag", 2024, 12, 5, &now);
+    assert_date("in 3 Tagen", 2024, 12, 4, &now);
+}
+
+#[test]
+fn test_specific_dates() {
+    // the first of december 2024 was a sunday
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("am 5. Dezember", 2024, 12, 5, &now);
+    assert_date("am 3. Januar", 2025, 1, 3, &now);
+    assert_date("am 5. Dez. 2026", 2026, 12, 5, &now);
+    assert_date("am 24.12.2024", 2024, 12, 24, &now);
+    assert_date("am 2024-12-24", 2024, 12, 24, &now);
+    assert_date("Rechnung vom 5.12.26", 2026, 12, 5, &now);
+    assert_date("in 2025-W02-5", 2025, 1, 10, &now);
+}
+
+#[test]
+fn test_months_across_years() {
+    assert_date(
+        "Rechnung in drei Monaten bezahlen",
+        2025,
+        2,
+        28,
+        &NaiveDate::from_ymd_opt(2024, 11, 30).unwrap(),
+    );
+    assert_date(
+        "Rechnungen vom letzten Monat prüfen",
+        2024,
+        12,
+        31,
+        &NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+    );
+}
+
+#[test]
+fn test_date_match() {
+    // the first of december 2024 was a sunday
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_eq!(
+        DeDateParser::search_date_expression(
+            "Bob am KOMMENDEN Freitag anrufen",
+            &now,
+            &DeDateParser::DATE_FORMAT,
+            &DeDateParser::START_DAY_OF_WEEK
+        ),
+        Some(DateMatch {
+            date: NaiveDate::from_ymd_opt(2024, 12, 6).unwrap(),
+            expression: DateExpression::DayInXWeeks(1, Weekday::Fri),
+            range: 7..24,
+            text: String::from("KOMMENDEN Freitag"),
+        })
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+    let try_search = |input: &str| {
+        DeDateParser::try_search_date_expression(
+            input,
+            &now,
+            &DeDateParser::DATE_FORMAT,
+            &DeDateParser::START_DAY_OF_WEEK,
+        )
+    };
+
+    assert_eq!(
+        try_search("Miete zahlen"),
+        Err(ParseError::NoExpressionFound)
+    );
+
+    let error = try_search("Miete zahlen am 30.2.2026").unwrap_err();
+    assert_eq!(
+        error,
+        ParseError::InvalidDate {
+            expression: DateExpression::DayInMonthInYear(Month::February, 30, 2026),
+            range: 16..25,
+        }
+    );
+    assert_eq!(error.to_string(), "February has no 30th");
+}
+
+#[test]
+fn test_strip_date_expression() {
+    // the first of december 2024 was a sunday
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_title(
+        "Erinnere mich daran, nächsten Freitag die Miete zu zahlen",
+        "die Miete zu zahlen",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 6).unwrap()),
+        &now,
+    );
+    assert_title(
+        "Zahnarzt am 5. Dezember.",
+        "Zahnarzt.",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 5).unwrap()),
+        &now,
+    );
+    assert_title(
+        "Bericht bis 10.12.2024 abgeben",
+        "Bericht abgeben",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 10).unwrap()),
+        &now,
+    );
+    assert_title("Vergiss nicht die Milch", "die Milch", None, &now);
+}
+
+#[test]
+fn test_search_all_date_expressions() {
+    // the first of december 2024 was a sunday
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    let found: Vec<(String, NaiveDate)> = DeDateParser::search_all_date_expressions(
+        "Können wir uns morgen treffen? Sonst passt Dienstag nächste Woche oder der 10.12.",
+        &now,
+        &DeDateParser::DATE_FORMAT,
+        &DeDateParser::START_DAY_OF_WEEK,
+    )
+    .into_iter()
+    .map(|date_match| (date_match.text, date_match.date))
+    .collect();
+
+    assert_eq!(
+        found,
+        vec![
+            (
+                String::from("morgen"),
+                NaiveDate::from_ymd_opt(2024, 12, 2).unwrap()
+            ),
+            (
+                String::from("Dienstag nächste Woche"),
+                NaiveDate::from_ymd_opt(2024, 12, 3).unwrap()
+            ),
+            (
+                String::from("10.12"),
+                NaiveDate::from_ymd_opt(2024, 12, 10).unwrap()
+            ),
+        ]
+    );
+}
+
+fn assert_date(input: &str, year: i32, month: u32, day: u32, now: &NaiveDate) {
+    assert_eq!(
+        DeDateParser::search_relative_date_expression(
+            input,
+            now,
+            &DeDateParser::DATE_FORMAT,
+            &DeDateParser::START_DAY_OF_WEEK
+        ),
+        NaiveDate::from_ymd_opt(year, month, day),
+        "failed to parse {}",
+        input
+    );
+}
+
+fn assert_title(
+    input: &str,
+    expected_title: &str,
+    expected_date: Option<NaiveDate>,
+    now: &NaiveDate,
+) {
+    let StrippedDateExpression { title, date_match } = DeDateParser::strip_date_expression(
+        input,
+        now,
+        &DeDateParser::DATE_FORMAT,
+        &DeDateParser::START_DAY_OF_WEEK,
+    );
+
+    assert_eq!(title, expected_title, "failed to strip {}", input);
+    assert_eq!(
+        date_match.map(|date_match| date_match.date),
+        expected_date,
+        "failed to find the date in {}",
+        input
+    );
+}
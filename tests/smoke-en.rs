@@ -1,5 +1,9 @@
-use chrono::{Days, NaiveDate};
-use date_time_parser_multi_language::{DateFormat, DateParser, EnDateParser, StartDayOfWeek};
+use chrono::{Days, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use date_time_parser_multi_language::{
+    DateExpression, DateFormat, DateMatch, DateParser, DateRange, DateRangeParser, DateTimeParser,
+    EnDateParser, EnDateTimeParser, EnTimeParser, Month, ParseError, StartDayOfWeek,
+    StrippedDateExpression, TimeParser,
+};
 
 #[test]
 fn test_in_x_days() {
@@ -49,6 +53,61 @@ fn test_keywords() {
     // assert_in_x_days("tell me something yesterday", -1, &now, &date_format);
 }
 
+#[test]
+fn test_past_expressions() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+    let date_format = DateFormat::DayMonthYear;
+    let start_of_week = StartDayOfWeek::Monday;
+
+    assert_in_x_days(
+        "tell me something yesterday",
+        -1,
+        &now,
+        &date_format,
+        &start_of_week,
+    );
+    assert_in_x_days(
+        "what did I do the day before yesterday",
+        -2,
+        &now,
+        &date_format,
+        &start_of_week,
+    );
+
+    assert_in_x_days(
+        "I called Bob 3 days ago",
+        -3,
+        &now,
+        &date_format,
+        &start_of_week,
+    );
+    assert_in_x_days(
+        "we met two weeks ago",
+        -14,
+        &now,
+        &date_format,
+        &start_of_week,
+    );
+    assert_specific_date(
+        "the package arrived a month ago",
+        NaiveDate::from_ymd_opt(2024, 11, 1).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "the same day last year",
+        NaiveDate::from_ymd_opt(2023, 12, 1).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "I started three years ago",
+        NaiveDate::from_ymd_opt(2021, 12, 1).unwrap(),
+        &now,
+        &date_format,
+    );
+}
+
 #[test]
 fn test_relative_weeks_start_sunday() {
     // the first of december 2024 was a sunday
@@ -176,17 +235,15 @@ fn assert_in_x_days(
     date_format: &DateFormat,
     start_of_week: &StartDayOfWeek,
 ) {
-    let mut expected_date = now.clone();
-    if in_days >= 0 {
-        expected_date = now.checked_add_days(Days::new(in_days as u64)).unwrap();
+    let expected_date = if in_days >= 0 {
+        now.checked_add_days(Days::new(in_days as u64)).unwrap()
     } else {
-        expected_date = now
-            .checked_sub_days(Days::new(in_days.abs() as u64))
-            .unwrap();
-    }
+        now.checked_sub_days(Days::new(in_days.unsigned_abs() as u64))
+            .unwrap()
+    };
 
     assert_eq!(
-        EnDateParser::search_relative_date_expression(input, &now, date_format, &start_of_week),
+        EnDateParser::search_relative_date_expression(input, now, date_format, start_of_week),
         Some(expected_date),
         "Failed to parse \"{}\" to {}",
         input,
@@ -232,6 +289,148 @@ fn test_a_specific_date_month() {
     // );
 }
 
+#[test]
+fn test_ordinals() {
+    let now = NaiveDate::from_ymd_opt(2024, 10, 15).unwrap();
+    let date_format = DateFormat::DayMonthYear;
+
+    // a day without a month is the next day with this number
+    assert_specific_date(
+        "Do something on 10th",
+        NaiveDate::from_ymd_opt(2024, 11, 10).unwrap(),
+        &now,
+        &date_format,
+    );
+
+    assert_specific_date(
+        "Do something on june 1st",
+        NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Do something on the 22nd of may",
+        NaiveDate::from_ymd_opt(2025, 5, 22).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Do something on the first of april",
+        NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Do something on the twenty-first of december",
+        NaiveDate::from_ymd_opt(2024, 12, 21).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Do something on the 3rd",
+        NaiveDate::from_ymd_opt(2024, 11, 3).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Do something on the 31st",
+        NaiveDate::from_ymd_opt(2024, 10, 31).unwrap(),
+        &now,
+        &date_format,
+    );
+}
+
+#[test]
+fn test_iso_dates() {
+    let now = NaiveDate::from_ymd_opt(2024, 10, 15).unwrap();
+
+    for date_format in [
+        DateFormat::DayMonthYear,
+        DateFormat::MonthDayYear,
+        DateFormat::YearMonthDay,
+    ] {
+        assert_specific_date(
+            "Do something on 2026-12-05",
+            NaiveDate::from_ymd_opt(2026, 12, 5).unwrap(),
+            &now,
+            &date_format,
+        );
+        assert_specific_date(
+            "Do something on 2026-12-05T09:30",
+            NaiveDate::from_ymd_opt(2026, 12, 5).unwrap(),
+            &now,
+            &date_format,
+        );
+        assert_specific_date(
+            "Do something in 2026-W49-3",
+            NaiveDate::from_ymd_opt(2026, 12, 2).unwrap(),
+            &now,
+            &date_format,
+        );
+        assert_specific_date(
+            "Do something on 2026-339",
+            NaiveDate::from_ymd_opt(2026, 12, 5).unwrap(),
+            &now,
+            &date_format,
+        );
+    }
+}
+
+#[test]
+fn test_two_digit_years() {
+    let now = NaiveDate::from_ymd_opt(2024, 10, 15).unwrap();
+
+    assert_specific_date(
+        "Invoice from 5.12.26",
+        NaiveDate::from_ymd_opt(2026, 12, 5).unwrap(),
+        &now,
+        &DateFormat::DayMonthYear,
+    );
+    assert_specific_date(
+        "Invoice from 12/5/98",
+        NaiveDate::from_ymd_opt(1998, 12, 5).unwrap(),
+        &now,
+        &DateFormat::MonthDayYear,
+    );
+    assert_specific_date(
+        "Invoice from Dec '26",
+        NaiveDate::from_ymd_opt(2026, 12, 1).unwrap(),
+        &now,
+        &DateFormat::DayMonthYear,
+    );
+    assert_specific_date(
+        "Invoice from June 5th, '23",
+        NaiveDate::from_ymd_opt(2023, 6, 5).unwrap(),
+        &now,
+        &DateFormat::DayMonthYear,
+    );
+}
+
+#[test]
+fn test_year_month_day() {
+    let now = NaiveDate::from_ymd_opt(2024, 10, 15).unwrap();
+    let date_format = DateFormat::YearMonthDay;
+
+    assert_specific_date(
+        "Do something on 2026.12.5",
+        NaiveDate::from_ymd_opt(2026, 12, 5).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Do something on 2026/12/05",
+        NaiveDate::from_ymd_opt(2026, 12, 5).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Do something on 12.5",
+        NaiveDate::from_ymd_opt(2024, 12, 5).unwrap(),
+        &now,
+        &date_format,
+    );
+}
+
 #[test]
 fn test_a_specific_month_date() {
     let now = NaiveDate::from_ymd_opt(2024, 10, 1).unwrap();
@@ -297,6 +496,135 @@ fn test_a_specifig_month_date_year() {
     );
 }
 
+#[test]
+fn test_number_words() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+    let date_format = DateFormat::DayMonthYear;
+    let start_of_week = StartDayOfWeek::Monday;
+
+    assert_in_x_days(
+        "Do something in fifteen days",
+        15,
+        &now,
+        &date_format,
+        &start_of_week,
+    );
+    assert_in_x_days(
+        "Do something in a hundred days",
+        100,
+        &now,
+        &date_format,
+        &start_of_week,
+    );
+    assert_in_x_days(
+        "Do something in a few days",
+        3,
+        &now,
+        &date_format,
+        &start_of_week,
+    );
+    // weeks are counted from the start of this week, monday the 25th of november
+    assert_in_x_days(
+        "Do something in a couple of weeks",
+        8,
+        &now,
+        &date_format,
+        &start_of_week,
+    );
+    assert_in_x_days(
+        "Do something in twenty-one weeks",
+        141,
+        &now,
+        &date_format,
+        &start_of_week,
+    );
+    assert_specific_date(
+        "Do something in half a year",
+        NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Do something in six months",
+        NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
+        &now,
+        &date_format,
+    );
+}
+
+#[test]
+fn test_relative_years() {
+    let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+    let date_format = DateFormat::DayMonthYear;
+
+    assert_specific_date(
+        "Renew the contract next year",
+        NaiveDate::from_ymd_opt(2027, 10, 18).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Renew the contract in 2 years",
+        NaiveDate::from_ymd_opt(2028, 10, 18).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Let's meet again this time next year",
+        NaiveDate::from_ymd_opt(2027, 10, 18).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Renew the contract in december next year",
+        NaiveDate::from_ymd_opt(2027, 12, 1).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Renew the contract on june 5th next year",
+        NaiveDate::from_ymd_opt(2027, 6, 5).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Renew the contract in june 2027",
+        NaiveDate::from_ymd_opt(2027, 6, 1).unwrap(),
+        &now,
+        &date_format,
+    );
+    assert_specific_date(
+        "Renew the contract on june 5th 2028",
+        NaiveDate::from_ymd_opt(2028, 6, 5).unwrap(),
+        &now,
+        &date_format,
+    );
+}
+
+#[test]
+fn test_months_across_years() {
+    let date_format = DateFormat::DayMonthYear;
+
+    assert_specific_date(
+        "Pay the bill in 3 months",
+        NaiveDate::from_ymd_opt(2025, 2, 15).unwrap(),
+        &NaiveDate::from_ymd_opt(2024, 11, 15).unwrap(),
+        &date_format,
+    );
+    assert_specific_date(
+        "Check the invoices of last month",
+        NaiveDate::from_ymd_opt(2024, 12, 15).unwrap(),
+        &NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+        &date_format,
+    );
+    assert_specific_date(
+        "Pay the bill next month",
+        NaiveDate::from_ymd_opt(2025, 2, 28).unwrap(),
+        &NaiveDate::from_ymd_opt(2025, 1, 31).unwrap(),
+        &date_format,
+    );
+}
+
 fn assert_specific_date(
     input: &str,
     expected_date: NaiveDate,
@@ -313,3 +641,419 @@ fn assert_specific_date(
         expected_date
     );
 }
+
+#[test]
+fn test_time_of_day() {
+    assert_time("Remind me at 5pm", NaiveTime::from_hms_opt(17, 0, 0));
+    assert_time("Meeting at 17:30", NaiveTime::from_hms_opt(17, 30, 0));
+    assert_time("Lunch at noon", NaiveTime::from_hms_opt(12, 0, 0));
+    assert_time("Call mom at midnight", NaiveTime::from_hms_opt(0, 0, 0));
+    assert_time("Meet at half past nine", NaiveTime::from_hms_opt(9, 30, 0));
+    assert_time("Meet at quarter to 8", NaiveTime::from_hms_opt(7, 45, 0));
+    assert_time("Remind me tomorrow", None);
+    assert_time("Invite 2 to 3 friends", None);
+    assert_time("Table for 5 to 10 people", None);
+}
+
+fn assert_time(input: &str, expected_time: Option<NaiveTime>) {
+    assert_eq!(
+        EnTimeParser::search_time(input),
+        expected_time,
+        "failed to parse {} as {:?}",
+        input,
+        expected_time
+    );
+}
+
+#[test]
+fn test_date_and_time() {
+    // the first of december 2024 was a sunday
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1)
+        .unwrap()
+        .and_hms_opt(10, 0, 0)
+        .unwrap();
+
+    assert_date_time(
+        "Call Bob tomorrow at 3pm",
+        NaiveDate::from_ymd_opt(2024, 12, 2)
+            .unwrap()
+            .and_hms_opt(15, 0, 0),
+        &now,
+        &DateFormat::DayMonthYear,
+    );
+    assert_date_time(
+        "Meeting next friday 09:00",
+        NaiveDate::from_ymd_opt(2024, 12, 6)
+            .unwrap()
+            .and_hms_opt(9, 0, 0),
+        &now,
+        &DateFormat::DayMonthYear,
+    );
+    assert_date_time(
+        "Party on 5.12.2026 14:30",
+        NaiveDate::from_ymd_opt(2026, 12, 5)
+            .unwrap()
+            .and_hms_opt(14, 30, 0),
+        &now,
+        &DateFormat::DayMonthYear,
+    );
+    assert_date_time(
+        "Party on 12.5.2026 at half past seven pm",
+        NaiveDate::from_ymd_opt(2026, 12, 5)
+            .unwrap()
+            .and_hms_opt(19, 30, 0),
+        &now,
+        &DateFormat::MonthDayYear,
+    );
+}
+
+#[test]
+fn test_date_and_time_fall_back() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1)
+        .unwrap()
+        .and_hms_opt(10, 0, 0)
+        .unwrap();
+
+    // only a date falls back to the default time
+    assert_date_time(
+        "Call Bob in 3 days",
+        NaiveDate::from_ymd_opt(2024, 12, 4)
+            .unwrap()
+            .and_hms_opt(8, 0, 0),
+        &now,
+        &DateFormat::DayMonthYear,
+    );
+    // only a time is the next occurrence of this time
+    assert_date_time(
+        "Call Bob at noon",
+        NaiveDate::from_ymd_opt(2024, 12, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0),
+        &now,
+        &DateFormat::DayMonthYear,
+    );
+    assert_date_time(
+        "Call Bob at 9am",
+        NaiveDate::from_ymd_opt(2024, 12, 2)
+            .unwrap()
+            .and_hms_opt(9, 0, 0),
+        &now,
+        &DateFormat::DayMonthYear,
+    );
+    assert_date_time("Call Bob", None, &now, &DateFormat::DayMonthYear);
+    // a count is no time
+    assert_date_time(
+        "buy 2 to 3 apples tomorrow",
+        NaiveDate::from_ymd_opt(2024, 12, 2)
+            .unwrap()
+            .and_hms_opt(8, 0, 0),
+        &now,
+        &DateFormat::DayMonthYear,
+    );
+}
+
+fn assert_date_time(
+    input: &str,
+    expected_date_time: Option<NaiveDateTime>,
+    now: &NaiveDateTime,
+    date_format: &DateFormat,
+) {
+    let start_of_week = StartDayOfWeek::Monday;
+    let default_time = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+
+    assert_eq!(
+        EnDateTimeParser::search_relative_date_time_expression(
+            input,
+            now,
+            date_format,
+            &start_of_week,
+            &default_time
+        ),
+        expected_date_time,
+        "failed to parse {} as {:?}",
+        input,
+        expected_date_time
+    );
+}
+
+#[test]
+fn test_date_match() {
+    // the first of december 2024 was a sunday
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date_match(
+        "call Bob next monday about taxes",
+        Some(DateMatch {
+            date: NaiveDate::from_ymd_opt(2024, 12, 2).unwrap(),
+            expression: DateExpression::DayInXWeeks(1, Weekday::Mon),
+            range: 9..20,
+            text: String::from("next monday"),
+        }),
+        &now,
+    );
+    assert_date_match(
+        "Call Bob IN THREE DAYS",
+        Some(DateMatch {
+            date: NaiveDate::from_ymd_opt(2024, 12, 4).unwrap(),
+            expression: DateExpression::InXDays(3),
+            range: 9..22,
+            text: String::from("IN THREE DAYS"),
+        }),
+        &now,
+    );
+    assert_date_match(
+        "Pay the rent on 10.12.2025, please",
+        Some(DateMatch {
+            date: NaiveDate::from_ymd_opt(2025, 12, 10).unwrap(),
+            expression: DateExpression::DayInMonthInYear(Month::December, 10, 2025),
+            range: 16..26,
+            text: String::from("10.12.2025"),
+        }),
+        &now,
+    );
+    assert_date_match("Pay the rent", None, &now);
+}
+
+fn assert_date_match(input: &str, expected_match: Option<DateMatch>, now: &NaiveDate) {
+    assert_eq!(
+        EnDateParser::search_date_expression(
+            input,
+            now,
+            &DateFormat::DayMonthYear,
+            &StartDayOfWeek::Monday
+        ),
+        expected_match,
+        "failed to match {}",
+        input
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+    let try_search = |input: &str| {
+        EnDateParser::try_search_date_expression(
+            input,
+            &now,
+            &DateFormat::DayMonthYear,
+            &StartDayOfWeek::Monday,
+        )
+    };
+
+    assert_eq!(
+        try_search("Pay the rent"),
+        Err(ParseError::NoExpressionFound)
+    );
+
+    let error = try_search("Pay the rent on February 30th").unwrap_err();
+    assert_eq!(
+        error,
+        ParseError::InvalidDate {
+            expression: DateExpression::DayInMonth(Month::February, 30),
+            range: 16..29,
+        }
+    );
+    assert_eq!(error.to_string(), "February has no 30th");
+
+    let error = try_search("Pay the rent on 31.4.2025").unwrap_err();
+    assert_eq!(error.range(), Some(16..25));
+    assert_eq!(error.to_string(), "April has no 31st");
+
+    let error = try_search("Pay the rent in 2025-W53").unwrap_err();
+    assert_eq!(error.range(), Some(16..24));
+    assert_eq!(error.to_string(), "2025 has no week 53");
+}
+
+#[test]
+fn test_strip_date_expression() {
+    // the first of december 2024 was a sunday
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_title(
+        "Remind me to pay rent next friday",
+        "pay rent",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 6).unwrap()),
+        &now,
+    );
+    assert_title(
+        "Submit the report by 10.12.",
+        "Submit the report.",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 10).unwrap()),
+        &now,
+    );
+    assert_title(
+        "Remind me that I have to check my mails in 3 days",
+        "I have to check my mails",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 4).unwrap()),
+        &now,
+    );
+    assert_title(
+        "Call  mom on tuesday next week about the party",
+        "Call mom about the party",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 3).unwrap()),
+        &now,
+    );
+    assert_title(
+        "Remind me to turn the light on",
+        "turn the light on",
+        None,
+        &now,
+    );
+}
+
+fn assert_title(
+    input: &str,
+    expected_title: &str,
+    expected_date: Option<NaiveDate>,
+    now: &NaiveDate,
+) {
+    let StrippedDateExpression { title, date_match } = EnDateParser::strip_date_expression(
+        input,
+        now,
+        &DateFormat::DayMonthYear,
+        &StartDayOfWeek::Monday,
+    );
+
+    assert_eq!(title, expected_title, "failed to strip {}", input);
+    assert_eq!(
+        date_match.map(|date_match| date_match.date),
+        expected_date,
+        "failed to find the date in {}",
+        input
+    );
+}
+
+#[test]
+fn test_search_all_date_expressions() {
+    // the first of december 2024 was a sunday
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_all_dates(
+        "move the meeting from monday to thursday",
+        vec![
+            ("monday", NaiveDate::from_ymd_opt(2024, 11, 25).unwrap()),
+            ("thursday", NaiveDate::from_ymd_opt(2024, 11, 28).unwrap()),
+        ],
+        &now,
+    );
+    assert_all_dates(
+        "Hi Bob, can we meet tomorrow? If that fails, 10.12 or tuesday next week works for me.",
+        vec![
+            ("tomorrow", NaiveDate::from_ymd_opt(2024, 12, 2).unwrap()),
+            ("10.12", NaiveDate::from_ymd_opt(2024, 12, 10).unwrap()),
+            (
+                "tuesday next week",
+                NaiveDate::from_ymd_opt(2024, 12, 3).unwrap(),
+            ),
+        ],
+        &now,
+    );
+    assert_all_dates("No dates in here", vec![], &now);
+}
+
+fn assert_all_dates(input: &str, expected: Vec<(&str, NaiveDate)>, now: &NaiveDate) {
+    let found: Vec<(String, NaiveDate)> = EnDateParser::search_all_date_expressions(
+        input,
+        now,
+        &DateFormat::DayMonthYear,
+        &StartDayOfWeek::Monday,
+    )
+    .into_iter()
+    .map(|date_match| {
+        assert_eq!(input[date_match.range.clone()], date_match.text);
+        (date_match.text, date_match.date)
+    })
+    .collect();
+    let expected: Vec<(String, NaiveDate)> = expected
+        .into_iter()
+        .map(|(text, date)| (text.to_string(), date))
+        .collect();
+
+    assert_eq!(found, expected, "failed to find all dates in {}", input);
+}
+
+#[test]
+fn test_date_ranges() {
+    // the first of december 2024 was a sunday
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date_range(
+        "Vacation from june 3rd to june 7th",
+        Some((2025, 6, 3, 2025, 6, 7)),
+        &now,
+    );
+    assert_date_range(
+        "Vacation between tuesday next week and friday next week",
+        Some((2024, 12, 3, 2024, 12, 6)),
+        &now,
+    );
+    assert_date_range(
+        "Vacation 3.-7.12.2026",
+        Some((2026, 12, 3, 2026, 12, 7)),
+        &now,
+    );
+    assert_date_range(
+        "Vacation 5.12 - 7.12",
+        Some((2024, 12, 5, 2024, 12, 7)),
+        &now,
+    );
+    assert_date_range(
+        "Vacation 28.12 - 2.1",
+        Some((2024, 12, 28, 2025, 1, 2)),
+        &now,
+    );
+    assert_date_range(
+        "Vacation from friday to monday",
+        Some((2024, 11, 29, 2024, 12, 2)),
+        &now,
+    );
+    assert_date_range(
+        "Vacation until 10.12",
+        Some((2024, 12, 1, 2024, 12, 10)),
+        &now,
+    );
+    assert_date_range("Vacation next week", Some((2024, 12, 2, 2024, 12, 8)), &now);
+    assert_date_range("Vacation until 10.11.2024", None, &now);
+    assert_date_range("Vacation tomorrow", None, &now);
+
+    let sunday = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+
+    assert_date_range("until friday", Some((2026, 10, 18, 2026, 10, 23)), &sunday);
+    assert_date_range(
+        "I'm away until saturday",
+        Some((2026, 10, 18, 2026, 10, 24)),
+        &sunday,
+    );
+    assert_date_range(
+        "Vacation until 3.10",
+        Some((2026, 10, 18, 2027, 10, 3)),
+        &sunday,
+    );
+    assert_date_range("Vacation until last friday", None, &sunday);
+}
+
+fn assert_date_range(
+    input: &str,
+    expected: Option<(i32, u32, u32, i32, u32, u32)>,
+    now: &NaiveDate,
+) {
+    let expected = expected.map(
+        |(start_y, start_m, start_d, end_y, end_m, end_d)| DateRange {
+            start: NaiveDate::from_ymd_opt(start_y, start_m, start_d).unwrap(),
+            end: NaiveDate::from_ymd_opt(end_y, end_m, end_d).unwrap(),
+        },
+    );
+
+    assert_eq!(
+        EnDateParser::search_date_range(
+            input,
+            now,
+            &DateFormat::DayMonthYear,
+            &StartDayOfWeek::Monday
+        )
+        .map(|range_match| range_match.date_range),
+        expected,
+        "failed to parse the range of {}",
+        input
+    );
+}
@@ -0,0 +1,221 @@
+use chrono::NaiveDate;
+use date_time_parser_multi_language::{
+    DateExpression, DateFormat, DateParser, EsDateParser, Language, Month, ParseError, Parser,
+    StrippedDateExpression,
+};
+
+// Unless a test says otherwise, it runs on sunday, the first of december 2024.
+
+#[test]
+fn test_tilde_and_accents() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("Llamar a Bob mañana", 2024, 12, 2, &now);
+    assert_date("Llamar a Bob manana", 2024, 12, 2, &now);
+    assert_date("Llamar a Bob pasado manana", 2024, 12, 3, &now);
+    assert_date("el año que viene", 2025, 12, 1, &now);
+    assert_date("el ano que viene", 2025, 12, 1, &now);
+    assert_date("el próximo sábado", 2024, 12, 7, &now);
+    assert_date("el proximo sabado", 2024, 12, 7, &now);
+    assert_date("el proximo miercoles", 2024, 12, 4, &now);
+    assert_date("dentro de tres dias", 2024, 12, 4, &now);
+}
+
+#[test]
+fn test_inverted_punctuation() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_text("¿Qué hacemos hoy?", "hoy", &now);
+    assert_text("¡Llama a Bob mañana!", "mañana", &now);
+    assert_text(
+        "¿Nos vemos el martes de la semana que viene?",
+        "el martes de la semana que viene",
+        &now,
+    );
+}
+
+#[test]
+fn test_morning_ambiguity() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_text("mañana por la mañana", "mañana por la mañana", &now);
+    assert_date("mañana por la mañana", 2024, 12, 2, &now);
+    assert_date("mañana por la noche", 2024, 12, 2, &now);
+    assert_date("esta mañana", 2024, 12, 1, &now);
+    assert_no_date("Correr por la mañana", &now);
+    assert_no_date("Correr por la manana", &now);
+
+    assert_title(
+        "Llamar a Bob mañana por la mañana",
+        "Llamar a Bob",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 2).unwrap()),
+        &now,
+    );
+}
+
+#[test]
+fn test_que_viene_and_pasado() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("la semana que viene", 2024, 12, 2, &now);
+    assert_date("el mes que viene", 2025, 1, 1, &now);
+    assert_date("el viernes pasado", 2024, 11, 22, &now);
+    assert_date("el año pasado", 2023, 12, 1, &now);
+    assert_date("pasado mañana", 2024, 12, 3, &now);
+    assert_date("hace tres días", 2024, 11, 28, &now);
+}
+
+#[test]
+fn test_months_with_de_and_del() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("el 5 de diciembre", 2024, 12, 5, &now);
+    assert_date("el primero de mayo", 2025, 5, 1, &now);
+    assert_date("el 1 de enero de 2026", 2026, 1, 1, &now);
+    assert_text("Balance en diciembre de 2027", "en diciembre de 2027", &now);
+    assert_date("en diciembre de 2027", 2027, 12, 1, &now);
+    assert_date("en junio del 2027", 2027, 6, 1, &now);
+    assert_text(
+        "Esquí en febrero del año que viene",
+        "en febrero del año que viene",
+        &now,
+    );
+    assert_date("en febrero del año que viene", 2025, 2, 1, &now);
+}
+
+#[test]
+fn test_number_words() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("dentro de quince días", 2024, 12, 16, &now);
+    assert_date("dentro de veinte días", 2024, 12, 21, &now);
+    assert_date("en veintiún días", 2024, 12, 22, &now);
+    assert_date("en veintiun dias", 2024, 12, 22, &now);
+    assert_date("dentro de treinta y un días", 2025, 1, 1, &now);
+    assert_date("hace dieciséis días", 2024, 11, 15, &now);
+    assert_date("el veinte de mayo", 2025, 5, 20, &now);
+    assert_date("el treinta y uno de diciembre", 2024, 12, 31, &now);
+    assert_date("el veintidós de junio del año que viene", 2025, 6, 22, &now);
+}
+
+#[test]
+fn test_invalid_dates() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    let error = EsDateParser::try_search_date_expression(
+        "Pagar el alquiler el 30 de febrero",
+        &now,
+        &EsDateParser::DATE_FORMAT,
+        &EsDateParser::START_DAY_OF_WEEK,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error,
+        ParseError::InvalidDate {
+            expression: DateExpression::DayInMonth(Month::February, 30),
+            range: 18..34,
+        }
+    );
+    assert_eq!(error.to_string(), "February has no 30th");
+}
+
+#[test]
+fn test_strip_date_expression() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_title(
+        "Recuérdame que pague el alquiler el próximo lunes",
+        "pague el alquiler",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 2).unwrap()),
+        &now,
+    );
+    assert_title(
+        "Dentista el 5 de diciembre.",
+        "Dentista.",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 5).unwrap()),
+        &now,
+    );
+    assert_title("No olvides la leche", "la leche", None, &now);
+}
+
+#[test]
+fn test_parser() {
+    let parser = Parser::builder()
+        .language(Language::Spanish)
+        .reference_date(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap())
+        .build();
+
+    assert_eq!(parser.config().date_format, DateFormat::DayMonthYear);
+    assert_eq!(
+        parser
+            .parse("Fiesta el 5/12")
+            .map(|date_match| date_match.date),
+        Ok(NaiveDate::from_ymd_opt(2024, 12, 5).unwrap())
+    );
+}
+
+fn assert_date(input: &str, year: i32, month: u32, day: u32, now: &NaiveDate) {
+    assert_eq!(
+        EsDateParser::search_relative_date_expression(
+            input,
+            now,
+            &EsDateParser::DATE_FORMAT,
+            &EsDateParser::START_DAY_OF_WEEK
+        ),
+        NaiveDate::from_ymd_opt(year, month, day),
+        "failed to parse {}",
+        input
+    );
+}
+
+fn assert_no_date(input: &str, now: &NaiveDate) {
+    assert_eq!(
+        EsDateParser::search_relative_date_expression(
+            input,
+            now,
+            &EsDateParser::DATE_FORMAT,
+            &EsDateParser::START_DAY_OF_WEEK
+        ),
+        None,
+        "found a date in {}",
+        input
+    );
+}
+
+fn assert_text(input: &str, expected_text: &str, now: &NaiveDate) {
+    assert_eq!(
+        EsDateParser::search_date_expression(
+            input,
+            now,
+            &EsDateParser::DATE_FORMAT,
+            &EsDateParser::START_DAY_OF_WEEK
+        )
+        .map(|date_match| date_match.text),
+        Some(String::from(expected_text)),
+        "failed to match {}",
+        input
+    );
+}
+
+fn assert_title(
+    input: &str,
+    expected_title: &str,
+    expected_date: Option<NaiveDate>,
+    now: &NaiveDate,
+) {
+    let StrippedDateExpression { title, date_match } = EsDateParser::strip_date_expression(
+        input,
+        now,
+        &EsDateParser::DATE_FORMAT,
+        &EsDateParser::START_DAY_OF_WEEK,
+    );
+
+    assert_eq!(title, expected_title, "failed to strip {}", input);
+    assert_eq!(
+        date_match.map(|date_match| date_match.date),
+        expected_date,
+        "failed to find the date in {}",
+        input
+    );
+}
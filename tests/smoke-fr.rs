@@ -0,0 +1,209 @@
+use chrono::NaiveDate;
+use date_time_parser_multi_language::{
+    DateExpression, DateFormat, DateParser, FrDateParser, Language, Month, ParseError, Parser,
+    StrippedDateExpression,
+};
+
+// Unless a test says otherwise, it runs on sunday, the first of december 2024.
+
+#[test]
+fn test_elisions() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_text("Qu'est-ce qu'on fait aujourd'hui ?", "aujourd'hui", &now);
+    assert_text("Qu’est-ce qu’on fait aujourd’hui ?", "aujourd’hui", &now);
+    assert_date("l'année prochaine", 2025, 12, 1, &now);
+    assert_date("l’année prochaine", 2025, 12, 1, &now);
+    assert_date("l’an dernier", 2023, 12, 1, &now);
+    assert_date("d'ici trois jours", 2024, 12, 4, &now);
+    assert_text(
+        "Ski en février de l'année prochaine",
+        "en février de l'année prochaine",
+        &now,
+    );
+    assert_date("en février de l'année prochaine", 2025, 2, 1, &now);
+
+    assert_title(
+        "Appeler Bob jusqu'à vendredi",
+        "Appeler Bob",
+        Some(NaiveDate::from_ymd_opt(2024, 11, 29).unwrap()),
+        &now,
+    );
+    assert_title(
+        "Appeler Bob jusqu’à vendredi",
+        "Appeler Bob",
+        Some(NaiveDate::from_ymd_opt(2024, 11, 29).unwrap()),
+        &now,
+    );
+    assert_title("N'oublie pas le lait", "le lait", None, &now);
+    assert_title("N’oublie pas le lait", "le lait", None, &now);
+}
+
+#[test]
+fn test_without_accents() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("Appeler Bob après-demain", 2024, 12, 3, &now);
+    assert_date("Appeler Bob apres-demain", 2024, 12, 3, &now);
+    assert_date("le 5 décembre", 2024, 12, 5, &now);
+    assert_date("le 5 decembre", 2024, 12, 5, &now);
+    assert_date("Bob a appelé avant-hier", 2024, 11, 29, &now);
+}
+
+#[test]
+fn test_number_words() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("dans treize jours", 2024, 12, 14, &now);
+    assert_date("dans vingt et un jours", 2024, 12, 22, &now);
+    assert_date("dans vingt-et-un jours", 2024, 12, 22, &now);
+    assert_date("il y a quatre-vingt-dix jours", 2024, 9, 2, &now);
+    assert_date("dans trois semaines", 2024, 12, 16, &now);
+    assert_date("le deux mai", 2025, 5, 2, &now);
+    assert_date("le premier mai", 2025, 5, 1, &now);
+    assert_date("le 1er janvier 2026", 2026, 1, 1, &now);
+}
+
+#[test]
+fn test_weeks() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("lundi prochain", 2024, 12, 2, &now);
+    assert_date("vendredi dernier", 2024, 11, 22, &now);
+    assert_date("la semaine prochaine", 2024, 12, 2, &now);
+    assert_date("lundi dans deux semaines", 2024, 12, 9, &now);
+    assert_date("la semaine prochaine, le jeudi", 2024, 12, 5, &now);
+    assert_text(
+        "On se voit mardi de la semaine prochaine ?",
+        "mardi de la semaine prochaine",
+        &now,
+    );
+}
+
+#[test]
+fn test_day_of_month() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_text("Réunion le 22", "le 22", &now);
+    assert_date("Réunion le 22", 2024, 12, 22, &now);
+    assert_date("Réunion le 1er", 2024, 12, 1, &now);
+    assert_date("le 24/12/2024", 2024, 12, 24, &now);
+}
+
+#[test]
+fn test_months_and_years() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_text("Bilan décembre 2027", "décembre 2027", &now);
+    assert_date("Bilan décembre 2027", 2027, 12, 1, &now);
+    assert_date("le mois prochain", 2025, 1, 1, &now);
+    assert_date("dans deux mois", 2025, 2, 1, &now);
+}
+
+#[test]
+fn test_invalid_dates() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    let error = FrDateParser::try_search_date_expression(
+        "Payer le loyer le 30 février",
+        &now,
+        &FrDateParser::DATE_FORMAT,
+        &FrDateParser::START_DAY_OF_WEEK,
+    )
+    .unwrap_err();
+
+    // `é` takes two bytes
+    assert_eq!(
+        error,
+        ParseError::InvalidDate {
+            expression: DateExpression::DayInMonth(Month::February, 30),
+            range: 15..29,
+        }
+    );
+    assert_eq!(error.to_string(), "February has no 30th");
+}
+
+#[test]
+fn test_strip_date_expression() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_title(
+        "Rappelle-moi de payer le loyer lundi prochain",
+        "payer le loyer",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 2).unwrap()),
+        &now,
+    );
+    assert_title(
+        "Dentiste le 5 décembre.",
+        "Dentiste.",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 5).unwrap()),
+        &now,
+    );
+}
+
+#[test]
+fn test_parser() {
+    let parser = Parser::builder()
+        .language(Language::French)
+        .reference_date(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap())
+        .build();
+
+    assert_eq!(parser.config().date_format, DateFormat::DayMonthYear);
+    assert_eq!(
+        parser
+            .parse("Fête le 5/12")
+            .map(|date_match| date_match.date),
+        Ok(NaiveDate::from_ymd_opt(2024, 12, 5).unwrap())
+    );
+}
+
+fn assert_date(input: &str, year: i32, month: u32, day: u32, now: &NaiveDate) {
+    assert_eq!(
+        FrDateParser::search_relative_date_expression(
+            input,
+            now,
+            &FrDateParser::DATE_FORMAT,
+            &FrDateParser::START_DAY_OF_WEEK
+        ),
+        NaiveDate::from_ymd_opt(year, month, day),
+        "failed to parse {}",
+        input
+    );
+}
+
+fn assert_text(input: &str, expected_text: &str, now: &NaiveDate) {
+    assert_eq!(
+        FrDateParser::search_date_expression(
+            input,
+            now,
+            &FrDateParser::DATE_FORMAT,
+            &FrDateParser::START_DAY_OF_WEEK
+        )
+        .map(|date_match| date_match.text),
+        Some(String::from(expected_text)),
+        "failed to match {}",
+        input
+    );
+}
+
+fn assert_title(
+    input: &str,
+    expected_title: &str,
+    expected_date: Option<NaiveDate>,
+    now: &NaiveDate,
+) {
+    let StrippedDateExpression { title, date_match } = FrDateParser::strip_date_expression(
+        input,
+        now,
+        &FrDateParser::DATE_FORMAT,
+        &FrDateParser::START_DAY_OF_WEEK,
+    );
+
+    assert_eq!(title, expected_title, "failed to strip {}", input);
+    assert_eq!(
+        date_match.map(|date_match| date_match.date),
+        expected_date,
+        "failed to find the date in {}",
+        input
+    );
+}
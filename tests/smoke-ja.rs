@@ -0,0 +1,196 @@
+use chrono::NaiveDate;
+use date_time_parser_multi_language::{
+    DateExpression, DateFormat, DateParser, JaDateParser, Language, Month, ParseError, Parser,
+    StrippedDateExpression,
+};
+
+// Unless a test says otherwise, it runs on sunday, the first of december 2024.
+
+#[test]
+fn test_without_spaces() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_text("今日は何をする？", "今日", &now);
+    assert_text("明日ボブに電話する", "明日", &now);
+    assert_text("明後日ボブに電話する", "明後日", &now);
+    assert_text("ボブは一昨日電話した", "一昨日", &now);
+    assert_text("2024/12/24に", "2024/12/24", &now);
+    assert_date("2024-12-24に", 2024, 12, 24, &now);
+}
+
+#[test]
+fn test_particles() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_text("来週の月曜日に会議", "来週の月曜日", &now);
+    assert_date("来週の月曜日に会議", 2024, 12, 2, &now);
+    assert_date("先週の金曜日", 2024, 11, 22, &now);
+    assert_text("二週間後の月曜日に", "二週間後の月曜日", &now);
+    assert_date("二週間後の月曜日に", 2024, 12, 9, &now);
+    assert_text("来年の12月に引っ越す", "来年の12月", &now);
+    assert_date("来年の12月に引っ越す", 2025, 12, 1, &now);
+    assert_text("来年12月5日に引っ越す", "来年12月5日", &now);
+    assert_date("来年12月5日に引っ越す", 2025, 12, 5, &now);
+
+    assert_title(
+        "来週の月曜日に家賃を払う",
+        "家賃を払う",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 2).unwrap()),
+        &now,
+    );
+    assert_title(
+        "レポートを金曜日までに出す",
+        "レポートを出す",
+        Some(NaiveDate::from_ymd_opt(2024, 11, 29).unwrap()),
+        &now,
+    );
+}
+
+#[test]
+fn test_kanji_and_full_width_numerals() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("十二月五日に会議", 2024, 12, 5, &now);
+    assert_date("１２月５日に会議", 2024, 12, 5, &now);
+    assert_date("来年の１２月", 2025, 12, 1, &now);
+    assert_date("三日後", 2024, 12, 4, &now);
+    assert_date("３日後", 2024, 12, 4, &now);
+    assert_date("2ヶ月後", 2025, 2, 1, &now);
+}
+
+#[test]
+fn test_after_and_before() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("3日後", 2024, 12, 4, &now);
+    assert_date("2週間後", 2024, 12, 9, &now);
+    assert_text("三日前に電話した", "三日前", &now);
+    assert_date("三日前に電話した", 2024, 11, 28, &now);
+    // `前に` after a date means before that date, not days ago
+    assert_text("12月5日前に", "12月5日", &now);
+    assert_date("12月5日前に", 2024, 12, 5, &now);
+}
+
+#[test]
+fn test_weeks_ago() {
+    // the 18th of october 2026 is a sunday
+    let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+
+    assert_date("1週間前", 2026, 10, 11, &now);
+    assert_date("一週間前", 2026, 10, 11, &now);
+    assert_date("2週間前に会った", 2026, 10, 4, &now);
+}
+
+#[test]
+fn test_months_and_years() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("2026年12月5日", 2026, 12, 5, &now);
+    assert_date("5月1日", 2025, 5, 1, &now);
+    assert_date("来月", 2025, 1, 1, &now);
+    assert_date("来年", 2025, 12, 1, &now);
+    assert_date("去年", 2023, 12, 1, &now);
+}
+
+#[test]
+fn test_invalid_dates() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    let error = JaDateParser::try_search_date_expression(
+        "家賃を2月30日に払う",
+        &now,
+        &JaDateParser::DATE_FORMAT,
+        &JaDateParser::START_DAY_OF_WEEK,
+    )
+    .unwrap_err();
+
+    // every kanji takes three bytes
+    assert_eq!(
+        error,
+        ParseError::InvalidDate {
+            expression: DateExpression::DayInMonth(Month::February, 30),
+            range: 9..18,
+        }
+    );
+    assert_eq!(error.to_string(), "February has no 30th");
+}
+
+#[test]
+fn test_strip_date_expression() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_title(
+        "歯医者、12月5日。",
+        "歯医者。",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 5).unwrap()),
+        &now,
+    );
+    assert_title("牛乳を買う", "牛乳を買う", None, &now);
+}
+
+#[test]
+fn test_parser() {
+    let parser = Parser::builder()
+        .language(Language::Japanese)
+        .reference_date(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap())
+        .build();
+
+    assert_eq!(parser.config().date_format, DateFormat::YearMonthDay);
+    assert_eq!(
+        parser
+            .parse("12/5にパーティー")
+            .map(|date_match| date_match.date),
+        Ok(NaiveDate::from_ymd_opt(2024, 12, 5).unwrap())
+    );
+}
+
+fn assert_date(input: &str, year: i32, month: u32, day: u32, now: &NaiveDate) {
+    assert_eq!(
+        JaDateParser::search_relative_date_expression(
+            input,
+            now,
+            &JaDateParser::DATE_FORMAT,
+            &JaDateParser::START_DAY_OF_WEEK
+        ),
+        NaiveDate::from_ymd_opt(year, month, day),
+        "failed to parse {}",
+        input
+    );
+}
+
+fn assert_text(input: &str, expected_text: &str, now: &NaiveDate) {
+    assert_eq!(
+        JaDateParser::search_date_expression(
+            input,
+            now,
+            &JaDateParser::DATE_FORMAT,
+            &JaDateParser::START_DAY_OF_WEEK
+        )
+        .map(|date_match| date_match.text),
+        Some(String::from(expected_text)),
+        "failed to match {}",
+        input
+    );
+}
+
+fn assert_title(
+    input: &str,
+    expected_title: &str,
+    expected_date: Option<NaiveDate>,
+    now: &NaiveDate,
+) {
+    let StrippedDateExpression { title, date_match } = JaDateParser::strip_date_expression(
+        input,
+        now,
+        &JaDateParser::DATE_FORMAT,
+        &JaDateParser::START_DAY_OF_WEEK,
+    );
+
+    assert_eq!(title, expected_title, "failed to strip {}", input);
+    assert_eq!(
+        date_match.map(|date_match| date_match.date),
+        expected_date,
+        "failed to find the date in {}",
+        input
+    );
+}
@@ -0,0 +1,199 @@
+use chrono::NaiveDate;
+use date_time_parser_multi_language::{
+    DateExpression, DateFormat, DateParser, Language, Month, ParseError, Parser, RuDateParser,
+    StrippedDateExpression,
+};
+
+// Unless a test says otherwise, it runs on sunday, the first of december 2024.
+
+#[test]
+fn test_case_endings_of_weekdays() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("в следующий понедельник", 2024, 12, 2, &now);
+    assert_date("в прошлую пятницу", 2024, 11, 22, &now);
+    assert_date("в среду на следующей неделе", 2024, 12, 4, &now);
+    assert_text(
+        "Увидимся во вторник на следующей неделе?",
+        "во вторник на следующей неделе",
+        &now,
+    );
+    assert_title(
+        "Сдать отчёт до пятницы",
+        "Сдать отчёт",
+        Some(NaiveDate::from_ymd_opt(2024, 11, 29).unwrap()),
+        &now,
+    );
+}
+
+#[test]
+fn test_case_endings_of_months() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("5 декабря", 2024, 12, 5, &now);
+    assert_date("5-го декабря", 2024, 12, 5, &now);
+    assert_date("1 января 2026 года", 2026, 1, 1, &now);
+    assert_date("декабрь 2026", 2026, 12, 1, &now);
+    assert_date("в декабре 2026 года", 2026, 12, 1, &now);
+    assert_text("Отпуск в декабре", "в декабре", &now);
+    assert_date("Отпуск в декабре", 2024, 12, 1, &now);
+    assert_date("Отпуск в мае", 2025, 5, 1, &now);
+    assert_text(
+        "Отпуск в феврале следующего года",
+        "в феврале следующего года",
+        &now,
+    );
+    assert_date("Отпуск в феврале следующего года", 2025, 2, 1, &now);
+}
+
+#[test]
+fn test_genitive_ordinals() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("первого мая", 2025, 5, 1, &now);
+    assert_date("двадцать пятого декабря", 2024, 12, 25, &now);
+}
+
+#[test]
+fn test_case_endings_of_counts() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("через три дня", 2024, 12, 4, &now);
+    assert_date("через пять дней", 2024, 12, 6, &now);
+    assert_date("три дня назад", 2024, 11, 28, &now);
+    assert_date("через две недели", 2024, 12, 9, &now);
+    assert_date("через два месяца", 2025, 2, 1, &now);
+}
+
+#[test]
+fn test_number_words() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("через тринадцать дней", 2024, 12, 14, &now);
+    assert_date("через четырнадцать дней", 2024, 12, 15, &now);
+    assert_date("через двадцать дней", 2024, 12, 21, &now);
+    assert_date("через двадцать один день", 2024, 12, 22, &now);
+    assert_date("через тридцать один день", 2025, 1, 1, &now);
+    assert_date("девяносто дней назад", 2024, 9, 2, &now);
+    assert_date("через двадцать одну неделю", 2025, 4, 21, &now);
+}
+
+#[test]
+fn test_relative_weeks_months_and_years() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("на следующей неделе", 2024, 12, 2, &now);
+    assert_date("на прошлой неделе", 2024, 11, 18, &now);
+    assert_date("в следующем месяце", 2025, 1, 1, &now);
+    assert_date("в следующем году", 2025, 12, 1, &now);
+    assert_date("в прошлом году", 2023, 12, 1, &now);
+}
+
+#[test]
+fn test_invalid_dates() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    let error = RuDateParser::try_search_date_expression(
+        "Заплатить 30 февраля",
+        &now,
+        &RuDateParser::DATE_FORMAT,
+        &RuDateParser::START_DAY_OF_WEEK,
+    )
+    .unwrap_err();
+
+    // every cyrillic letter takes two bytes
+    assert_eq!(
+        error,
+        ParseError::InvalidDate {
+            expression: DateExpression::DayInMonth(Month::February, 30),
+            range: 19..36,
+        }
+    );
+    assert_eq!(error.to_string(), "February has no 30th");
+}
+
+#[test]
+fn test_strip_date_expression() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_title(
+        "Напомни мне заплатить за квартиру в следующий понедельник",
+        "заплатить за квартиру",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 2).unwrap()),
+        &now,
+    );
+    assert_title(
+        "Стоматолог 5 декабря.",
+        "Стоматолог.",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 5).unwrap()),
+        &now,
+    );
+    assert_title("Не забудь молоко", "молоко", None, &now);
+}
+
+#[test]
+fn test_parser() {
+    let parser = Parser::builder()
+        .language(Language::Russian)
+        .reference_date(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap())
+        .build();
+
+    assert_eq!(parser.config().date_format, DateFormat::DayMonthYear);
+    assert_eq!(
+        parser
+            .parse("Праздник 5.12")
+            .map(|date_match| date_match.date),
+        Ok(NaiveDate::from_ymd_opt(2024, 12, 5).unwrap())
+    );
+}
+
+fn assert_date(input: &str, year: i32, month: u32, day: u32, now: &NaiveDate) {
+    assert_eq!(
+        RuDateParser::search_relative_date_expression(
+            input,
+            now,
+            &RuDateParser::DATE_FORMAT,
+            &RuDateParser::START_DAY_OF_WEEK
+        ),
+        NaiveDate::from_ymd_opt(year, month, day),
+        "failed to parse {}",
+        input
+    );
+}
+
+fn assert_text(input: &str, expected_text: &str, now: &NaiveDate) {
+    assert_eq!(
+        RuDateParser::search_date_expression(
+            input,
+            now,
+            &RuDateParser::DATE_FORMAT,
+            &RuDateParser::START_DAY_OF_WEEK
+        )
+        .map(|date_match| date_match.text),
+        Some(String::from(expected_text)),
+        "failed to match {}",
+        input
+    );
+}
+
+fn assert_title(
+    input: &str,
+    expected_title: &str,
+    expected_date: Option<NaiveDate>,
+    now: &NaiveDate,
+) {
+    let StrippedDateExpression { title, date_match } = RuDateParser::strip_date_expression(
+        input,
+        now,
+        &RuDateParser::DATE_FORMAT,
+        &RuDateParser::START_DAY_OF_WEEK,
+    );
+
+    assert_eq!(title, expected_title, "failed to strip {}", input);
+    assert_eq!(
+        date_match.map(|date_match| date_match.date),
+        expected_date,
+        "failed to find the date in {}",
+        input
+    );
+}
@@ -0,0 +1,194 @@
+use chrono::NaiveDate;
+use date_time_parser_multi_language::{
+    DateExpression, DateFormat, DateParser, Language, Month, ParseError, Parser,
+    StrippedDateExpression, ZhDateParser,
+};
+
+// Unless a test says otherwise, it runs on sunday, the first of december 2024.
+
+#[test]
+fn test_simplified_and_traditional_characters() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("后天给鲍勃打电话", 2024, 12, 3, &now);
+    assert_date("後天給鮑勃打電話", 2024, 12, 3, &now);
+    assert_date("下周一", 2024, 12, 2, &now);
+    assert_date("下週一", 2024, 12, 2, &now);
+    assert_date("下个月", 2025, 1, 1, &now);
+    assert_date("下個月", 2025, 1, 1, &now);
+    assert_date("两周后", 2024, 12, 9, &now);
+    assert_date("兩週後", 2024, 12, 9, &now);
+    assert_date("三天后", 2024, 12, 4, &now);
+    assert_date("三天後", 2024, 12, 4, &now);
+    assert_date("12月5号开会", 2024, 12, 5, &now);
+    assert_date("12月5號開會", 2024, 12, 5, &now);
+    assert_date("后年三月一号", 2026, 3, 1, &now);
+    assert_date("後年三月一號", 2026, 3, 1, &now);
+}
+
+#[test]
+fn test_names_of_weekdays() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("下个星期一", 2024, 12, 2, &now);
+    assert_date("上礼拜五", 2024, 11, 22, &now);
+    assert_date("上禮拜五", 2024, 11, 22, &now);
+    assert_date("星期天", 2024, 12, 1, &now);
+    assert_date("星期日", 2024, 12, 1, &now);
+}
+
+#[test]
+fn test_without_spaces() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_text("今天做什么？", "今天", &now);
+    assert_text("明天见面？不行的话下周二", "明天", &now);
+    assert_text("鲍勃前天打过电话", "前天", &now);
+    assert_text("不行的话下周二吧", "下周二", &now);
+    assert_date("2024-12-24", 2024, 12, 24, &now);
+}
+
+#[test]
+fn test_months_in_relative_years() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_text("明年12月搬家", "明年12月", &now);
+    assert_date("明年12月搬家", 2025, 12, 1, &now);
+    assert_text("明年的12月5日搬家", "明年的12月5日", &now);
+    assert_date("明年的12月5日搬家", 2025, 12, 5, &now);
+    assert_date("去年的五月", 2023, 5, 1, &now);
+    assert_date("兩年後的4月", 2026, 4, 1, &now);
+    assert_date("明年", 2025, 12, 1, &now);
+}
+
+#[test]
+fn test_chinese_numerals() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_date("十二月五日開會", 2024, 12, 5, &now);
+    assert_date("两个月后", 2025, 2, 1, &now);
+    assert_date("三天前", 2024, 11, 28, &now);
+}
+
+#[test]
+fn test_weeks_ago() {
+    // the 18th of october 2026 is a sunday
+    let now = NaiveDate::from_ymd_opt(2026, 10, 18).unwrap();
+
+    assert_date("一周前", 2026, 10, 11, &now);
+    assert_date("两周前见过面", 2026, 10, 4, &now);
+    assert_date("兩週前", 2026, 10, 4, &now);
+    assert_date("三个星期以前", 2026, 9, 27, &now);
+}
+
+#[test]
+fn test_invalid_dates() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    let error = ZhDateParser::try_search_date_expression(
+        "2月30日交房租",
+        &now,
+        &ZhDateParser::DATE_FORMAT,
+        &ZhDateParser::START_DAY_OF_WEEK,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error,
+        ParseError::InvalidDate {
+            expression: DateExpression::DayInMonth(Month::February, 30),
+            range: 0..9,
+        }
+    );
+    assert_eq!(error.to_string(), "February has no 30th");
+}
+
+#[test]
+fn test_strip_date_expression() {
+    let now = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+
+    assert_title(
+        "下周一交房租",
+        "交房租",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 2).unwrap()),
+        &now,
+    );
+    assert_title(
+        "我们在明天开会",
+        "我们开会",
+        Some(NaiveDate::from_ymd_opt(2024, 12, 2).unwrap()),
+        &now,
+    );
+    assert_title(
+        "周五之前交报告",
+        "交报告",
+        Some(NaiveDate::from_ymd_opt(2024, 11, 29).unwrap()),
+        &now,
+    );
+    assert_title("买牛奶", "买牛奶", None, &now);
+}
+
+#[test]
+fn test_parser() {
+    let parser = Parser::builder()
+        .language(Language::Chinese)
+        .reference_date(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap())
+        .build();
+
+    assert_eq!(parser.config().date_format, DateFormat::YearMonthDay);
+    assert_eq!(
+        parser.parse("12/5聚会").map(|date_match| date_match.date),
+        Ok(NaiveDate::from_ymd_opt(2024, 12, 5).unwrap())
+    );
+}
+
+fn assert_date(input: &str, year: i32, month: u32, day: u32, now: &NaiveDate) {
+    assert_eq!(
+        ZhDateParser::search_relative_date_expression(
+            input,
+            now,
+            &ZhDateParser::DATE_FORMAT,
+            &ZhDateParser::START_DAY_OF_WEEK
+        ),
+        NaiveDate::from_ymd_opt(year, month, day),
+        "failed to parse {}",
+        input
+    );
+}
+
+fn assert_text(input: &str, expected_text: &str, now: &NaiveDate) {
+    assert_eq!(
+        ZhDateParser::search_date_expression(
+            input,
+            now,
+            &ZhDateParser::DATE_FORMAT,
+            &ZhDateParser::START_DAY_OF_WEEK
+        )
+        .map(|date_match| date_match.text),
+        Some(String::from(expected_text)),
+        "failed to match {}",
+        input
+    );
+}
+
+fn assert_title(
+    input: &str,
+    expected_title: &str,
+    expected_date: Option<NaiveDate>,
+    now: &NaiveDate,
+) {
+    let StrippedDateExpression { title, date_match } = ZhDateParser::strip_date_expression(
+        input,
+        now,
+        &ZhDateParser::DATE_FORMAT,
+        &ZhDateParser::START_DAY_OF_WEEK,
+    );
+
+    assert_eq!(title, expected_title, "failed to strip {}", input);
+    assert_eq!(
+        date_match.map(|date_match| date_match.date),
+        expected_date,
+        "failed to find the date in {}",
+        input
+    );
+}